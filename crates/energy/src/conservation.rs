@@ -1,5 +1,7 @@
 use bevy::prelude::*;
-use forces::prelude::{RotationalWorkEvent, WorkDoneEvent};
+use forces::prelude::{
+    BondEvent, ContinuumEnergyEvent, MolecularEnergyEvent, RotationalWorkEvent, WorkDoneEvent,
+};
 
 /// Enum representing different types of energy
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component, Reflect)]
@@ -257,6 +259,51 @@ impl EnergyDriftMonitor {
             None
         }
     }
+
+    /// Drift check for continuum matter: mechanical energy net of external work.
+    /// `ContinuumEnergyLedger::total_dissipated` tells where any drift went.
+    pub fn check_continuum_drift(&self, ledger: &ContinuumEnergyLedger) -> Option<f32> {
+        self.check_drift(ledger.mechanical_energy() - ledger.total_external_work)
+    }
+}
+
+/// Energy account for continuum matter (MPM), fed by `ContinuumEnergyEvent`.
+///
+/// Continuum bodies are tracked as a whole, not per particle: the ledger keeps the
+/// latest totals plus the cumulative dissipated energy and external work.
+#[derive(Resource, Debug, Default)]
+pub struct ContinuumEnergyLedger {
+    /// Most recent per-step report
+    pub latest: ContinuumEnergyEvent,
+    /// Mechanical energy lost since tracking began (J)
+    pub total_dissipated: f32,
+    /// Work done on the continuum by other bodies since tracking began (J)
+    pub total_external_work: f32,
+    /// Transaction history (dissipation = output, external work = input/output)
+    pub balance: EnergyBalance,
+}
+
+impl ContinuumEnergyLedger {
+    /// Kinetic + elastic + gravitational energy of the latest report (J).
+    pub fn mechanical_energy(&self) -> f32 {
+        self.latest.mechanical_energy()
+    }
+}
+
+/// Energy account for interatomic potentials, fed by `MolecularEnergyEvent` and
+/// `BondEvent`.
+///
+/// **CONSERVATION**: Kinetic + `latest.potential_energy()` + `total_released` is
+/// constant for an isolated molecular system; bond chemistry moves energy between
+/// the last two.
+#[derive(Resource, Debug, Default)]
+pub struct MolecularEnergyLedger {
+    /// Most recent potential energy report
+    pub latest: MolecularEnergyEvent,
+    /// Energy released by bond formation net of bond breaking since tracking began (J)
+    pub total_released: f32,
+    /// Transaction history (released = output, absorbed = input)
+    pub balance: EnergyBalance,
 }
 
 /// System to ensure entities with Mass have energy balance tracking
@@ -325,6 +372,78 @@ pub fn track_rotational_work_from_torques(
     }
 }
 
+/// System to record continuum (MPM) energy reports in the continuum ledger
+pub fn track_continuum_energy(
+    mut reports: MessageReader<ContinuumEnergyEvent>,
+    mut ledger: ResMut<ContinuumEnergyLedger>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs().max(f32::EPSILON);
+    for report in reports.read() {
+        ledger.latest = *report;
+        ledger.total_dissipated += report.dissipated;
+        ledger.total_external_work += report.external_work;
+
+        for (amount, transaction_type) in [
+            (report.dissipated, TransactionType::Output),
+            (report.external_work, TransactionType::Input),
+        ] {
+            if amount == 0.0 {
+                continue;
+            }
+            // A negative output is energy gained (and vice versa)
+            let transaction_type = match (transaction_type, amount > 0.0) {
+                (TransactionType::Output, false) => TransactionType::Input,
+                (TransactionType::Input, false) => TransactionType::Output,
+                (kind, true) => kind,
+            };
+            ledger.balance.record_transaction(EnergyTransaction {
+                transaction_type,
+                amount: amount.abs(),
+                source: None,
+                destination: None,
+                timestamp: time.elapsed_secs(),
+                transfer_rate: amount.abs() / dt,
+                duration: time.delta_secs(),
+            });
+        }
+    }
+}
+
+/// System to record interatomic potential energy and bond chemistry
+pub fn track_molecular_energy(
+    mut reports: MessageReader<MolecularEnergyEvent>,
+    mut bonds: MessageReader<BondEvent>,
+    mut ledger: ResMut<MolecularEnergyLedger>,
+    time: Res<Time>,
+) {
+    if let Some(report) = reports.read().last() {
+        ledger.latest = *report;
+    }
+    let dt = time.delta_secs().max(f32::EPSILON);
+    for event in bonds.read() {
+        let amount = event.energy_released;
+        if amount == 0.0 {
+            continue;
+        }
+        ledger.total_released += amount;
+        let transaction_type = if amount > 0.0 {
+            TransactionType::Output
+        } else {
+            TransactionType::Input
+        };
+        ledger.balance.record_transaction(EnergyTransaction {
+            transaction_type,
+            amount: amount.abs(),
+            source: Some(event.a),
+            destination: Some(event.b),
+            timestamp: time.elapsed_secs(),
+            transfer_rate: amount.abs() / dt,
+            duration: time.delta_secs(),
+        });
+    }
+}
+
 /// Plugin to manage energy conservation systems
 pub struct EnergyConservationPlugin;
 
@@ -339,15 +458,25 @@ impl Plugin for EnergyConservationPlugin {
             .register_type::<EnergyBalance>()
             // Add resources
             .init_resource::<EnergyConservationTracker>()
+            .init_resource::<ContinuumEnergyLedger>()
+            .init_resource::<MolecularEnergyLedger>()
             // Add event channel
             .add_message::<EnergyTransferEvent>()
+            .add_message::<ContinuumEnergyEvent>()
+            .add_message::<MolecularEnergyEvent>()
+            .add_message::<BondEvent>()
             // Track energy in FixedUpdate to match physics integration schedule
             .add_systems(
                 FixedUpdate,
                 (
                     initialize_energy_balance,
                     ApplyDeferred,
-                    (track_work_from_forces, track_rotational_work_from_torques)
+                    (
+                        track_work_from_forces,
+                        track_rotational_work_from_torques,
+                        track_continuum_energy,
+                        track_molecular_energy,
+                    )
                         .after(forces::PhysicsSet::ApplyForces),
                 )
                     .chain(),
@@ -442,4 +571,48 @@ mod tests {
         let flux = ledger.current_flux(current_time, 1.0);
         assert_eq!(flux, 15.0, "Expected sum of active rates: 10.0 + 5.0");
     }
+
+    #[test]
+    fn test_continuum_reports_feed_ledger_and_drift_monitor() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(forces::core::newton_laws::NewtonLawsPlugin)
+            .add_plugins(EnergyConservationPlugin);
+
+        // Block falls and loses 2 J to plasticity; 1 J of work pushes it back
+        let reports = [
+            ContinuumEnergyEvent {
+                kinetic: 0.0,
+                potential: 100.0,
+                ..default()
+            },
+            ContinuumEnergyEvent {
+                kinetic: 48.0,
+                potential: 50.0,
+                dissipated: 2.0,
+                ..default()
+            },
+            ContinuumEnergyEvent {
+                kinetic: 49.0,
+                potential: 50.0,
+                external_work: 1.0,
+                ..default()
+            },
+        ];
+        for report in reports {
+            app.world_mut().write_message(report);
+            app.world_mut().run_schedule(FixedUpdate);
+        }
+
+        let ledger = app.world().resource::<ContinuumEnergyLedger>();
+        assert_eq!(ledger.total_dissipated, 2.0);
+        assert_eq!(ledger.total_external_work, 1.0);
+        assert_eq!(ledger.balance.total_output, 2.0);
+        assert_eq!(ledger.balance.total_input, 1.0);
+
+        // Mechanical energy net of external work drifted by exactly the dissipated 2 J
+        let monitor = EnergyDriftMonitor::new(100.0, 1.0);
+        let drift = monitor.check_continuum_drift(ledger).unwrap();
+        assert!((drift - ledger.total_dissipated).abs() < 1e-5);
+    }
 }
@@ -14,6 +14,7 @@
 //!        3. Add proper charge sign handling and test orbital/binding stability
 
 use bevy::prelude::*;
+use forces::core::layers::CollisionLayers;
 use forces::core::newton_laws::AppliedForce;
 use std::collections::HashMap;
 use utils::{SpatiallyIndexed, UnifiedSpatialIndex, force_switch};
@@ -71,7 +72,7 @@ pub struct SofteningLength {
 
 #[derive(Default)]
 pub(crate) struct CoulombComputeContext {
-    charge_data: HashMap<Entity, (f32, Vec2, f32)>,
+    charge_data: HashMap<Entity, (f32, Vec2, f32, CollisionLayers)>,
     sorted_entities: Vec<Entity>,
     neighbor_candidates: Vec<Entity>,
 }
@@ -151,9 +152,11 @@ pub fn mark_charged_entities_spatially_indexed(
 /// - Softening: 0.01m default (singularity avoidance for r→0)
 /// - Potential energy: Not tracked (force-only, PE = 0 in LP-0)
 /// - Pair-once guarantee: Only processes pairs where entity_b.id > entity_a.id to avoid double-counting
+/// - Pairs whose `CollisionLayers` do not interact are skipped
 ///
 /// **CONSERVATION**: Momentum conserved (F_ab = -F_ba, Newton's 3rd law).
 /// Energy NOT conserved (PE missing from accounting).
+#[allow(clippy::type_complexity)]
 pub(crate) fn apply_coulomb_pairwise_forces(
     mut charges: Query<(
         Entity,
@@ -161,6 +164,7 @@ pub(crate) fn apply_coulomb_pairwise_forces(
         &Transform,
         Option<&SofteningLength>,
         &mut AppliedForce,
+        Option<&CollisionLayers>,
     )>,
     index: Res<UnifiedSpatialIndex>,
     config: Res<CoulombConfig>,
@@ -173,7 +177,7 @@ pub(crate) fn apply_coulomb_pairwise_forces(
     // Reuse staging buffers across frames to avoid per-frame allocation churn.
     let estimated = charges.iter().len();
     prepare_staging_map(&mut ctx.charge_data, estimated);
-    for (entity, charge, trans, softening, _) in charges.iter() {
+    for (entity, charge, trans, softening, _, layers) in charges.iter() {
         let pos = trans.translation.truncate();
 
         // No silent defaults: require SofteningLength
@@ -195,8 +199,15 @@ pub(crate) fn apply_coulomb_pairwise_forces(
             }
         };
 
-        ctx.charge_data
-            .insert(entity, (charge.value, pos, soft.value));
+        ctx.charge_data.insert(
+            entity,
+            (
+                charge.value,
+                pos,
+                soft.value,
+                layers.copied().unwrap_or_default(),
+            ),
+        );
     }
 
     // Deterministic outer iteration: sort entities by stable id
@@ -207,7 +218,7 @@ pub(crate) fn apply_coulomb_pairwise_forces(
     let sorted_entities = std::mem::take(&mut ctx.sorted_entities);
     let charge_data = std::mem::take(&mut ctx.charge_data);
     for &entity_a in &sorted_entities {
-        let (charge_a, pos_a, soft_a) = charge_data[&entity_a];
+        let (charge_a, pos_a, soft_a, layers_a) = charge_data[&entity_a];
         // Find neighbors within cutoff using UnifiedSpatialIndex backend.
         for_each_neighbor_candidate(
             &index,
@@ -222,9 +233,12 @@ pub(crate) fn apply_coulomb_pairwise_forces(
                 }
 
                 // Get data for entity B from staged map
-                let Some((charge_b, pos_b, soft_b)) = charge_data.get(&entity_b) else {
+                let Some((charge_b, pos_b, soft_b, layers_b)) = charge_data.get(&entity_b) else {
                     return;
                 };
+                if !layers_a.interacts_with(layers_b) {
+                    return;
+                }
 
                 let r_vec = *pos_b - pos_a;
                 let r = r_vec.length();
@@ -258,10 +272,10 @@ pub(crate) fn apply_coulomb_pairwise_forces(
                 let force = force_2d.extend(0.0); // Convert to Vec3 for AppliedForce
 
                 // Apply forces symmetrically (Newton's 3rd law)
-                if let Ok((_, _, _, _, mut force_a)) = charges.get_mut(entity_a) {
+                if let Ok((_, _, _, _, mut force_a, _)) = charges.get_mut(entity_a) {
                     force_a.force += force;
                 }
-                if let Ok((_, _, _, _, mut force_b)) = charges.get_mut(entity_b) {
+                if let Ok((_, _, _, _, mut force_b, _)) = charges.get_mut(entity_b) {
                     force_b.force -= force; // F_ba = -F_ab
                 }
 
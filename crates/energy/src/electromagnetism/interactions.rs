@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use matter::material::{Material, MaterialId, MaterialRegistry};
 
 use super::fields::{ElectricField, MagneticField};
 
@@ -100,6 +101,16 @@ impl MaterialProperties {
         }
     }
 
+    /// Absolute properties of a registry material: ε = εᵣ·ε₀, μ = μᵣ·μ₀.
+    pub fn from_material(material: &Material) -> Self {
+        let vacuum = Self::vacuum();
+        Self {
+            permittivity: material.relative_permittivity * vacuum.permittivity,
+            permeability: material.relative_permeability * vacuum.permeability,
+            conductivity: material.electrical_conductivity,
+        }
+    }
+
     /// Create custom material properties
     pub fn new(permittivity: f32, permeability: f32, conductivity: f32) -> Self {
         Self {
@@ -125,3 +136,23 @@ impl MaterialProperties {
         C / self.refractive_index()
     }
 }
+
+/// Derive [`MaterialProperties`] from an entity's [`MaterialId`] when it is added or
+/// changed, or for every body when the registry changes (late or hot-reloaded
+/// libraries).
+pub fn apply_material_em_properties(
+    mut commands: Commands,
+    registry: Res<MaterialRegistry>,
+    bodies: Query<(Entity, Ref<MaterialId>)>,
+) {
+    for (entity, id) in bodies.iter() {
+        if !id.is_changed() && !registry.is_changed() {
+            continue;
+        }
+        if let Some(material) = registry.get(*id) {
+            commands
+                .entity(entity)
+                .insert(MaterialProperties::from_material(material));
+        }
+    }
+}
@@ -5,6 +5,7 @@ pub mod interactions;
 use bevy::prelude::*;
 use forces::PhysicsSet;
 use forces::core::gravity::GravitySet;
+use matter::material::MaterialRegistry;
 use utils::SpatialIndexSet;
 
 // NOTE: Charge is NOT conserved; EM is quasi-static (no charge continuity equation).
@@ -21,11 +22,16 @@ impl Plugin for ElectromagnetismPlugin {
             .init_resource::<charges::CoulombConfig>()
             .register_type::<charges::Charge>()
             .register_type::<charges::SofteningLength>()
+            .init_resource::<MaterialRegistry>()
             // Marker injection in PreUpdate
             .add_systems(
                 PreUpdate,
-                charges::mark_charged_entities_spatially_indexed
-                    .in_set(SpatialIndexSet::InjectMarkers),
+                (
+                    charges::mark_charged_entities_spatially_indexed
+                        .in_set(SpatialIndexSet::InjectMarkers),
+                    interactions::apply_material_em_properties
+                        .after(matter::material::register_loaded_materials),
+                ),
             )
             // Coulomb forces in FixedUpdate, in force accumulation, after gravity
             .add_systems(
@@ -118,9 +118,9 @@ pub mod prelude {
     pub use crate::PairwiseDeterminismConfig;
 
     pub use crate::conservation::{
-        EnergyBalance, EnergyConservationPlugin, EnergyConservationTracker, EnergyDriftMonitor,
-        EnergyQuantity, EnergyTransaction, EnergyTransferEvent, EnergyType, TransactionType,
-        conversion_efficiency, verify_conservation,
+        ContinuumEnergyLedger, EnergyBalance, EnergyConservationPlugin, EnergyConservationTracker,
+        EnergyDriftMonitor, EnergyQuantity, EnergyTransaction, EnergyTransferEvent, EnergyType,
+        MolecularEnergyLedger, TransactionType, conversion_efficiency, verify_conservation,
     };
 
     pub use crate::electromagnetism::prelude::*;
@@ -9,17 +9,8 @@ pub struct ThermalEquilibrium {
     pub group_id: Option<u32>,
 }
 
-/// Component for phase state of matter that will use the matter crate later once implemented, soon once PBMPM will be in place
-/// and the matter crate is implemented
-/// Stub phase state representation until matter integration is ready.
-#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
-#[reflect(Component)]
-pub enum PhaseState {
-    Solid,
-    Liquid,
-    Gas,
-    Plasma,
-}
+/// Phase state of matter, owned by the matter crate (see `matter::phase`).
+pub use matter::phase::PhaseState;
 
 /// Weighted equilibrium parameters
 #[derive(Component, Debug, Clone, Copy)]
@@ -0,0 +1,246 @@
+//! Thermal ionization coupling: temperature → free charge and conductivity.
+//!
+//! **PHYSICS**: Bodies with `matter`'s [`Ionizable`] relax to Saha equilibrium each
+//! frame. The energy to free electrons is taken from the body's thermal energy, the
+//! plasma contribution to electrical conductivity is added to its
+//! [`MaterialProperties`], and separated charge (if any) to its [`Charge`].
+//!
+//! **CONSERVATION**: C·T + N·χ·x is conserved per body. The ionization term is held
+//! in [`IonizationLevel`], not in the thermal `EnergyQuantity` (U = C·T); each change
+//! is recorded in the body's [`EnergyBalance`] (output when ionizing, input when
+//! recombining), so the ledger accounts for the thermal energy that left.
+
+use bevy::prelude::*;
+use forces::core::newton_laws::Mass;
+use matter::phase::PhaseState;
+use matter::states::plasma::ionization::{
+    ELEMENTARY_CHARGE, Ionizable, IonizationLevel, PLASMA_THRESHOLD,
+};
+
+use super::thermal::{HeatCapacity, Temperature};
+use crate::conservation::{EnergyBalance, EnergyTransaction, TransactionType};
+use crate::electromagnetism::charges::Charge;
+use crate::electromagnetism::interactions::MaterialProperties;
+
+/// Relax ionizable bodies to equilibrium and push the result into EM state.
+///
+/// Conductivity and charge are updated by the change since last frame, so values
+/// set by materials or gameplay are kept underneath the plasma contribution.
+/// `PhaseState` flips between `Gas` and `Plasma` at half ionization.
+#[allow(clippy::type_complexity)]
+pub(crate) fn apply_ionization(
+    mut commands: Commands,
+    mut bodies: Query<(
+        Entity,
+        &Ionizable,
+        &mut IonizationLevel,
+        &mut Temperature,
+        &HeatCapacity,
+        &Mass,
+        Option<&mut PhaseState>,
+        Option<&mut Charge>,
+        Option<&mut MaterialProperties>,
+        Option<&mut EnergyBalance>,
+    )>,
+    time: Res<Time>,
+) {
+    for (entity, species, mut level, mut temperature, capacity, mass, phase, charge, em, balance) in
+        bodies.iter_mut()
+    {
+        if mass.is_infinite {
+            continue;
+        }
+        let atoms = mass.value * species.atoms_per_kilogram();
+        let (fraction, next_temperature) =
+            species.equilibrate(level.fraction, temperature.value, capacity.value, atoms);
+        let conductivity = species.conductivity(fraction, next_temperature);
+
+        // Only write on change to keep Changed<Temperature> meaningful
+        if next_temperature != temperature.value {
+            temperature.value = next_temperature;
+        }
+
+        // Heat that went into freeing electrons (negative when recombining)
+        let absorbed = IonizationLevel { fraction, ..*level }.stored_energy(species, atoms)
+            - level.stored_energy(species, atoms);
+        if absorbed != 0.0
+            && let Some(mut balance) = balance
+        {
+            let dt = time.delta_secs();
+            let (transaction_type, source, destination) = if absorbed > 0.0 {
+                (TransactionType::Output, Some(entity), None)
+            } else {
+                (TransactionType::Input, None, Some(entity))
+            };
+            balance.record_transaction(EnergyTransaction {
+                transaction_type,
+                amount: absorbed.abs(),
+                source,
+                destination,
+                timestamp: time.elapsed_secs(),
+                transfer_rate: if dt > 0.0 { absorbed.abs() / dt } else { 0.0 },
+                duration: dt,
+            });
+        }
+
+        let conductivity_change = conductivity - level.conductivity;
+        if conductivity_change != 0.0 {
+            match em {
+                Some(mut em) => em.conductivity = (em.conductivity + conductivity_change).max(0.0),
+                None => {
+                    commands.entity(entity).insert(MaterialProperties {
+                        conductivity,
+                        ..MaterialProperties::vacuum()
+                    });
+                }
+            }
+        }
+
+        // Electrons that leave the body strip +e each from it
+        let charge_change =
+            species.charge_separation * (fraction - level.fraction) * atoms * ELEMENTARY_CHARGE;
+        if charge_change != 0.0 {
+            match charge {
+                Some(mut charge) => charge.value += charge_change,
+                None => {
+                    commands.entity(entity).insert(Charge::new(charge_change));
+                }
+            }
+        }
+
+        if let Some(mut phase) = phase {
+            let plasma = fraction >= PLASMA_THRESHOLD;
+            match *phase {
+                PhaseState::Gas if plasma => *phase = PhaseState::Plasma,
+                PhaseState::Plasma if !plasma => *phase = PhaseState::Gas,
+                _ => {}
+            }
+        }
+
+        if level.fraction != fraction || level.conductivity != conductivity {
+            level.fraction = fraction;
+            level.conductivity = conductivity;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conservation::EnergyQuantity;
+
+    #[test]
+    fn test_lightning_channel_ionizes_and_conducts() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_systems(Update, apply_ionization);
+
+        // 1 g of dilute hydrogen with a quarter of the freed electrons escaping
+        let species = Ionizable::hydrogen(1e20).with_charge_separation(0.25);
+        let heat_capacity = 1e-3 * 14_300.0;
+        // Enough heat to ionize about half of it: N·χ ≈ 1.3 MJ
+        let temperature = 1.0e5;
+        let body = app
+            .world_mut()
+            .spawn((
+                species,
+                Temperature::new(temperature),
+                HeatCapacity {
+                    value: heat_capacity,
+                },
+                Mass::new(1e-3),
+                PhaseState::Gas,
+                MaterialProperties::vacuum(),
+            ))
+            .id();
+        app.update();
+
+        let world = app.world();
+        let level = *world.get::<IonizationLevel>(body).unwrap();
+        let cooled = world.get::<Temperature>(body).unwrap().value;
+        let atoms = 1e-3 * species.atoms_per_kilogram();
+        assert!(level.fraction > 0.1 && level.fraction < 1.0);
+        assert!(cooled < temperature);
+
+        let before = heat_capacity * temperature;
+        let after = heat_capacity * cooled + level.stored_energy(&species, atoms);
+        assert!((after - before).abs() < 1e-3 * before);
+
+        let sigma = world.get::<MaterialProperties>(body).unwrap().conductivity;
+        assert!(sigma > 0.0);
+        assert_eq!(sigma, level.conductivity);
+        let charge = world.get::<Charge>(body).unwrap().value;
+        let expected = 0.25 * level.fraction * atoms * ELEMENTARY_CHARGE;
+        assert!((charge - expected).abs() < 1e-3 * expected);
+        assert_eq!(
+            *world.get::<PhaseState>(body).unwrap(),
+            if level.is_plasma() {
+                PhaseState::Plasma
+            } else {
+                PhaseState::Gas
+            }
+        );
+
+        // Cooling the channel recombines it: conductivity and charge go back to zero
+        app.world_mut().get_mut::<Temperature>(body).unwrap().value = 300.0;
+        app.world_mut().get_mut::<HeatCapacity>(body).unwrap().value = 1e6;
+        app.update();
+        let world = app.world();
+        assert!(world.get::<IonizationLevel>(body).unwrap().fraction < 1e-6);
+        assert!(world.get::<MaterialProperties>(body).unwrap().conductivity < 1e-3 * sigma);
+        assert!(world.get::<Charge>(body).unwrap().value.abs() < 1e-3 * expected);
+        assert_eq!(*world.get::<PhaseState>(body).unwrap(), PhaseState::Gas);
+    }
+
+    #[test]
+    fn test_ionization_energy_is_recorded_in_the_thermal_ledger() {
+        let mut app = App::new();
+        app.init_resource::<Time>().add_systems(
+            Update,
+            (apply_ionization, super::super::thermal::sync_thermal_energy).chain(),
+        );
+
+        let species = Ionizable::hydrogen(1e20);
+        let heat_capacity = 1e-3 * 14_300.0;
+        let temperature = 1.0e5;
+        let body = app
+            .world_mut()
+            .spawn((
+                species,
+                Temperature::new(temperature),
+                HeatCapacity {
+                    value: heat_capacity,
+                },
+                Mass::new(1e-3),
+                EnergyBalance::default(),
+            ))
+            .id();
+        let atoms = 1e-3 * species.atoms_per_kilogram();
+        let total = |app: &App| {
+            let world = app.world();
+            let thermal = world.get::<EnergyQuantity>(body).unwrap().value;
+            let level = world.get::<IonizationLevel>(body).unwrap();
+            (thermal, level.stored_energy(&species, atoms))
+        };
+
+        // C·T + N·χ·x before and after ionizing, then after recombining
+        let before = heat_capacity * temperature;
+        app.update();
+        let (thermal, ionization) = total(&app);
+        assert!(ionization > 0.0);
+        assert!((thermal + ionization - before).abs() < 1e-3 * before);
+        let balance = app.world().get::<EnergyBalance>(body).unwrap();
+        assert!((balance.net_energy_change() + ionization).abs() < 1e-3 * ionization);
+        assert!((before + balance.net_energy_change() - thermal).abs() < 1e-3 * before);
+
+        app.world_mut().get_mut::<HeatCapacity>(body).unwrap().value = 1e6;
+        app.world_mut().get_mut::<Temperature>(body).unwrap().value = 300.0;
+        let before = 1e6 * 300.0 + ionization;
+        app.update();
+        let (thermal, ionization) = total(&app);
+        assert!(ionization < 1e-6 * before);
+        assert!((thermal + ionization - before).abs() < 1e-4 * before);
+        let balance = app.world().get::<EnergyBalance>(body).unwrap();
+        assert!(balance.net_energy_change().abs() < 1e-3 * balance.total_input);
+    }
+}
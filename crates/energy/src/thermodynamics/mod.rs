@@ -1,5 +1,6 @@
 pub mod entropy;
 pub mod equilibrium;
+pub mod ionization;
 pub mod thermal;
 
 use bevy::prelude::*;
@@ -1,5 +1,8 @@
 use bevy::prelude::*;
-use matter::geometry::Radius;
+use forces::core::newton_laws::Mass;
+use matter::geometry::{Radius, Shape};
+use matter::material::{MaterialId, MaterialRegistry};
+use matter::phase::{LatentHeatReservoir, PhaseState, PhaseTransitionEvent, PhaseTransitions};
 use std::collections::HashMap;
 use utils::{SpatialIndexSet, SpatiallyIndexed, UnifiedSpatialIndex, force_switch};
 
@@ -11,8 +14,10 @@ use crate::pairwise::{
 
 /// Configuration for Fourier conduction system.
 ///
-/// **LP-0 SCAFFOLDING**: Pairwise particle-particle approximation.
-/// Future: Grid-based diffusion PDE (∇·(k∇T) = ρc_p ∂T/∂t).
+/// **LP-0 SCAFFOLDING**: Pairwise particle-particle approximation for discrete bodies.
+/// Continuum matter solves the grid diffusion PDE (∇·(k∇T) = ρc_p ∂T/∂t) in the MPM
+/// backend instead (`mpm::thermal`). Conductivity is per body: see
+/// [`ThermalConductivity`], set from the body's `MaterialId`.
 #[derive(Resource, Debug, Clone, Reflect)]
 #[reflect(Resource)]
 pub struct ThermalConductionConfig {
@@ -29,11 +34,6 @@ pub struct ThermalConductionConfig {
     /// CFL safety factor (dimensionless). For dt ≤ safety × dx²/α.
     /// **Units**: dimensionless
     pub cfl_safety_factor: f32,
-
-    /// Effective thermal conductivity for LP-0 pairwise conduction.
-    /// **Units**: Watts / (meter × Kelvin) (W/(m·K))
-    /// **Note**: Scaffold parameter. Future: per-material property in continuum/PDE.
-    pub thermal_conductivity_w_per_m_k: f32,
 }
 
 impl Default for ThermalConductionConfig {
@@ -43,7 +43,6 @@ impl Default for ThermalConductionConfig {
             cutoff_radius: cutoff,
             switch_on_radius: 0.8 * cutoff,
             cfl_safety_factor: 0.5,
-            thermal_conductivity_w_per_m_k: 1.0, // Explicit default, SI documented
         }
     }
 }
@@ -188,7 +187,10 @@ impl HeatCapacity {
         }
     }
 
-    /// Common materials (per kg)
+    /// Common materials (per kg).
+    ///
+    /// Prefer a `MaterialId`: these constants predate `matter::material` and are kept
+    /// for hand-built bodies.
     pub fn water(mass: f32) -> Self {
         Self::from_material(mass, 4184.0) // J/(kg·K)
     }
@@ -219,12 +221,50 @@ pub struct ThermalTransferEvent {
 
 #[derive(Default)]
 pub(crate) struct ThermalComputeContext {
-    thermal_data: HashMap<Entity, (Vec2, f32, f32, f32, f32)>,
+    thermal_data: HashMap<Entity, (Vec2, f32, f32, f32, ContactProfile)>,
     temp_changes: HashMap<Entity, f32>,
     sorted_entities: Vec<Entity>,
     neighbor_candidates: Vec<Entity>,
 }
 
+/// Outline a thermal body presents to its neighbours.
+#[derive(Debug, Clone)]
+enum ContactProfile {
+    /// `Radius` only: round body
+    Round(f32),
+    /// `Shape` with the body's world rotation
+    Shaped(Shape, Quat),
+}
+
+impl ContactProfile {
+    /// Half-width of the body across the line of centers `normal` (m).
+    ///
+    /// Equal to the radius for round bodies; a capsule touching end-on presents its
+    /// radius, side-on its half length plus radius.
+    fn contact_radius(&self, normal: Vec2) -> f32 {
+        match self {
+            Self::Round(radius) => *radius,
+            Self::Shaped(shape, rotation) => {
+                let across = (rotation.inverse() * normal.perp().extend(0.0)).truncate();
+                shape.half_width(across)
+            }
+        }
+    }
+
+    /// Half-extent of the body along the line of centers `normal` (m).
+    ///
+    /// ½(support(n) + support(-n)) in the body frame; the radius for round bodies.
+    fn reach(&self, normal: Vec2) -> f32 {
+        match self {
+            Self::Round(radius) => *radius,
+            Self::Shaped(shape, rotation) => {
+                let along = (rotation.inverse() * normal.extend(0.0)).truncate();
+                shape.half_width(along)
+            }
+        }
+    }
+}
+
 /// Mark thermal entities for spatial indexing.
 ///
 /// **Phase A2**: Inject SpatiallyIndexed marker for UnifiedSpatialIndex.
@@ -241,19 +281,23 @@ fn mark_thermal_entities_spatially_indexed(
 /// Compute thermal transfer via Fourier's Law of conduction.
 ///
 /// **LP-0 SCAFFOLDING**: Pairwise particle-particle thermal conduction.
-/// **TEMPORARY**: Will be replaced by grid-based diffusion PDE (∇·(k∇T) = ρc_p ∂T/∂t) in LP-1.
+/// **TEMPORARY**: Continuum matter already uses the grid diffusion PDE (∇·(k∇T) = ρc_p ∂T/∂t)
+/// in `mpm::thermal`; discrete bodies move there in LP-1.
 ///
 /// **PHYSICS**: Fourier's Law q = k·A·ΔT/d (W), Q = P·dt (J)
 /// - q: Heat flux (Watts) = k·A·ΔT/d
 /// - Q: Energy transferred (Joules) = q·dt
 /// - k: Thermal conductivity (W/(m·K))
-/// - A: Contact area (m²) - assumes π·r² (spherical particles)
+/// - A: Contact area (m²) - π·r² with r the half-width of the facing sides
 /// - ΔT: Temperature difference (K)
 /// - d: Distance (m)
 ///
 /// **APPROXIMATIONS**:
 /// - Cutoff radius: 10m default (performance hack, IRL heat conduction has no cutoff)
-/// - Contact area: A = π·min(r₁,r₂)² (assumes spherical particles)
+/// - Contact area: A = π·min(w₁,w₂)², wᵢ = half-width of body i across the line of
+///   centers (its radius when round, from `Shape` otherwise)
+/// - Softening: pairs closer than min(e₁,e₂) are skipped, eᵢ = half-extent of body i
+///   along the line of centers
 /// - Constant c_p: Heat capacity assumed independent of temperature
 ///
 /// **CONSERVATION**: Energy-symmetric (Q_out = Q_in), momentum conserved (no forces applied).
@@ -273,6 +317,7 @@ pub(crate) fn compute_fourier_conduction(
         &ThermalConductivity,
         Option<&HeatCapacity>,
         Option<&Radius>,
+        Option<&Shape>,
     )>,
 ) {
     // **LP-0 SCAFFOLDING**: Pairwise particle-particle thermal conduction.
@@ -281,7 +326,7 @@ pub(crate) fn compute_fourier_conduction(
     // Reuse staging buffers across frames to avoid per-frame allocation churn.
     let estimated = entities.iter().len();
     prepare_staging_map(&mut ctx.thermal_data, estimated);
-    for (entity, trans, temp, conductivity, heat_capacity, radius) in entities.iter() {
+    for (entity, trans, temp, conductivity, heat_capacity, radius, shape) in entities.iter() {
         let pos = trans.translation.truncate();
         let k = conductivity.value;
         let t = temp.value;
@@ -305,11 +350,16 @@ pub(crate) fn compute_fourier_conduction(
             }
         };
 
-        // No silent defaults: require Radius
-        let Some(rad) = radius else {
+        // No silent defaults: require Shape or Radius
+        let profile = match (shape, radius) {
+            (Some(shape), _) => Some(ContactProfile::Shaped(shape.clone(), trans.rotation)),
+            (None, Some(radius)) => Some(ContactProfile::Round(radius.value)),
+            (None, None) => None,
+        };
+        let Some(profile) = profile else {
             #[cfg(debug_assertions)]
             panic!(
-                "Entity {:?} missing Radius for thermal contact area",
+                "Entity {:?} missing Shape or Radius for thermal contact area",
                 entity
             );
 
@@ -318,15 +368,14 @@ pub(crate) fn compute_fourier_conduction(
                 static LOGGED_RADIUS: std::sync::atomic::AtomicBool =
                     std::sync::atomic::AtomicBool::new(false);
                 if !LOGGED_RADIUS.swap(true, std::sync::atomic::Ordering::Relaxed) {
-                    warn!("Skipping thermal entities missing Radius (logged once)");
+                    warn!("Skipping thermal entities missing Shape or Radius (logged once)");
                 }
                 continue;
             }
         };
 
         let c = capacity.value;
-        let r = rad.value;
-        ctx.thermal_data.insert(entity, (pos, t, k, c, r));
+        ctx.thermal_data.insert(entity, (pos, t, k, c, profile));
     }
 
     ctx.temp_changes.clear();
@@ -346,7 +395,7 @@ pub(crate) fn compute_fourier_conduction(
     let thermal_data = std::mem::take(&mut ctx.thermal_data);
     let sorted_entities = std::mem::take(&mut ctx.sorted_entities);
     for &entity_a in &sorted_entities {
-        let (pos_a, temp_a, k_a, capacity_a, radius_a) = thermal_data[&entity_a];
+        let (pos_a, temp_a, k_a, capacity_a, ref profile_a) = thermal_data[&entity_a];
         // Find neighbors within cutoff using UnifiedSpatialIndex backend.
         for_each_neighbor_candidate(
             &index,
@@ -361,7 +410,7 @@ pub(crate) fn compute_fourier_conduction(
                 }
 
                 // Get data for entity B from staged map
-                let Some((pos_b, temp_b, k_b, capacity_b, radius_b)) = thermal_data.get(&entity_b)
+                let Some((pos_b, temp_b, k_b, capacity_b, profile_b)) = thermal_data.get(&entity_b)
                 else {
                     return;
                 };
@@ -369,19 +418,25 @@ pub(crate) fn compute_fourier_conduction(
                 let r_vec = *pos_b - pos_a;
                 let distance = r_vec.length();
 
-                // Property-based softening: use smaller radius as minimum distance
-                let softening = radius_a.min(*radius_b);
+                // Half-widths of the facing sides (radii for round bodies)
+                let normal = r_vec.normalize_or(Vec2::X);
+                let contact_radius = profile_a
+                    .contact_radius(normal)
+                    .min(profile_b.contact_radius(normal));
+
+                // Property-based softening: the smaller extent along the line of
+                // centers, so bodies touching side by side still conduct
+                let softening = profile_a.reach(normal).min(profile_b.reach(normal));
                 if distance < softening || distance >= cutoff_radius {
                     return;
                 }
 
                 let temp_diff = temp_a - temp_b; // +: A hotter, -: B hotter
 
-                // Cross-sectional contact area: A = π·r² where r = min(r1, r2)
+                // Cross-sectional contact area: A = π·r² where r = min(w1, w2)
                 //
-                // **LP-0 APPROXIMATION**: Assumes spherical particles in contact.
-                // Future: MPM will use material point volume overlap.
-                let contact_radius = radius_a.min(*radius_b);
+                // **LP-0 APPROXIMATION**: Flat facing sides of width 2r, no overlap
+                // geometry. MPM uses the grid diffusion instead.
                 let contact_area = std::f32::consts::PI * contact_radius.powi(2);
 
                 // Average thermal conductivity (harmonic mean more accurate, but arithmetic for simplicity)
@@ -437,7 +492,7 @@ pub(crate) fn compute_fourier_conduction(
 
     // Apply temperature changes
     for (&entity, &delta) in &temp_changes {
-        if let Ok((_, _, temp, _, _, _)) = entities.get(entity) {
+        if let Ok((_, _, temp, ..)) = entities.get(entity) {
             let new_temp = (temp.value + delta).max(0.0); // Numerical guard: clamp to non-negative temperature
             commands
                 .entity(entity)
@@ -526,6 +581,106 @@ fn check_thermal_stability(
     }
 }
 
+/// Derive thermal components from an entity's [`MaterialId`].
+///
+/// Sets `ThermalConductivity` and `Emissivity`, plus `HeatCapacity` = m·c_p when the
+/// body has a `Mass`. Materials with phase points also get `PhaseTransitions` and, for
+/// bodies with a `Temperature`, the equilibrium `PhaseState` and that phase's c_p;
+/// switching to a material without phase points removes them again.
+/// Runs when the id is added or changed, and for every body when the registry changes
+/// (a library finished loading or was hot-reloaded), so hand-set components on bodies
+/// without a `MaterialId` are never touched.
+#[allow(clippy::type_complexity)]
+pub fn apply_material_thermal_properties(
+    mut commands: Commands,
+    registry: Res<MaterialRegistry>,
+    bodies: Query<(Entity, Ref<MaterialId>, Option<&Mass>, Option<&Temperature>)>,
+) {
+    for (entity, id, mass, temperature) in bodies.iter() {
+        if !id.is_changed() && !registry.is_changed() {
+            continue;
+        }
+        let Some(material) = registry.get(*id) else {
+            // Retried when the registry changes (asset libraries load asynchronously)
+            if id.is_changed() {
+                warn!("Entity {:?} references unknown material {:?}", entity, *id);
+            }
+            continue;
+        };
+        let mut body = commands.entity(entity);
+        body.insert((
+            ThermalConductivity {
+                value: material.thermal_conductivity,
+            },
+            Emissivity::new(material.emissivity),
+        ));
+
+        let mut specific_heat = material.specific_heat;
+        match material.phase {
+            Some(table) => {
+                body.insert(table);
+                if let Some(temperature) = temperature {
+                    let phase = table.phase_at(temperature.value);
+                    specific_heat = table.specific_heat(phase);
+                    body.insert(phase);
+                }
+            }
+            None => {
+                body.remove::<(PhaseTransitions, LatentHeatReservoir, PhaseState)>();
+            }
+        }
+        if let Some(mass) = mass.filter(|mass| !mass.is_infinite) {
+            body.insert(HeatCapacity::from_material(mass.value, specific_heat));
+        }
+    }
+}
+
+/// Resolve phase transitions of thermal bodies after conduction.
+///
+/// **PHYSICS**: Enthalpy method (see `matter::phase`). Conduction moved T with the
+/// current phase's c_p; the excess past a transition temperature goes into the latent
+/// reservoir, and `HeatCapacity` switches to the new phase's c_p once it completes.
+/// Mass is recovered from C = m·c_p, so no `Mass` component is needed.
+fn apply_phase_transitions(
+    mut bodies: Query<(
+        Entity,
+        &mut Temperature,
+        &mut HeatCapacity,
+        &mut PhaseState,
+        &mut LatentHeatReservoir,
+        &PhaseTransitions,
+    )>,
+    mut transitions: MessageWriter<PhaseTransitionEvent>,
+) {
+    for (entity, mut temperature, mut capacity, mut phase, mut reservoir, table) in
+        bodies.iter_mut()
+    {
+        let mass = capacity.value / table.specific_heat(*phase);
+        let (mut next_phase, mut next_temperature, mut next_reservoir) =
+            (*phase, temperature.value, *reservoir);
+        let transition = table.update(&mut next_phase, &mut next_temperature, &mut next_reservoir);
+
+        // Only write on change to keep Changed<Temperature> meaningful
+        if next_temperature != temperature.value {
+            temperature.value = next_temperature;
+        }
+        if next_reservoir != *reservoir {
+            *reservoir = next_reservoir;
+        }
+        if let Some((from, to)) = transition {
+            *phase = to;
+            capacity.value = mass * table.specific_heat(to);
+            transitions.write(PhaseTransitionEvent {
+                entity,
+                from,
+                to,
+                temperature: next_temperature,
+                latent_heat: mass * table.latent_heat_released(from, to),
+            });
+        }
+    }
+}
+
 /// Sync Temperature changes to EnergyQuantity for conservation tracking.
 ///
 /// **LP-0**: Calculates thermal energy as U = m·c_p·T (assumes constant c_p).
@@ -533,7 +688,7 @@ fn check_thermal_stability(
 ///
 /// **Efficiency**: Uses Changed<Temperature> for O(N_changed) instead of O(N).
 /// **Conservation**: Thermal energy tracked, but not yet integrated with ledger.
-fn sync_thermal_energy(
+pub(crate) fn sync_thermal_energy(
     mut commands: Commands,
     changed_temps: Query<(Entity, &Temperature, &HeatCapacity), Changed<Temperature>>,
 ) {
@@ -630,6 +785,7 @@ impl Plugin for ThermalSystemPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<ThermalConductionConfig>()
             .init_resource::<ThermalSanityConfig>()
+            .init_resource::<MaterialRegistry>()
             .register_type::<ThermalConductionConfig>()
             .register_type::<ThermalSanityConfig>()
             .register_type::<Temperature>()
@@ -638,13 +794,18 @@ impl Plugin for ThermalSystemPlugin {
             .register_type::<Emissivity>()
             .register_type::<HeatCapacity>()
             .add_message::<ThermalTransferEvent>()
+            .add_message::<PhaseTransitionEvent>()
             .add_systems(Startup, check_thermal_stability)
             // Marker injection in PreUpdate
             .add_systems(
                 PreUpdate,
-                mark_thermal_entities_spatially_indexed.in_set(SpatialIndexSet::InjectMarkers),
+                (
+                    mark_thermal_entities_spatially_indexed.in_set(SpatialIndexSet::InjectMarkers),
+                    apply_material_thermal_properties
+                        .after(matter::material::register_loaded_materials),
+                ),
             )
-            // Thermal conduction → flush commands → sync energy.
+            // Thermal conduction → flush commands → phase transitions → ionization → sync energy.
             // conduction inserts Temperature via Commands; apply_deferred flushes
             // so sync_thermal_energy sees Changed<Temperature> in the same frame.
             .add_systems(
@@ -652,6 +813,8 @@ impl Plugin for ThermalSystemPlugin {
                 (
                     compute_fourier_conduction,
                     ApplyDeferred,
+                    apply_phase_transitions,
+                    super::ionization::apply_ionization,
                     sync_thermal_energy,
                     check_thermal_sanity_realtime,
                 )
@@ -664,6 +827,8 @@ impl Plugin for ThermalSystemPlugin {
 mod tests {
     use super::thermal_utils::*;
     use super::*;
+    use matter::material::Material;
+    use std::f32::consts::FRAC_PI_2;
 
     #[test]
     fn test_heat_conservation() {
@@ -746,4 +911,184 @@ mod tests {
             "Stefan-Boltzmann mismatch"
         );
     }
+
+    #[test]
+    fn test_water_body_absorbs_latent_heat_before_boiling() {
+        let mut app = App::new();
+        app.add_message::<PhaseTransitionEvent>()
+            .add_systems(Update, apply_phase_transitions);
+
+        // 1 kg of water that conduction pushed 28 K past the boiling point
+        let water = PhaseTransitions::water();
+        let body = app
+            .world_mut()
+            .spawn((
+                Temperature::new(401.15),
+                HeatCapacity::from_material(1.0, water.specific_heat_liquid),
+                PhaseState::Liquid,
+                water,
+            ))
+            .id();
+        app.update();
+
+        // 28 K · 4186 J/(kg·K) ≈ 117 kJ stored, far from L_v = 2.26 MJ: still liquid
+        let world = app.world();
+        assert_eq!(world.get::<Temperature>(body).unwrap().value, 373.15);
+        assert_eq!(*world.get::<PhaseState>(body).unwrap(), PhaseState::Liquid);
+        let stored = world.get::<LatentHeatReservoir>(body).unwrap().stored;
+        assert!((stored - 28.0 * 4186.0).abs() < 1.0);
+
+        // Enough heat to finish boiling: steam, with c_p of the gas
+        app.world_mut().get_mut::<Temperature>(body).unwrap().value += 2.2e6 / 4186.0;
+        app.update();
+        let world = app.world();
+        assert_eq!(*world.get::<PhaseState>(body).unwrap(), PhaseState::Gas);
+        assert_eq!(
+            world.get::<HeatCapacity>(body).unwrap().value,
+            water.specific_heat_gas
+        );
+        assert!(world.get::<Temperature>(body).unwrap().value > 373.15);
+    }
+
+    #[test]
+    fn test_material_id_sets_thermal_components() {
+        let mut app = App::new();
+        app.init_resource::<MaterialRegistry>()
+            .add_systems(Update, apply_material_thermal_properties);
+
+        let ice = app
+            .world_mut()
+            .spawn((
+                MaterialId::new("ice"),
+                Mass::new(2.0),
+                Temperature::new(260.0),
+            ))
+            .id();
+        app.update();
+
+        let world = app.world();
+        assert_eq!(world.get::<ThermalConductivity>(ice).unwrap().value, 2.2);
+        assert_eq!(world.get::<Emissivity>(ice).unwrap().value, 0.97);
+        assert_eq!(*world.get::<PhaseState>(ice).unwrap(), PhaseState::Solid);
+        assert_eq!(world.get::<HeatCapacity>(ice).unwrap().value, 2.0 * 2090.0);
+
+        // Switching material re-derives everything; iron uses its own phase table
+        app.world_mut()
+            .entity_mut(ice)
+            .insert(MaterialId::new("iron"));
+        app.update();
+        let world = app.world();
+        assert_eq!(world.get::<ThermalConductivity>(ice).unwrap().value, 80.2);
+        assert_eq!(
+            *world.get::<PhaseTransitions>(ice).unwrap(),
+            PhaseTransitions::iron()
+        );
+        assert_eq!(world.get::<HeatCapacity>(ice).unwrap().value, 2.0 * 449.0);
+
+        // Wood has no phase points: the phase components go away
+        app.world_mut()
+            .entity_mut(ice)
+            .insert(MaterialId::new("wood"));
+        app.update();
+        let world = app.world();
+        assert!(world.get::<PhaseTransitions>(ice).is_none());
+        assert!(world.get::<LatentHeatReservoir>(ice).is_none());
+        assert!(world.get::<PhaseState>(ice).is_none());
+        assert_eq!(world.get::<HeatCapacity>(ice).unwrap().value, 2.0 * 1700.0);
+    }
+
+    #[test]
+    fn test_material_registered_after_spawn_still_applies() {
+        let mut app = App::new();
+        app.insert_resource(MaterialRegistry::empty())
+            .add_systems(Update, apply_material_thermal_properties);
+
+        // The library has not loaded yet: the lookup misses
+        let body = app
+            .world_mut()
+            .spawn((MaterialId::new("brick"), Mass::new(2.0)))
+            .id();
+        app.update();
+        assert!(app.world().get::<ThermalConductivity>(body).is_none());
+
+        let mut brick = Material::new("brick");
+        brick.thermal_conductivity = 0.7;
+        brick.specific_heat = 840.0;
+        app.world_mut()
+            .resource_mut::<MaterialRegistry>()
+            .insert(brick.clone());
+        app.update();
+        let world = app.world();
+        assert_eq!(world.get::<ThermalConductivity>(body).unwrap().value, 0.7);
+        assert_eq!(world.get::<HeatCapacity>(body).unwrap().value, 2.0 * 840.0);
+
+        // Hot reload: existing bodies pick up the new values
+        brick.thermal_conductivity = 0.9;
+        app.world_mut()
+            .resource_mut::<MaterialRegistry>()
+            .insert(brick);
+        app.update();
+        assert_eq!(
+            app.world().get::<ThermalConductivity>(body).unwrap().value,
+            0.9
+        );
+    }
+
+    #[test]
+    fn test_contact_radius_follows_shape_orientation() {
+        let capsule = Shape::capsule(1.0, 0.25);
+        let upright = ContactProfile::Shaped(capsule.clone(), Quat::IDENTITY);
+        let lying = ContactProfile::Shaped(capsule, Quat::from_rotation_z(FRAC_PI_2));
+
+        // Neighbour to the right: the upright capsule shows its long side
+        assert!((upright.contact_radius(Vec2::X) - 1.25).abs() < 1e-5);
+        assert!((upright.contact_radius(Vec2::Y) - 0.25).abs() < 1e-5);
+        assert!((lying.contact_radius(Vec2::X) - 0.25).abs() < 1e-5);
+        assert_eq!(ContactProfile::Round(0.5).contact_radius(Vec2::Y), 0.5);
+        assert!((upright.reach(Vec2::X) - 0.25).abs() < 1e-5);
+        assert!((upright.reach(Vec2::Y) - 1.25).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_shaped_bodies_touching_side_by_side_conduct() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+                std::time::Duration::from_secs_f32(0.01),
+            ))
+            .init_resource::<UnifiedSpatialIndex>()
+            .init_resource::<ThermalConductionConfig>()
+            .init_resource::<PairwiseDeterminismConfig>()
+            .add_message::<ThermalTransferEvent>()
+            .add_systems(Update, compute_fourier_conduction);
+
+        // Upright capsules 0.5 m apart: surfaces touch, centers closer than their height
+        let mut spawn = |x: f32, kelvin: f32| {
+            let entity = app
+                .world_mut()
+                .spawn((
+                    Transform::from_xyz(x, 0.0, 0.0),
+                    Temperature::new(kelvin),
+                    ThermalConductivity { value: 50.0 },
+                    HeatCapacity { value: 1000.0 },
+                    Shape::capsule(1.0, 0.25),
+                ))
+                .id();
+            app.world_mut()
+                .resource_mut::<UnifiedSpatialIndex>()
+                .insert(entity, Vec2::new(x, 0.0));
+            entity
+        };
+        let hot = spawn(0.0, 400.0);
+        let cold = spawn(0.5, 300.0);
+        app.update();
+        app.update();
+
+        let world = app.world();
+        let t_hot = world.get::<Temperature>(hot).unwrap().value;
+        let t_cold = world.get::<Temperature>(cold).unwrap().value;
+        assert!(t_hot < 400.0 && t_cold > 300.0);
+        // Equal capacities: what one loses the other gains
+        assert!(((400.0 - t_hot) - (t_cold - 300.0)).abs() < 1e-3);
+    }
 }
@@ -0,0 +1,571 @@
+//! Platformer character controller on top of Newtonian bodies.
+//!
+//! **ARCHITECTURE**: The controller never moves the body kinematically except for
+//! step-up. Walking is an `AppliedForce` toward the desired velocity, jumping is a
+//! `Velocity` change, so gravity, drag, contacts and joints act on characters the
+//! same way they act on creatures. Game input writes [`CharacterInput`]; the
+//! controller writes [`CharacterState`] for animation and gameplay.
+//!
+//! **LP-0**: Ground, walls and steps are found by casting the character's own
+//! `Shape` against [`Collider`] bodies found through `UnifiedSpatialIndex`. Give the
+//! character a `Collider` with low friction so walls do not hold it up. Sensors and
+//! bodies outside the character's `CollisionLayers` are walked through.
+
+use super::contact::{CastHit, Collider, ConvexPiece, body_shape, cast_pieces, transform_pieces};
+use super::layers::CollisionLayers;
+use super::newton_laws::{AppliedForce, Mass, Velocity, WorkDoneEvent};
+use super::triggers::Sensor;
+use crate::PhysicsSet;
+use bevy::prelude::*;
+use matter::geometry::{Radius, Shape};
+use utils::UnifiedSpatialIndex;
+
+/// Extra clearance used when lifting a character onto a step (m).
+const STEP_SKIN: f32 = 0.01;
+
+/// Movement tuning of a player-like body.
+///
+/// **UNITS**: speeds in m/s, accelerations in m/s², times in s, lengths in m,
+/// `max_slope` in radians from horizontal. Characters spawned without a `Mass`
+/// weigh 70 kg.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+#[require(CharacterInput, CharacterState, AppliedForce, Velocity, Mass = Mass::new(70.0))]
+pub struct CharacterController {
+    /// Top walking speed relative to the ground
+    pub move_speed: f32,
+    /// Acceleration toward the walking speed while grounded
+    pub ground_acceleration: f32,
+    /// Acceleration toward the walking speed while airborne
+    pub air_acceleration: f32,
+    /// Vertical launch speed of a full jump
+    pub jump_speed: f32,
+    /// Fraction of upward speed kept when jump is released early (variable height)
+    pub jump_cut: f32,
+    /// Time after leaving the ground during which a jump is still allowed
+    pub coyote_time: f32,
+    /// Steepest walkable surface
+    pub max_slope: f32,
+    /// Tallest ledge climbed without jumping
+    pub step_height: f32,
+    /// How far below the feet ground is still detected
+    pub ground_probe: f32,
+}
+
+impl Default for CharacterController {
+    fn default() -> Self {
+        Self {
+            move_speed: 6.0,
+            ground_acceleration: 60.0,
+            air_acceleration: 20.0,
+            jump_speed: 7.0,
+            jump_cut: 0.5,
+            coyote_time: 0.1,
+            max_slope: 50f32.to_radians(),
+            step_height: 0.25,
+            ground_probe: 0.05,
+        }
+    }
+}
+
+impl CharacterController {
+    fn is_walkable(&self, normal: Vec2) -> bool {
+        normal.y >= self.max_slope.cos()
+    }
+}
+
+/// Intent for this step, written by input or AI.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct CharacterInput {
+    /// Horizontal intent in [-1, 1]
+    pub movement: f32,
+    /// Jump button held
+    pub jump: bool,
+}
+
+/// What the controller found this step.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct CharacterState {
+    pub grounded: bool,
+    /// Normal of the ground under the feet (up when airborne)
+    pub ground_normal: Vec2,
+    /// Body standing on, for moving platforms
+    pub ground: Option<Entity>,
+    /// Velocity of the ground body (m/s)
+    pub ground_velocity: Vec2,
+    /// Time since last grounded (s)
+    pub time_since_grounded: f32,
+    /// Rising from a jump that can still be cut short
+    pub jumping: bool,
+    jump_held: bool,
+}
+
+impl Default for CharacterState {
+    fn default() -> Self {
+        Self {
+            grounded: false,
+            ground_normal: Vec2::Y,
+            ground: None,
+            ground_velocity: Vec2::ZERO,
+            time_since_grounded: f32::INFINITY,
+            jumping: false,
+            jump_held: false,
+        }
+    }
+}
+
+struct Obstacle {
+    pieces: std::ops::Range<usize>,
+    velocity: Vec2,
+    layers: CollisionLayers,
+}
+
+#[derive(Default)]
+pub(crate) struct CharacterScratch {
+    obstacles: Vec<Obstacle>,
+    lookup: std::collections::HashMap<Entity, usize>,
+    pieces: Vec<ConvexPiece>,
+    body: Vec<ConvexPiece>,
+    candidates: Vec<Entity>,
+}
+
+impl CharacterScratch {
+    /// Nearest hit of `body` swept along `direction` against the candidates.
+    fn cast(
+        &self,
+        body: &[ConvexPiece],
+        direction: Vec2,
+        max_distance: f32,
+    ) -> Option<(Entity, CastHit)> {
+        let mut best: Option<(Entity, CastHit)> = None;
+        for entity in &self.candidates {
+            let Some(&index) = self.lookup.get(entity) else {
+                continue;
+            };
+            let obstacle = &self.obstacles[index];
+            let target = &self.pieces[obstacle.pieces.clone()];
+            if let Some(hit) = cast_pieces(body, direction, max_distance, target)
+                && best.is_none_or(|(_, b)| hit.distance < b.distance)
+            {
+                best = Some((*entity, hit));
+            }
+        }
+        best.filter(|(_, hit)| hit.distance <= max_distance)
+    }
+}
+
+fn lowest_point(pieces: &[ConvexPiece]) -> f32 {
+    pieces
+        .iter()
+        .flat_map(|p| p.vertices.iter().map(move |v| v.y - p.radius))
+        .fold(f32::INFINITY, f32::min)
+}
+
+fn shifted(pieces: &[ConvexPiece], offset: Vec2) -> Vec<ConvexPiece> {
+    pieces.iter().map(|p| p.translated(offset)).collect()
+}
+
+/// Ground detection, walking, jumping and step-up for every character.
+///
+/// **PHYSICS**: Walking adds F = m·Δv/dt along the ground tangent, with Δv toward
+/// `move_speed` (relative to the ground body) limited by the current acceleration.
+/// Jump launches and cuts change `Velocity` directly and are reported as
+/// [`WorkDoneEvent`] (muscle work).
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub(crate) fn update_character_controllers(
+    time: Res<Time>,
+    index: Res<UnifiedSpatialIndex>,
+    mut bodies: ParamSet<(
+        Query<
+            (
+                Entity,
+                &Transform,
+                Option<&Shape>,
+                Option<&Radius>,
+                Option<&Velocity>,
+                Option<&CollisionLayers>,
+            ),
+            (With<Collider>, Without<Sensor>),
+        >,
+        Query<(
+            Entity,
+            &CharacterController,
+            &CharacterInput,
+            &mut CharacterState,
+            &Mass,
+            &mut Transform,
+            &mut Velocity,
+            &mut AppliedForce,
+            Option<&Shape>,
+            Option<&Radius>,
+            Option<&CollisionLayers>,
+        )>,
+    )>,
+    mut work: MessageWriter<WorkDoneEvent>,
+    mut ctx: Local<CharacterScratch>,
+) {
+    let dt = time.delta_secs();
+    if dt <= 0.0 {
+        return;
+    }
+    let ctx = &mut *ctx;
+    ctx.obstacles.clear();
+    ctx.lookup.clear();
+    ctx.pieces.clear();
+
+    let mut max_radius = 0.0_f32;
+    for (entity, transform, shape, radius, velocity, layers) in bodies.p0().iter() {
+        let Some(shape) = body_shape(shape, radius) else {
+            continue;
+        };
+        let start = ctx.pieces.len();
+        transform_pieces(&shape, transform, &mut ctx.pieces);
+        max_radius = max_radius.max(shape.bounding_radius());
+        ctx.lookup.insert(entity, ctx.obstacles.len());
+        ctx.obstacles.push(Obstacle {
+            pieces: start..ctx.pieces.len(),
+            velocity: velocity.map_or(Vec2::ZERO, |v| v.linvel.truncate()),
+            layers: layers.copied().unwrap_or_default(),
+        });
+    }
+
+    for (
+        entity,
+        controller,
+        input,
+        mut state,
+        mass,
+        mut transform,
+        mut velocity,
+        mut force,
+        shape,
+        radius,
+        layers,
+    ) in bodies.p1().iter_mut()
+    {
+        let Some(shape) = body_shape(shape, radius) else {
+            continue;
+        };
+        ctx.body.clear();
+        transform_pieces(&shape, &transform, &mut ctx.body);
+        let origin = transform.translation.truncate();
+        let reach = controller.step_height.max(controller.ground_probe)
+            + velocity.linvel.truncate().length() * dt
+            + 2.0 * STEP_SKIN;
+        ctx.candidates.clear();
+        index.for_each_neighbor_candidate_in_radius(
+            origin,
+            shape.bounding_radius() + reach + max_radius,
+            |e| ctx.candidates.push(e),
+        );
+        let layers = layers.copied().unwrap_or_default();
+        ctx.candidates.retain(|e| {
+            *e != entity
+                && ctx
+                    .lookup
+                    .get(e)
+                    .is_some_and(|&i| ctx.obstacles[i].layers.interacts_with(&layers))
+        });
+        ctx.candidates.sort_by_key(|e| e.to_bits());
+        let body = std::mem::take(&mut ctx.body);
+
+        // Ground: walkable surface under the feet that we are not leaving
+        let ground = ctx
+            .cast(&body, Vec2::NEG_Y, controller.ground_probe)
+            .filter(|(_, hit)| controller.is_walkable(hit.normal))
+            .map(|(e, hit)| (e, hit, ctx.obstacles[ctx.lookup[&e]].velocity))
+            .filter(|(_, hit, ground_velocity)| {
+                (velocity.linvel.truncate() - *ground_velocity).dot(hit.normal) <= 0.1
+            });
+        state.grounded = ground.is_some();
+        match ground {
+            Some((e, hit, ground_velocity)) => {
+                state.ground = Some(e);
+                state.ground_normal = hit.normal;
+                state.ground_velocity = ground_velocity;
+                state.time_since_grounded = 0.0;
+                state.jumping = false;
+            }
+            None => {
+                state.ground = None;
+                state.ground_normal = Vec2::Y;
+                state.time_since_grounded += dt;
+            }
+        }
+
+        // Walk along the ground tangent toward the target speed
+        let tangent = if state.grounded {
+            -state.ground_normal.perp()
+        } else {
+            Vec2::X
+        };
+        let movement = input.movement.clamp(-1.0, 1.0);
+        let carried = state.ground_velocity.dot(tangent);
+        let target = carried + movement * controller.move_speed;
+        let current = velocity.linvel.truncate().dot(tangent);
+        let acceleration = if state.grounded {
+            controller.ground_acceleration
+        } else {
+            controller.air_acceleration
+        };
+        let max_change = acceleration * dt;
+        // In the air without input, keep momentum
+        if state.grounded || movement != 0.0 {
+            let change = (target - current).clamp(-max_change, max_change);
+            force.force += (mass.value * change / dt * tangent).extend(0.0);
+        }
+
+        // Jump (with coyote time) and variable height
+        let pressed = input.jump && !state.jump_held;
+        state.jump_held = input.jump;
+        let before = velocity.linvel;
+        if pressed && !state.jumping && state.time_since_grounded <= controller.coyote_time {
+            velocity.linvel.y = state.ground_velocity.y.max(0.0) + controller.jump_speed;
+            state.jumping = true;
+            state.grounded = false;
+            // Spend the coyote window so the jump cannot repeat in the air
+            state.time_since_grounded = f32::INFINITY;
+        } else if state.jumping && !input.jump {
+            if velocity.linvel.y > 0.0 {
+                velocity.linvel.y *= controller.jump_cut;
+            }
+            state.jumping = false;
+        }
+        if velocity.linvel != before && !mass.is_infinite {
+            work.write(WorkDoneEvent {
+                entity,
+                work: 0.5
+                    * mass.value
+                    * (velocity.linvel.length_squared() - before.length_squared()),
+            });
+        }
+
+        // Step-up: blocked by a wall low enough to stand on
+        if state.grounded && movement != 0.0 {
+            let forward = Vec2::X * movement.signum();
+            let ahead = (velocity.linvel.x.abs() * dt).max(STEP_SKIN) + STEP_SKIN;
+            let blocked = ctx
+                .cast(&body, forward, ahead)
+                .is_some_and(|(_, hit)| !controller.is_walkable(hit.normal));
+            let lift = Vec2::Y * controller.step_height;
+            if blocked && ctx.cast(&body, Vec2::Y, controller.step_height).is_none() {
+                let raised = shifted(&body, lift);
+                if ctx.cast(&raised, forward, ahead).is_none() {
+                    let probe = shifted(&raised, forward * ahead);
+                    // Rounded feet meet the step edge at a slant, so lift the lowest
+                    // point of the body to the edge rather than to the contact normal
+                    if let Some((_, hit)) = ctx.cast(&probe, Vec2::NEG_Y, controller.step_height)
+                        && hit.normal.y > 0.0
+                    {
+                        let rise = hit.point.y - lowest_point(&body);
+                        if rise > STEP_SKIN && rise <= controller.step_height {
+                            transform.translation.y += rise + STEP_SKIN;
+                            velocity.linvel.y = velocity.linvel.y.max(0.0);
+                        }
+                    }
+                }
+            }
+        }
+        ctx.body = body;
+    }
+}
+
+/// Platformer character controller.
+pub struct CharacterControllerPlugin;
+
+impl Plugin for CharacterControllerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UnifiedSpatialIndex>()
+            .register_type::<CharacterController>()
+            .register_type::<CharacterInput>()
+            .register_type::<CharacterState>()
+            .add_message::<WorkDoneEvent>()
+            .add_systems(
+                FixedUpdate,
+                update_character_controllers.in_set(PhysicsSet::AccumulateForces),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    const DT: f32 = 1.0 / 60.0;
+
+    fn character_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<UnifiedSpatialIndex>()
+            .add_message::<WorkDoneEvent>()
+            .insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+                Duration::from_secs_f32(DT),
+            ))
+            .add_systems(
+                Update,
+                (
+                    update_character_controllers,
+                    // Horizontal-only integration: vertical support comes from contacts
+                    |mut q: Query<(&mut Transform, &mut Velocity, &mut AppliedForce, &Mass)>| {
+                        for (mut transform, mut velocity, mut force, mass) in q.iter_mut() {
+                            velocity.linvel.x += force.force.x * mass.inverse() * DT;
+                            transform.translation.x += velocity.linvel.x * DT;
+                            force.force = Vec3::ZERO;
+                        }
+                    },
+                )
+                    .chain(),
+            );
+        app
+    }
+
+    fn spawn_indexed(app: &mut App, position: Vec2, bundle: impl Bundle) -> Entity {
+        let entity = app
+            .world_mut()
+            .spawn((Transform::from_translation(position.extend(0.0)), bundle))
+            .id();
+        app.world_mut()
+            .resource_mut::<UnifiedSpatialIndex>()
+            .insert(entity, position);
+        entity
+    }
+
+    /// Capsule player standing 1 cm above a wide floor whose top is y = 0.
+    fn player_on_floor(app: &mut App, floor_velocity: Vec2) -> (Entity, Entity) {
+        let floor = spawn_indexed(
+            app,
+            Vec2::new(0.0, -0.5),
+            (
+                Collider::default(),
+                Shape::rectangle(Vec2::new(20.0, 0.5)),
+                Velocity {
+                    linvel: floor_velocity.extend(0.0),
+                    angvel: Vec3::ZERO,
+                },
+                Mass::infinite(),
+            ),
+        );
+        let player = spawn_indexed(
+            app,
+            Vec2::new(0.0, 0.91),
+            (
+                // No `Mass`: the controller's 70 kg default
+                CharacterController::default(),
+                Collider::new(0.0, 0.0),
+                Shape::capsule(0.5, 0.4),
+            ),
+        );
+        (player, floor)
+    }
+
+    fn input(app: &mut App, player: Entity, movement: f32, jump: bool) {
+        *app.world_mut().get_mut::<CharacterInput>(player).unwrap() =
+            CharacterInput { movement, jump };
+    }
+
+    #[test]
+    fn test_jump_height_follows_button_hold() {
+        let mut app = character_app();
+        let (player, _) = player_on_floor(&mut app, Vec2::ZERO);
+        app.update();
+        app.update();
+        assert!(app.world().get::<CharacterState>(player).unwrap().grounded);
+
+        let controller = CharacterController::default();
+        let mass = app.world().get::<Mass>(player).unwrap().value;
+        assert_eq!(mass, 70.0);
+        let vy = |app: &App| app.world().get::<Velocity>(player).unwrap().linvel.y;
+        let work = |app: &mut App| -> f32 {
+            app.world_mut()
+                .resource_mut::<Messages<WorkDoneEvent>>()
+                .drain()
+                .map(|e| e.work)
+                .sum()
+        };
+        work(&mut app);
+
+        // Launch at exactly the jump speed, paid for by the legs
+        input(&mut app, player, 0.0, true);
+        app.update();
+        assert!((vy(&app) - controller.jump_speed).abs() < 1e-5);
+        let launch = 0.5 * mass * controller.jump_speed * controller.jump_speed;
+        assert!((work(&mut app) - launch).abs() < 1e-3 * launch);
+
+        // Holding does not re-trigger or cut the rise
+        app.update();
+        assert!((vy(&app) - controller.jump_speed).abs() < 1e-5);
+        assert_eq!(work(&mut app), 0.0);
+
+        // Releasing keeps only `jump_cut` of the upward speed
+        input(&mut app, player, 0.0, false);
+        app.update();
+        let cut = controller.jump_cut * controller.jump_speed;
+        assert!((vy(&app) - cut).abs() < 1e-5);
+        let removed = 0.5 * mass * (cut * cut - controller.jump_speed * controller.jump_speed);
+        assert!((work(&mut app) - removed).abs() < 1e-3 * removed.abs());
+    }
+
+    #[test]
+    fn test_coyote_time_allows_late_jumps_only() {
+        let mut app = character_app();
+        let (player, floor) = player_on_floor(&mut app, Vec2::ZERO);
+        app.update();
+        app.update();
+
+        // Walk off the ledge: the floor is gone, a jump 2 frames later still works
+        app.world_mut().despawn(floor);
+        app.update();
+        app.update();
+        input(&mut app, player, 0.0, true);
+        app.update();
+        assert!(app.world().get::<CharacterState>(player).unwrap().jumping);
+
+        // Too late: after the coyote window nothing happens
+        let mut app = character_app();
+        let (player, floor) = player_on_floor(&mut app, Vec2::ZERO);
+        app.update();
+        app.update();
+        app.world_mut().despawn(floor);
+        for _ in 0..10 {
+            app.update();
+        }
+        input(&mut app, player, 0.0, true);
+        app.update();
+        assert!(!app.world().get::<CharacterState>(player).unwrap().jumping);
+        assert_eq!(app.world().get::<Velocity>(player).unwrap().linvel.y, 0.0);
+    }
+
+    #[test]
+    fn test_walking_carries_on_platforms_and_climbs_steps() {
+        // Standing still on a platform moving right: pushed to match it
+        let mut app = character_app();
+        let (player, floor) = player_on_floor(&mut app, Vec2::new(2.0, 0.0));
+        app.update();
+        app.update();
+        let state = *app.world().get::<CharacterState>(player).unwrap();
+        assert_eq!(state.ground, Some(floor));
+        assert!(app.world().get::<Velocity>(player).unwrap().linvel.x > 0.0);
+
+        // Walking right into a 20 cm ledge lifts the player onto it
+        let mut app = character_app();
+        let (player, _) = player_on_floor(&mut app, Vec2::ZERO);
+        spawn_indexed(
+            &mut app,
+            Vec2::new(1.35, 0.1),
+            (Collider::default(), Shape::rectangle(Vec2::new(1.0, 0.1))),
+        );
+        input(&mut app, player, 1.0, false);
+        for _ in 0..30 {
+            app.update();
+        }
+        let transform = *app.world().get::<Transform>(player).unwrap();
+        assert!(transform.translation.x > 0.5);
+        // Capsule feet (0.9 m below the center) stand on the ledge top
+        let feet = transform.translation.y - 0.9;
+        assert!(feet > 0.19 && feet < 0.26, "feet {feet}");
+        assert!(app.world().get::<CharacterState>(player).unwrap().grounded);
+    }
+}
@@ -0,0 +1,1043 @@
+//! Contact and collision physics for Newtonian bodies.
+//!
+//! **PHYSICS**: Bodies with a [`Collider`] and a `Shape` (or `Radius`) stop passing
+//! through each other. Broadphase candidates come from `UnifiedSpatialIndex`; the
+//! narrowphase builds contact manifolds between circles, capsules and convex
+//! polygons; a sequential-impulse solver applies non-penetration, restitution and
+//! Coulomb friction.
+//!
+//! **CONSERVATION**: Contact impulses are equal and opposite (momentum conserved)
+//! and go through [`ForceImpulse`]. The kinetic energy each body gains or loses is
+//! reported as [`WorkDoneEvent`] / [`RotationalWorkEvent`], so the energy lost in
+//! inelastic collisions and friction shows up in the ledger.
+//!
+//! **NUMERICAL**: Penetration left after the velocity solve is removed by moving the
+//! bodies apart (split position correction), which does not inject kinetic energy.
+
+use super::layers::CollisionLayers;
+use super::newton_laws::{
+    ForceImpulse, Mass, MomentOfInertia, RotationalWorkEvent, Velocity, WorkDoneEvent,
+    apply_impulses,
+};
+use super::triggers::Sensor;
+use crate::PhysicsSet;
+use bevy::prelude::*;
+use matter::geometry::{Radius, Shape};
+use std::borrow::Cow;
+use utils::{SpatialIndexSet, SpatiallyIndexed, UnifiedSpatialIndex};
+
+/// Surface response of a colliding body.
+///
+/// **Property-based**: Pairs combine as restitution e = max(e₁, e₂) and friction
+/// μ = √(μ₁·μ₂). A collider without `Mass` and `Velocity` is static (ground, walls).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct Collider {
+    /// Coefficient of restitution e ∈ [0, 1] (dimensionless)
+    pub restitution: f32,
+    /// Coefficient of friction μ (dimensionless)
+    pub friction: f32,
+}
+
+impl Default for Collider {
+    fn default() -> Self {
+        Self {
+            restitution: 0.2,
+            friction: 0.5,
+        }
+    }
+}
+
+impl Collider {
+    pub fn new(restitution: f32, friction: f32) -> Self {
+        Self {
+            restitution: restitution.clamp(0.0, 1.0),
+            friction: friction.max(0.0),
+        }
+    }
+
+    /// Perfectly elastic, frictionless.
+    pub fn elastic() -> Self {
+        Self::new(1.0, 0.0)
+    }
+}
+
+/// Contact solver tuning.
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct ContactConfig {
+    /// Sequential impulse iterations per step
+    pub iterations: usize,
+    /// Approach speed below which contacts do not bounce (m/s)
+    pub restitution_threshold: f32,
+    /// Penetration left uncorrected to keep resting contacts warm (m)
+    pub penetration_slop: f32,
+    /// Fraction of the remaining penetration removed per step (dimensionless)
+    pub position_correction: f32,
+}
+
+impl Default for ContactConfig {
+    fn default() -> Self {
+        Self {
+            iterations: 8,
+            restitution_threshold: 0.5,
+            penetration_slop: 0.005,
+            position_correction: 0.8,
+        }
+    }
+}
+
+/// One contact point between two colliders.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Contact {
+    pub a: Entity,
+    pub b: Entity,
+    /// World-space contact point (m)
+    pub point: Vec2,
+    /// Unit normal pointing from `a` to `b`
+    pub normal: Vec2,
+    /// Penetration depth (m, > 0 while touching)
+    pub depth: f32,
+    /// Normal impulse applied by the solver this step (N·s)
+    pub normal_impulse: f32,
+    /// Friction impulse applied by the solver this step (N·s)
+    pub tangent_impulse: f32,
+}
+
+/// Contacts found in the last fixed step.
+#[derive(Resource, Debug, Default)]
+pub struct Contacts {
+    contacts: Vec<Contact>,
+}
+
+impl Contacts {
+    pub fn iter(&self) -> impl Iterator<Item = &Contact> {
+        self.contacts.iter()
+    }
+
+    /// Contacts touching `entity`, with the normal flipped to point away from it.
+    pub fn involving(&self, entity: Entity) -> impl Iterator<Item = Contact> + '_ {
+        self.contacts.iter().filter_map(move |contact| {
+            if contact.a == entity {
+                Some(*contact)
+            } else if contact.b == entity {
+                Some(Contact {
+                    a: contact.b,
+                    b: contact.a,
+                    normal: -contact.normal,
+                    ..*contact
+                })
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.contacts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.contacts.is_empty()
+    }
+}
+
+/// Convex piece of a collider in world space: the hull of `vertices` swept by
+/// `radius`. One vertex is a circle, two a capsule, three or more a polygon (CCW).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvexPiece {
+    pub vertices: Vec<Vec2>,
+    pub radius: f32,
+}
+
+impl ConvexPiece {
+    /// Closed edges of the core; a segment counts once, a point not at all.
+    fn edges(&self) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+        let n = self.vertices.len();
+        let count = match n {
+            0 | 1 => 0,
+            2 => 1,
+            _ => n,
+        };
+        (0..count).map(move |i| (self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+
+    /// Separating axis candidates: edge normals, plus the direction of a segment.
+    fn axes(&self) -> impl Iterator<Item = Vec2> + '_ {
+        let segment = (self.vertices.len() == 2).then(|| self.vertices[1] - self.vertices[0]);
+        self.edges()
+            .map(|(a, b)| (b - a).perp())
+            .chain(segment)
+            .filter_map(|axis| axis.try_normalize())
+    }
+
+    fn project(&self, axis: Vec2) -> (f32, f32) {
+        self.vertices
+            .iter()
+            .map(|v| v.dot(axis))
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), d| {
+                (lo.min(d), hi.max(d))
+            })
+    }
+
+    fn center(&self) -> Vec2 {
+        self.vertices.iter().sum::<Vec2>() / self.vertices.len().max(1) as f32
+    }
+
+    /// Closest point of the core to `point`.
+    pub fn closest_core_point(&self, point: Vec2) -> Vec2 {
+        match self.vertices.len() {
+            0 => point,
+            1 => self.vertices[0],
+            _ => self
+                .edges()
+                .map(|(a, b)| closest_on_segment(point, a, b))
+                .min_by(|p, q| {
+                    p.distance_squared(point)
+                        .total_cmp(&q.distance_squared(point))
+                })
+                .unwrap_or(self.vertices[0]),
+        }
+    }
+
+    /// The same piece moved by `offset`.
+    pub fn translated(&self, offset: Vec2) -> Self {
+        Self {
+            vertices: self.vertices.iter().map(|v| *v + offset).collect(),
+            radius: self.radius,
+        }
+    }
+
+    /// Whether the core polygon contains `point` (never true for points and segments).
+    pub fn core_contains(&self, point: Vec2) -> bool {
+        self.vertices.len() >= 3
+            && self
+                .edges()
+                .all(|(a, b)| (b - a).perp_dot(point - a) >= 0.0)
+    }
+}
+
+/// Collision shape of a body: its `Shape`, or a circle from its `Radius`.
+pub fn body_shape<'a>(shape: Option<&'a Shape>, radius: Option<&Radius>) -> Option<Cow<'a, Shape>> {
+    match (shape, radius) {
+        (Some(shape), _) => Some(Cow::Borrowed(shape)),
+        (None, Some(radius)) => Some(Cow::Owned(Shape::circle(radius.value))),
+        (None, None) => None,
+    }
+}
+
+/// Append the world-space convex pieces of `shape` placed by `transform`.
+pub fn transform_pieces(shape: &Shape, transform: &Transform, out: &mut Vec<ConvexPiece>) {
+    collider_pieces(
+        shape,
+        transform.translation.truncate(),
+        transform.rotation.to_euler(EulerRot::ZYX).0,
+        out,
+    );
+}
+
+/// Append the world-space convex pieces of `shape` placed at `origin`, rotated by
+/// `rotation` (radians).
+pub fn collider_pieces(shape: &Shape, origin: Vec2, rotation: f32, out: &mut Vec<ConvexPiece>) {
+    let rotate = Vec2::from_angle(rotation);
+    match shape {
+        Shape::Circle { radius } => out.push(ConvexPiece {
+            vertices: vec![origin],
+            radius: *radius,
+        }),
+        Shape::Capsule {
+            half_length,
+            radius,
+        } => {
+            let axis = rotate.rotate(Vec2::Y) * *half_length;
+            let vertices = if *half_length > 0.0 {
+                vec![origin - axis, origin + axis]
+            } else {
+                vec![origin]
+            };
+            out.push(ConvexPiece {
+                vertices,
+                radius: *radius,
+            });
+        }
+        Shape::ConvexPolygon { vertices } => out.push(ConvexPiece {
+            vertices: vertices
+                .iter()
+                .map(|v| origin + rotate.rotate(*v))
+                .collect(),
+            radius: 0.0,
+        }),
+        Shape::Compound { parts } => {
+            for part in parts {
+                collider_pieces(
+                    &part.shape,
+                    origin + rotate.rotate(part.offset),
+                    rotation + part.rotation,
+                    out,
+                );
+            }
+        }
+    }
+}
+
+fn closest_on_segment(point: Vec2, a: Vec2, b: Vec2) -> Vec2 {
+    let ab = b - a;
+    let t = ((point - a).dot(ab) / ab.length_squared().max(f32::EPSILON)).clamp(0.0, 1.0);
+    a + ab * t
+}
+
+/// Closest pair of points between two separated cores.
+fn closest_core_points(p: &ConvexPiece, q: &ConvexPiece) -> (Vec2, Vec2) {
+    let mut best = (p.vertices[0], q.vertices[0]);
+    let mut best_distance = f32::INFINITY;
+    let mut consider = |a: Vec2, b: Vec2| {
+        let d = a.distance_squared(b);
+        if d < best_distance {
+            best_distance = d;
+            best = (a, b);
+        }
+    };
+    for &v in &p.vertices {
+        consider(v, q.closest_core_point(v));
+    }
+    for &v in &q.vertices {
+        consider(p.closest_core_point(v), v);
+    }
+    best
+}
+
+/// Separating axis test on the cores: (penetration, normal p→q, reference is p),
+/// or `None` when the cores do not overlap.
+fn core_overlap(p: &ConvexPiece, q: &ConvexPiece) -> Option<(f32, Vec2, bool)> {
+    let (n, m) = (p.vertices.len(), q.vertices.len());
+    // Points and point-segment pairs only touch through their radii
+    if n < 3 && m < 3 && !(n == 2 && m == 2) {
+        return None;
+    }
+    let mut best: Option<(f32, Vec2, bool)> = None;
+    for (axis, from_p) in p
+        .axes()
+        .map(|a| (a, true))
+        .chain(q.axes().map(|a| (a, false)))
+    {
+        let (p_lo, p_hi) = p.project(axis);
+        let (q_lo, q_hi) = q.project(axis);
+        let forward = p_hi - q_lo;
+        let backward = q_hi - p_lo;
+        if forward <= 0.0 || backward <= 0.0 {
+            return None;
+        }
+        for (overlap, normal) in [(forward, axis), (backward, -axis)] {
+            if best.is_none_or(|(o, ..)| overlap < o) {
+                best = Some((overlap, normal, from_p));
+            }
+        }
+    }
+    best
+}
+
+/// Signed gap between two pieces (m, negative when overlapping) and the unit
+/// normal from `p` to `q`.
+pub fn piece_separation(p: &ConvexPiece, q: &ConvexPiece) -> (f32, Vec2) {
+    let radii = p.radius + q.radius;
+    if let Some((penetration, normal, _)) = core_overlap(p, q) {
+        return (-(penetration + radii), normal);
+    }
+    let (pa, pb) = closest_core_points(p, q);
+    let distance = pa.distance(pb);
+    let normal = if distance > 1e-6 {
+        (pb - pa) / distance
+    } else {
+        (q.center() - p.center()).try_normalize().unwrap_or(Vec2::Y)
+    };
+    (distance - radii, normal)
+}
+
+/// First hit of a swept shape.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CastHit {
+    /// Distance travelled before touching (m, 0 when already overlapping)
+    pub distance: f32,
+    /// World-space point of first contact (m)
+    pub point: Vec2,
+    /// Surface normal of the hit shape, pointing back at the caster
+    pub normal: Vec2,
+}
+
+/// Sweep `moving` along unit `direction` for up to `max_distance` against `target`.
+///
+/// **NUMERICAL**: Conservative advancement: step by the current gap until it
+/// closes below `CAST_TOLERANCE`. Exact for translation; grazing sweeps may take
+/// all `CAST_ITERATIONS` and then report a miss.
+pub fn cast_pieces(
+    moving: &[ConvexPiece],
+    direction: Vec2,
+    max_distance: f32,
+    target: &[ConvexPiece],
+) -> Option<CastHit> {
+    const CAST_TOLERANCE: f32 = 1e-4;
+    const CAST_ITERATIONS: usize = 32;
+
+    let mut best: Option<CastHit> = None;
+    for p in moving {
+        for q in target {
+            let mut travelled = 0.0;
+            for _ in 0..CAST_ITERATIONS {
+                let swept = p.translated(direction * travelled);
+                let (gap, normal) = piece_separation(&swept, q);
+                if gap <= CAST_TOLERANCE {
+                    // Approaching surfaces only; sliding along or away from a
+                    // convex piece can never run into it
+                    if normal.dot(direction) <= 0.0 {
+                        break;
+                    }
+                    if best.is_none_or(|hit| travelled < hit.distance) {
+                        best = Some(CastHit {
+                            distance: travelled,
+                            point: closest_core_points(&swept, q).0 + normal * swept.radius,
+                            normal: -normal,
+                        });
+                    }
+                    break;
+                }
+                travelled += gap;
+                if travelled > max_distance {
+                    break;
+                }
+            }
+        }
+    }
+    best
+}
+
+/// Contact points (point, normal from `p` to `q`, depth) between two pieces.
+///
+/// **NUMERICAL**: Separated cores use their closest points and the swept radii.
+/// Overlapping cores use the separating axis of least penetration; the incident
+/// piece's vertices behind the reference face become the manifold (up to two
+/// points, so boxes rest flat).
+pub fn piece_contacts(p: &ConvexPiece, q: &ConvexPiece, out: &mut Vec<(Vec2, Vec2, f32)>) {
+    if p.vertices.is_empty() || q.vertices.is_empty() {
+        return;
+    }
+    let radii = p.radius + q.radius;
+
+    let Some((penetration, normal, reference_is_p)) = core_overlap(p, q) else {
+        let (pa, pb) = closest_core_points(p, q);
+        let distance = pa.distance(pb);
+        if distance >= radii {
+            return;
+        }
+        let normal = if distance > 1e-6 {
+            (pb - pa) / distance
+        } else {
+            (q.center() - p.center()).try_normalize().unwrap_or(Vec2::Y)
+        };
+        let depth = radii - distance;
+        out.push((pa + normal * (p.radius - 0.5 * depth), normal, depth));
+        return;
+    };
+
+    let start = out.len();
+    if reference_is_p {
+        let face = p.project(normal).1;
+        for &v in &q.vertices {
+            let behind = face - v.dot(normal);
+            if behind >= 0.0 {
+                out.push((v + 0.5 * behind * normal, normal, behind + radii));
+            }
+        }
+    } else {
+        let face = q.project(normal).0;
+        for &v in &p.vertices {
+            let behind = v.dot(normal) - face;
+            if behind >= 0.0 {
+                out.push((v - 0.5 * behind * normal, normal, behind + radii));
+            }
+        }
+    }
+    // Keep the two deepest points
+    let manifold = &mut out[start..];
+    manifold.sort_by(|x, y| y.2.total_cmp(&x.2));
+    let keep = manifold.len().min(2);
+    out.truncate(start + keep);
+    if keep == 0 {
+        out.push((0.5 * (p.center() + q.center()), normal, penetration + radii));
+    }
+}
+
+/// Mark colliders for the shared spatial index.
+pub fn mark_colliders_spatially_indexed(
+    mut commands: Commands,
+    q: Query<Entity, (With<Collider>, Without<SpatiallyIndexed>)>,
+) {
+    for e in q.iter() {
+        commands.entity(e).insert(SpatiallyIndexed);
+    }
+}
+
+struct SolverBody {
+    entity: Entity,
+    collider: Collider,
+    layers: CollisionLayers,
+    pieces: std::ops::Range<usize>,
+    bounding_radius: f32,
+    /// Shape origin (the indexed position)
+    origin: Vec2,
+    /// Center of mass, pivot for contact levers
+    center: Vec2,
+    velocity: Vec2,
+    angular_velocity: f32,
+    inverse_mass: f32,
+    inverse_inertia: f32,
+    correction: Vec2,
+}
+
+impl SolverBody {
+    fn point_velocity(&self, lever: Vec2) -> Vec2 {
+        self.velocity + self.angular_velocity * lever.perp()
+    }
+
+    fn apply(&mut self, impulse: Vec2, lever: Vec2) {
+        self.velocity += impulse * self.inverse_mass;
+        self.angular_velocity += lever.perp_dot(impulse) * self.inverse_inertia;
+    }
+
+    fn is_static(&self) -> bool {
+        self.inverse_mass == 0.0 && self.inverse_inertia == 0.0
+    }
+}
+
+struct Constraint {
+    contact: Contact,
+    a: usize,
+    b: usize,
+    lever_a: Vec2,
+    lever_b: Vec2,
+    normal_mass: f32,
+    tangent_mass: f32,
+    target_velocity: f32,
+    friction: f32,
+}
+
+#[derive(Default)]
+pub(crate) struct ContactScratch {
+    bodies: Vec<SolverBody>,
+    lookup: std::collections::HashMap<Entity, usize>,
+    pieces: Vec<ConvexPiece>,
+    constraints: Vec<Constraint>,
+    manifold: Vec<(Vec2, Vec2, f32)>,
+    neighbor_candidates: Vec<Entity>,
+}
+
+/// Detect contacts and resolve them with sequential impulses.
+///
+/// **PHYSICS**: Per contact, the accumulated normal impulse λₙ ≥ 0 drives the normal
+/// approach speed to -e·vₙ (0 below `restitution_threshold`), and the friction impulse
+/// is clamped to |λₜ| ≤ μ·λₙ.
+///
+/// [`Sensor`]s and pairs whose [`CollisionLayers`] do not interact are skipped.
+///
+/// **DETERMINISM**: Bodies and broadphase candidates are visited in entity order.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub(crate) fn solve_contacts(
+    mut bodies: Query<
+        (
+            Entity,
+            &mut Transform,
+            &Collider,
+            Option<&Shape>,
+            Option<&Radius>,
+            Option<&Mass>,
+            Option<&Velocity>,
+            Option<&MomentOfInertia>,
+            Option<&CollisionLayers>,
+        ),
+        Without<Sensor>,
+    >,
+    index: Res<UnifiedSpatialIndex>,
+    config: Res<ContactConfig>,
+    mut contacts: ResMut<Contacts>,
+    mut impulses: MessageWriter<ForceImpulse>,
+    mut work: MessageWriter<WorkDoneEvent>,
+    mut rotational_work: MessageWriter<RotationalWorkEvent>,
+    mut ctx: Local<ContactScratch>,
+) {
+    let ctx = &mut *ctx;
+    ctx.bodies.clear();
+    ctx.lookup.clear();
+    ctx.pieces.clear();
+    ctx.constraints.clear();
+    contacts.contacts.clear();
+
+    for (entity, transform, collider, shape, radius, mass, velocity, inertia, layers) in
+        bodies.iter()
+    {
+        let Some(shape) = body_shape(shape, radius) else {
+            continue;
+        };
+        let origin = transform.translation.truncate();
+        let rotation = transform.rotation.to_euler(EulerRot::ZYX).0;
+        let start = ctx.pieces.len();
+        collider_pieces(&shape, origin, rotation, &mut ctx.pieces);
+
+        let dynamic = velocity.is_some();
+        let inverse_mass = match (mass, dynamic) {
+            (Some(mass), true) => mass.inverse(),
+            _ => 0.0,
+        };
+        let inverse_inertia = match (inertia, dynamic) {
+            (Some(inertia), true) => inertia.inverse(),
+            _ => 0.0,
+        };
+        let velocity = velocity.copied().unwrap_or_default();
+        ctx.bodies.push(SolverBody {
+            entity,
+            collider: *collider,
+            layers: layers.copied().unwrap_or_default(),
+            pieces: start..ctx.pieces.len(),
+            bounding_radius: shape.bounding_radius(),
+            origin,
+            center: origin + Vec2::from_angle(rotation).rotate(shape.centroid()),
+            velocity: velocity.linvel.truncate(),
+            angular_velocity: velocity.angvel.z,
+            inverse_mass,
+            inverse_inertia,
+            correction: Vec2::ZERO,
+        });
+    }
+    ctx.bodies.sort_by_key(|body| body.entity.to_bits());
+    for (i, body) in ctx.bodies.iter().enumerate() {
+        ctx.lookup.insert(body.entity, i);
+    }
+    let max_radius = ctx
+        .bodies
+        .iter()
+        .map(|body| body.bounding_radius)
+        .fold(0.0_f32, f32::max);
+
+    // Broadphase + narrowphase
+    for i in 0..ctx.bodies.len() {
+        let (entity_a, origin_a, radius_a) = {
+            let body = &ctx.bodies[i];
+            (body.entity, body.origin, body.bounding_radius)
+        };
+        ctx.neighbor_candidates.clear();
+        index.for_each_neighbor_candidate_in_radius(origin_a, radius_a + max_radius, |e| {
+            ctx.neighbor_candidates.push(e)
+        });
+        ctx.neighbor_candidates.sort_by_key(|e| e.to_bits());
+
+        for c in 0..ctx.neighbor_candidates.len() {
+            let entity_b = ctx.neighbor_candidates[c];
+            // Pair-once guarantee: only process pairs where B > A
+            if entity_b.to_bits() <= entity_a.to_bits() {
+                continue;
+            }
+            let Some(&j) = ctx.lookup.get(&entity_b) else {
+                continue;
+            };
+            let (body_a, body_b) = (&ctx.bodies[i], &ctx.bodies[j]);
+            if (body_a.is_static() && body_b.is_static())
+                || !body_a.layers.interacts_with(&body_b.layers)
+            {
+                continue;
+            }
+            ctx.manifold.clear();
+            for p in body_a.pieces.clone() {
+                for q in body_b.pieces.clone() {
+                    piece_contacts(&ctx.pieces[p], &ctx.pieces[q], &mut ctx.manifold);
+                }
+            }
+            for &(point, normal, depth) in &ctx.manifold {
+                let lever_a = point - body_a.center;
+                let lever_b = point - body_b.center;
+                let tangent = normal.perp();
+                let effective = |axis: Vec2| {
+                    body_a.inverse_mass
+                        + body_b.inverse_mass
+                        + body_a.inverse_inertia * lever_a.perp_dot(axis).powi(2)
+                        + body_b.inverse_inertia * lever_b.perp_dot(axis).powi(2)
+                };
+                let approach =
+                    (body_b.point_velocity(lever_b) - body_a.point_velocity(lever_a)).dot(normal);
+                let restitution = body_a.collider.restitution.max(body_b.collider.restitution);
+                let target_velocity = if approach < -config.restitution_threshold {
+                    -restitution * approach
+                } else {
+                    0.0
+                };
+                ctx.constraints.push(Constraint {
+                    contact: Contact {
+                        a: entity_a,
+                        b: entity_b,
+                        point,
+                        normal,
+                        depth,
+                        normal_impulse: 0.0,
+                        tangent_impulse: 0.0,
+                    },
+                    a: i,
+                    b: j,
+                    lever_a,
+                    lever_b,
+                    normal_mass: 1.0 / effective(normal).max(f32::EPSILON),
+                    tangent_mass: 1.0 / effective(tangent).max(f32::EPSILON),
+                    target_velocity,
+                    friction: (body_a.collider.friction * body_b.collider.friction).sqrt(),
+                });
+            }
+        }
+    }
+
+    if ctx.constraints.is_empty() {
+        return;
+    }
+    let initial: Vec<(Vec2, f32)> = ctx
+        .bodies
+        .iter()
+        .map(|body| (body.velocity, body.angular_velocity))
+        .collect();
+
+    // Sequential impulses with accumulated clamping
+    for _ in 0..config.iterations {
+        for constraint in ctx.constraints.iter_mut() {
+            let (a, b) = (constraint.a, constraint.b);
+            let normal = constraint.contact.normal;
+            let tangent = normal.perp();
+
+            let relative = ctx.bodies[b].point_velocity(constraint.lever_b)
+                - ctx.bodies[a].point_velocity(constraint.lever_a);
+            let delta =
+                constraint.normal_mass * (constraint.target_velocity - relative.dot(normal));
+            let previous = constraint.contact.normal_impulse;
+            constraint.contact.normal_impulse = (previous + delta).max(0.0);
+            let impulse = (constraint.contact.normal_impulse - previous) * normal;
+            ctx.bodies[a].apply(-impulse, constraint.lever_a);
+            ctx.bodies[b].apply(impulse, constraint.lever_b);
+
+            let relative = ctx.bodies[b].point_velocity(constraint.lever_b)
+                - ctx.bodies[a].point_velocity(constraint.lever_a);
+            let delta = -constraint.tangent_mass * relative.dot(tangent);
+            let limit = constraint.friction * constraint.contact.normal_impulse;
+            let previous = constraint.contact.tangent_impulse;
+            constraint.contact.tangent_impulse = (previous + delta).clamp(-limit, limit);
+            let impulse = (constraint.contact.tangent_impulse - previous) * tangent;
+            ctx.bodies[a].apply(-impulse, constraint.lever_a);
+            ctx.bodies[b].apply(impulse, constraint.lever_b);
+        }
+    }
+
+    for constraint in &ctx.constraints {
+        let contact = constraint.contact;
+        let impulse = contact.normal_impulse * contact.normal
+            + contact.tangent_impulse * contact.normal.perp();
+        if impulse != Vec2::ZERO {
+            impulses.write(ForceImpulse::at_points(
+                contact.a,
+                contact.b,
+                -impulse.extend(0.0),
+                constraint.lever_a.extend(0.0),
+                constraint.lever_b.extend(0.0),
+            ));
+        }
+        contacts.contacts.push(contact);
+
+        // Split position correction, by inverse mass
+        let (a, b) = (constraint.a, constraint.b);
+        let total = ctx.bodies[a].inverse_mass + ctx.bodies[b].inverse_mass;
+        let excess = (contact.depth - config.penetration_slop).max(0.0);
+        if total > 0.0 && excess > 0.0 {
+            let push = contact.normal * (config.position_correction * excess / total);
+            let push_a = ctx.bodies[a].inverse_mass;
+            let push_b = ctx.bodies[b].inverse_mass;
+            // Several contacts on one body: keep the largest push along each axis
+            let keep = |current: Vec2, wanted: Vec2| {
+                Vec2::new(
+                    if wanted.x.abs() > current.x.abs() {
+                        wanted.x
+                    } else {
+                        current.x
+                    },
+                    if wanted.y.abs() > current.y.abs() {
+                        wanted.y
+                    } else {
+                        current.y
+                    },
+                )
+            };
+            ctx.bodies[a].correction = keep(ctx.bodies[a].correction, -push * push_a);
+            ctx.bodies[b].correction = keep(ctx.bodies[b].correction, push * push_b);
+        }
+    }
+
+    // Report the kinetic energy each body gained (> 0) or lost (< 0)
+    for (body, (velocity, angular_velocity)) in ctx.bodies.iter().zip(initial) {
+        if body.inverse_mass > 0.0 {
+            let linear = 0.5 / body.inverse_mass
+                * (body.velocity.length_squared() - velocity.length_squared());
+            if linear != 0.0 {
+                work.write(WorkDoneEvent {
+                    entity: body.entity,
+                    work: linear,
+                });
+            }
+        }
+        if body.inverse_inertia > 0.0 {
+            let angular = 0.5 / body.inverse_inertia
+                * (body.angular_velocity.powi(2) - angular_velocity.powi(2));
+            if angular != 0.0 {
+                rotational_work.write(RotationalWorkEvent {
+                    entity: body.entity,
+                    work: angular,
+                });
+            }
+        }
+        if body.correction != Vec2::ZERO
+            && let Ok((_, mut transform, ..)) = bodies.get_mut(body.entity)
+        {
+            transform.translation += body.correction.extend(0.0);
+        }
+    }
+}
+
+/// Collision detection and response for [`Collider`] bodies.
+pub struct ContactPlugin;
+
+impl Plugin for ContactPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ContactConfig>()
+            .init_resource::<Contacts>()
+            .init_resource::<UnifiedSpatialIndex>()
+            .register_type::<Collider>()
+            .register_type::<ContactConfig>()
+            .add_message::<ForceImpulse>()
+            .add_message::<WorkDoneEvent>()
+            .add_message::<RotationalWorkEvent>()
+            .add_systems(
+                PreUpdate,
+                mark_colliders_spatially_indexed.in_set(SpatialIndexSet::InjectMarkers),
+            )
+            // Resolve contacts on the velocities that forces are about to integrate
+            .add_systems(
+                FixedUpdate,
+                solve_contacts
+                    .in_set(PhysicsSet::ApplyForces)
+                    .before(apply_impulses),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pieces(shape: &Shape, origin: Vec2, rotation: f32) -> Vec<ConvexPiece> {
+        let mut out = Vec::new();
+        collider_pieces(shape, origin, rotation, &mut out);
+        out
+    }
+
+    fn contacts_between(a: &[ConvexPiece], b: &[ConvexPiece]) -> Vec<(Vec2, Vec2, f32)> {
+        let mut out = Vec::new();
+        for p in a {
+            for q in b {
+                piece_contacts(p, q, &mut out);
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_narrowphase_manifolds() {
+        let ground = pieces(&Shape::rectangle(Vec2::new(5.0, 0.5)), Vec2::ZERO, 0.0);
+
+        // Circle sinking 0.1 m into the ground: normal up, one point
+        let ball = pieces(&Shape::circle(0.5), Vec2::new(1.0, 0.9), 0.0);
+        let hits = contacts_between(&ground, &ball);
+        assert_eq!(hits.len(), 1);
+        assert!((hits[0].1 - Vec2::Y).length() < 1e-5);
+        assert!((hits[0].2 - 0.1).abs() < 1e-5);
+
+        // Box resting flat: two points, same depth
+        let crate_box = pieces(
+            &Shape::rectangle(Vec2::splat(0.5)),
+            Vec2::new(0.0, 0.95),
+            0.0,
+        );
+        let hits = contacts_between(&ground, &crate_box);
+        assert_eq!(hits.len(), 2);
+        for (_, normal, depth) in &hits {
+            assert!((*normal - Vec2::Y).length() < 1e-5);
+            assert!((depth - 0.05).abs() < 1e-5);
+        }
+
+        // Capsule lying across the ground, rotated 90°
+        let capsule = pieces(&Shape::capsule(1.0, 0.25), Vec2::new(0.0, 0.7), FRAC_PI_2);
+        let hits = contacts_between(&ground, &capsule);
+        assert!(!hits.is_empty());
+        assert!(
+            hits.iter()
+                .all(|(_, n, d)| (*n - Vec2::Y).length() < 1e-4 && (d - 0.05).abs() < 1e-4)
+        );
+
+        // Separated shapes and a circle centered inside a box
+        let far = pieces(&Shape::circle(0.5), Vec2::new(0.0, 2.0), 0.0);
+        assert!(contacts_between(&ground, &far).is_empty());
+        let inside = pieces(&Shape::circle(0.2), Vec2::new(0.0, 0.4), 0.0);
+        let hits = contacts_between(&ground, &inside);
+        assert!((hits[0].1 - Vec2::Y).length() < 1e-5);
+        assert!((hits[0].2 - 0.3).abs() < 1e-5);
+    }
+
+    use std::f32::consts::FRAC_PI_2;
+
+    fn contact_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<ContactConfig>()
+            .init_resource::<Contacts>()
+            .init_resource::<UnifiedSpatialIndex>()
+            .add_message::<ForceImpulse>()
+            .add_message::<WorkDoneEvent>()
+            .add_message::<RotationalWorkEvent>()
+            .add_systems(Update, (solve_contacts, apply_impulses).chain());
+        app
+    }
+
+    fn spawn_body(app: &mut App, position: Vec2, components: impl Bundle) -> Entity {
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::from_translation(position.extend(0.0)),
+                components,
+            ))
+            .id();
+        app.world_mut()
+            .resource_mut::<UnifiedSpatialIndex>()
+            .insert(entity, position);
+        entity
+    }
+
+    fn ball(collider: Collider, velocity: Vec2) -> impl Bundle {
+        (
+            collider,
+            Shape::circle(0.5),
+            Mass::new(1.0),
+            Velocity {
+                linvel: velocity.extend(0.0),
+                angvel: Vec3::ZERO,
+            },
+        )
+    }
+
+    fn total_work(app: &App) -> f32 {
+        app.world()
+            .resource::<Messages<WorkDoneEvent>>()
+            .iter_current_update_messages()
+            .map(|event| event.work)
+            .sum::<f32>()
+            + app
+                .world()
+                .resource::<Messages<RotationalWorkEvent>>()
+                .iter_current_update_messages()
+                .map(|event| event.work)
+                .sum::<f32>()
+    }
+
+    #[test]
+    fn test_head_on_collisions_conserve_momentum() {
+        // Elastic: equal masses swap velocities, no energy lost
+        let mut app = contact_app();
+        let a = spawn_body(
+            &mut app,
+            Vec2::ZERO,
+            ball(Collider::elastic(), Vec2::X * 2.0),
+        );
+        let b = spawn_body(
+            &mut app,
+            Vec2::new(0.99, 0.0),
+            ball(Collider::elastic(), Vec2::ZERO),
+        );
+        app.update();
+        let velocity = |app: &App, e: Entity| app.world().get::<Velocity>(e).unwrap().linvel;
+        assert!(velocity(&app, a).length() < 1e-4);
+        assert!((velocity(&app, b).x - 2.0).abs() < 1e-4);
+        assert!(total_work(&app).abs() < 1e-4);
+        assert_eq!(app.world().resource::<Contacts>().len(), 1);
+
+        // Perfectly inelastic: common velocity, half the kinetic energy lost
+        let mut app = contact_app();
+        let sticky = Collider::new(0.0, 0.0);
+        let a = spawn_body(&mut app, Vec2::ZERO, ball(sticky, Vec2::X * 2.0));
+        let b = spawn_body(&mut app, Vec2::new(0.99, 0.0), ball(sticky, Vec2::ZERO));
+        app.update();
+        assert!((velocity(&app, a).x - 1.0).abs() < 1e-4);
+        assert!((velocity(&app, b).x - 1.0).abs() < 1e-4);
+        // KE: 2 J → 1 J, reported as negative work
+        assert!((total_work(&app) + 1.0).abs() < 1e-4);
+        // Overlap was pushed apart
+        let gap = app.world().get::<Transform>(b).unwrap().translation.x
+            - app.world().get::<Transform>(a).unwrap().translation.x;
+        assert!(gap > 0.99);
+    }
+
+    #[test]
+    fn test_friction_spins_a_ball_landing_on_static_ground() {
+        let mut app = contact_app();
+        spawn_body(
+            &mut app,
+            Vec2::ZERO,
+            (
+                Collider::new(0.0, 1.0),
+                Shape::rectangle(Vec2::new(5.0, 0.5)),
+            ),
+        );
+        let inertia = Shape::circle(0.5).moment_of_inertia(1.0);
+        let ball = spawn_body(
+            &mut app,
+            Vec2::new(0.0, 0.99),
+            (
+                ball(Collider::new(0.0, 1.0), Vec2::new(3.0, -1.0)),
+                MomentOfInertia::new(inertia),
+            ),
+        );
+        app.update();
+
+        let velocity = *app.world().get::<Velocity>(ball).unwrap();
+        // Normal velocity stopped, sliding slowed by μ·λₙ, clockwise spin started
+        assert!(velocity.linvel.y.abs() < 1e-4);
+        assert!(velocity.linvel.x < 3.0 && velocity.linvel.x > 0.0);
+        assert!(
+            (3.0 - velocity.linvel.x - 1.0).abs() < 1e-3,
+            "μ·λₙ/m = 1 m/s"
+        );
+        assert!(velocity.angvel.z < 0.0);
+        // Friction and the inelastic landing dissipate energy
+        assert!(total_work(&app) < 0.0);
+    }
+
+    #[test]
+    fn test_layers_and_sensors_do_not_collide() {
+        let creatures = CollisionLayers::new(2, !4);
+        let plants = CollisionLayers::new(4, CollisionLayers::ALL);
+        let mut app = contact_app();
+        spawn_body(
+            &mut app,
+            Vec2::ZERO,
+            (ball(Collider::elastic(), Vec2::X * 2.0), creatures),
+        );
+        spawn_body(
+            &mut app,
+            Vec2::new(0.99, 0.0),
+            (ball(Collider::elastic(), Vec2::ZERO), plants),
+        );
+        spawn_body(
+            &mut app,
+            Vec2::new(-0.99, 0.0),
+            (ball(Collider::elastic(), Vec2::ZERO), Sensor),
+        );
+        app.update();
+        assert!(app.world().resource::<Contacts>().is_empty());
+        assert!(total_work(&app).abs() < 1e-6);
+    }
+}
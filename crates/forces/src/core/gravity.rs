@@ -1,3 +1,4 @@
+use super::layers::CollisionLayers;
 use super::newton_laws::{AppliedForce, Mass};
 use crate::PhysicsSet;
 use bevy::prelude::*;
@@ -106,6 +107,7 @@ struct GravityBody {
     entity: Entity,
     position: Vec3,
     mass: f32,
+    layers: CollisionLayers,
 }
 
 #[derive(Default)]
@@ -392,11 +394,21 @@ pub fn apply_uniform_gravity(
 }
 
 /// Compute mutual gravitational attraction for bodies that are gravity sources.
-/// In this mode, every source both exerts and receives force.
-#[allow(private_interfaces)]
+/// In this mode, every source both exerts and receives force. Pairs whose
+/// [`CollisionLayers`] do not interact are skipped.
+#[allow(private_interfaces, clippy::type_complexity)]
 pub fn calculate_mutual_gravitational_attraction(
     gravity_params: Res<GravityParams>,
-    mut query: Query<(Entity, &Transform, &Mass, &mut AppliedForce), With<GravitySource>>,
+    mut query: Query<
+        (
+            Entity,
+            &Transform,
+            &Mass,
+            &mut AppliedForce,
+            Option<&CollisionLayers>,
+        ),
+        With<GravitySource>,
+    >,
     mut ctx: Local<MutualGravityBuffers>,
 ) {
     let softening_squared = gravity_params.softening * gravity_params.softening;
@@ -408,10 +420,11 @@ pub fn calculate_mutual_gravitational_attraction(
     ctx.bodies.extend(
         query
             .iter()
-            .map(|(entity, transform, mass, _)| GravityBody {
+            .map(|(entity, transform, mass, _, layers)| GravityBody {
                 entity,
                 position: transform.translation,
                 mass: mass.value,
+                layers: layers.copied().unwrap_or_default(),
             }),
     );
     // Stable ordering keeps accumulation deterministic for replay/debug.
@@ -440,6 +453,9 @@ pub fn calculate_mutual_gravitational_attraction(
         let body_a = ctx.bodies[i];
         for j in (i + 1)..ctx.bodies.len() {
             let body_b = ctx.bodies[j];
+            if !body_a.layers.interacts_with(&body_b.layers) {
+                continue;
+            }
             let Some(force_on_a) = pair_force_vector(
                 body_b.position,
                 body_b.mass,
@@ -457,7 +473,7 @@ pub fn calculate_mutual_gravitational_attraction(
 
     // Single writeback pass to ECS.
     for (index, body) in ctx.bodies.iter().enumerate() {
-        if let Ok((_, _, _, mut applied_force)) = query.get_mut(body.entity) {
+        if let Ok((_, _, _, mut applied_force, _)) = query.get_mut(body.entity) {
             applied_force.force += ctx.forces[index];
         }
     }
@@ -832,6 +848,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_mutual_gravity_skips_filtered_pairs() {
+        let mut app = App::new();
+        app.insert_resource(GravityParams::default())
+            .add_systems(Update, calculate_mutual_gravitational_attraction);
+
+        // Ghost sees nothing but layer 2, which nobody else is in
+        let ghost = CollisionLayers::new(CollisionLayers::DEFAULT, 2);
+        let mut spawn = |x: f32, layers: Option<CollisionLayers>| {
+            let mut body = app.world_mut().spawn((
+                Transform::from_xyz(x, 0.0, 0.0),
+                Mass::new(10.0),
+                AppliedForce::new(Vec3::ZERO),
+                GravitySource,
+            ));
+            if let Some(layers) = layers {
+                body.insert(layers);
+            }
+            body.id()
+        };
+        let a = spawn(0.0, None);
+        let b = spawn(10.0, Some(ghost));
+        let c = spawn(-10.0, None);
+        app.update();
+
+        let force = |e: Entity| app.world().get::<AppliedForce>(e).unwrap().force;
+        assert_eq!(force(b), Vec3::ZERO);
+        // a and c still attract each other, equal and opposite
+        assert!(force(a).x < 0.0);
+        assert!((force(a) + force(c)).length() < 1e-6);
+    }
+
     #[test]
     fn test_barnes_hut_vs_brute_force_small_n() {
         // For small N, Barnes-Hut should match brute force closely
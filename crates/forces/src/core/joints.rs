@@ -0,0 +1,716 @@
+//! Joints between Newtonian bodies, solved with XPBD.
+//!
+//! **PHYSICS**: A [`Joint`] entity links bodies `a` and `b` at local anchors. After
+//! integration, each joint's position and angle errors are projected out with
+//! extended position-based dynamics (XPBD): Δλ = (−C − α̃·λ)/(Σwᵢ + α̃), α̃ = α/dt²,
+//! where wᵢ = 1/mᵢ + (rᵢ × n)²/Iᵢ. Velocities follow the corrections (Δv = Δx/dt), so
+//! forces and `AppliedTorque`s integrated earlier in the step are respected.
+//!
+//! **UNITS**: compliance α in m/N (inverse stiffness, 0 is rigid), forces in N,
+//! torques in N·m. The constraint force is λ/dt².
+//!
+//! **LP-0**: Planar (2D) bodies rotating about the centroid of their `Shape` (or
+//! `Radius`), the same pivot the contact solver uses; bodies without a shape pivot
+//! about their `Transform` origin. Anchors stay relative to the origin. A body
+//! without `Velocity` (or with infinite `Mass`) is a fixed anchor; without
+//! `MomentOfInertia` it does not rotate.
+
+use super::contact::body_shape;
+use super::newton_laws::{Mass, MomentOfInertia, Velocity};
+use crate::PhysicsSet;
+use bevy::prelude::*;
+use matter::geometry::{Radius, Shape};
+
+/// Allowed range of a joint coordinate (radians for angles, m for slides).
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct JointLimits {
+    pub min: f32,
+    pub max: f32,
+}
+
+impl JointLimits {
+    pub fn new(min: f32, max: f32) -> Self {
+        Self {
+            min: min.min(max),
+            max: max.max(min),
+        }
+    }
+
+    /// Signed violation: negative below `min`, positive above `max`, zero inside.
+    fn violation(&self, value: f32) -> f32 {
+        if value < self.min {
+            value - self.min
+        } else if value > self.max {
+            value - self.max
+        } else {
+            0.0
+        }
+    }
+}
+
+/// What a [`Joint`] constrains.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum JointKind {
+    /// Anchors held `length` apart (rigid rod)
+    Distance { length: f32 },
+    /// Anchors at most `max_length` apart (slack rope, vine)
+    Rope { max_length: f32 },
+    /// Anchors held `rest_length` apart with stiffness k (N/m); compliance is 1/k
+    Spring { rest_length: f32, stiffness: f32 },
+    /// Anchors coincide; relative angle free or within `limits` (hinge, knee)
+    Revolute { limits: Option<JointLimits> },
+    /// Anchor of `b` slides along `axis` (in `a`'s frame) with the relative angle
+    /// locked; slide distance free or within `limits` (piston)
+    Prismatic {
+        axis: Vec2,
+        limits: Option<JointLimits>,
+    },
+    /// Anchors coincide and the relative angle is locked
+    Weld,
+}
+
+/// Constraint between two bodies. Lives on its own entity, like a `Bond`.
+///
+/// **Property-based**: `rest_angle` is the relative angle θ_b − θ_a the angular
+/// parts hold (weld, prismatic) or measure limits from (revolute). `break_force`
+/// despawns the joint when the constraint force exceeds it.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+#[require(JointForce)]
+pub struct Joint {
+    pub a: Entity,
+    pub b: Entity,
+    /// Anchor in `a`'s local frame (m)
+    pub anchor_a: Vec2,
+    /// Anchor in `b`'s local frame (m)
+    pub anchor_b: Vec2,
+    pub kind: JointKind,
+    /// Relative angle θ_b − θ_a at rest (radians)
+    pub rest_angle: f32,
+    /// Extra compliance on every part of the joint (m/N); 0 is rigid
+    pub compliance: f32,
+    /// Constraint force above which the joint breaks (N)
+    pub break_force: f32,
+}
+
+impl Joint {
+    pub fn new(a: Entity, b: Entity, kind: JointKind) -> Self {
+        Self {
+            a,
+            b,
+            anchor_a: Vec2::ZERO,
+            anchor_b: Vec2::ZERO,
+            kind,
+            rest_angle: 0.0,
+            compliance: 0.0,
+            break_force: f32::INFINITY,
+        }
+    }
+
+    pub fn distance(a: Entity, b: Entity, length: f32) -> Self {
+        Self::new(a, b, JointKind::Distance { length })
+    }
+
+    pub fn rope(a: Entity, b: Entity, max_length: f32) -> Self {
+        Self::new(a, b, JointKind::Rope { max_length })
+    }
+
+    pub fn spring(a: Entity, b: Entity, rest_length: f32, stiffness: f32) -> Self {
+        Self::new(
+            a,
+            b,
+            JointKind::Spring {
+                rest_length,
+                stiffness,
+            },
+        )
+    }
+
+    pub fn revolute(a: Entity, b: Entity) -> Self {
+        Self::new(a, b, JointKind::Revolute { limits: None })
+    }
+
+    pub fn prismatic(a: Entity, b: Entity, axis: Vec2) -> Self {
+        Self::new(
+            a,
+            b,
+            JointKind::Prismatic {
+                axis: axis.normalize_or(Vec2::X),
+                limits: None,
+            },
+        )
+    }
+
+    pub fn weld(a: Entity, b: Entity) -> Self {
+        Self::new(a, b, JointKind::Weld)
+    }
+
+    pub fn with_anchors(mut self, anchor_a: Vec2, anchor_b: Vec2) -> Self {
+        self.anchor_a = anchor_a;
+        self.anchor_b = anchor_b;
+        self
+    }
+
+    /// Angle limits for revolute joints, slide limits for prismatic joints.
+    pub fn with_limits(mut self, min: f32, max: f32) -> Self {
+        let limits = Some(JointLimits::new(min, max));
+        match &mut self.kind {
+            JointKind::Revolute { limits: l } | JointKind::Prismatic { limits: l, .. } => {
+                *l = limits
+            }
+            _ => {}
+        }
+        self
+    }
+
+    pub fn with_rest_angle(mut self, rest_angle: f32) -> Self {
+        self.rest_angle = rest_angle;
+        self
+    }
+
+    pub fn with_compliance(mut self, compliance: f32) -> Self {
+        self.compliance = compliance.max(0.0);
+        self
+    }
+
+    pub fn with_break_force(mut self, break_force: f32) -> Self {
+        self.break_force = break_force;
+        self
+    }
+}
+
+/// Force and torque the joint applied to `b` last step (`a` gets the opposite).
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct JointForce {
+    /// Constraint force on `b` (N)
+    pub force: Vec2,
+    /// Constraint torque on `b` about its origin (N·m)
+    pub torque: f32,
+}
+
+/// A joint exceeded its `break_force` and was removed.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct JointBreakEvent {
+    pub joint: Entity,
+    pub a: Entity,
+    pub b: Entity,
+    /// Constraint force magnitude that broke it (N)
+    pub force: f32,
+}
+
+/// XPBD solver tuning.
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct JointConfig {
+    /// Gauss-Seidel passes over all joints per step
+    pub iterations: usize,
+}
+
+impl Default for JointConfig {
+    fn default() -> Self {
+        Self { iterations: 8 }
+    }
+}
+
+struct JointBody {
+    /// World position of the pivot (shape centroid)
+    position: Vec2,
+    angle: f32,
+    /// Pivot in the body's local frame
+    pivot: Vec2,
+    inverse_mass: f32,
+    inverse_inertia: f32,
+    start_position: Vec2,
+    start_angle: f32,
+}
+
+impl JointBody {
+    /// Lever from the pivot to a local anchor, in world axes.
+    fn lever(&self, local: Vec2) -> Vec2 {
+        Vec2::from_angle(self.angle).rotate(local - self.pivot)
+    }
+
+    fn world_anchor(&self, local: Vec2) -> Vec2 {
+        self.position + self.lever(local)
+    }
+
+    /// Where the `Transform` origin sits for the current pivot position and angle.
+    fn origin(&self) -> Vec2 {
+        self.position - Vec2::from_angle(self.angle).rotate(self.pivot)
+    }
+}
+
+/// Joint state carried across the iterations of one step.
+struct JointRow {
+    entity: Entity,
+    joint: Joint,
+    a: usize,
+    b: usize,
+    /// Accumulated positional multiplier along each correction (N·s²)
+    linear: Vec2,
+    /// Accumulated angular multiplier (N·m·s²)
+    angular: f32,
+}
+
+#[derive(Default)]
+pub(crate) struct JointScratch {
+    bodies: Vec<JointBody>,
+    entities: Vec<Entity>,
+    lookup: std::collections::HashMap<Entity, usize>,
+    rows: Vec<JointRow>,
+}
+
+/// Positional XPBD update along unit `n` (C grows as `b` moves along `n`).
+/// Returns Δλ; the impulse on `b` is Δλ·n.
+fn solve_positional(
+    bodies: &mut [JointBody],
+    (a, b): (usize, usize),
+    (ra, rb): (Vec2, Vec2),
+    n: Vec2,
+    c: f32,
+    lambda: f32,
+    alpha: f32,
+) -> f32 {
+    let wa = bodies[a].inverse_mass + bodies[a].inverse_inertia * ra.perp_dot(n).powi(2);
+    let wb = bodies[b].inverse_mass + bodies[b].inverse_inertia * rb.perp_dot(n).powi(2);
+    let w = wa + wb + alpha;
+    if w <= 0.0 {
+        return 0.0;
+    }
+    let delta = (-c - alpha * lambda) / w;
+    let p = delta * n;
+    let body = &mut bodies[a];
+    body.position -= p * body.inverse_mass;
+    body.angle -= ra.perp_dot(p) * body.inverse_inertia;
+    let body = &mut bodies[b];
+    body.position += p * body.inverse_mass;
+    body.angle += rb.perp_dot(p) * body.inverse_inertia;
+    delta
+}
+
+/// Angular XPBD update for C = θ_b − θ_a − target. Returns Δλ.
+fn solve_angular(
+    bodies: &mut [JointBody],
+    (a, b): (usize, usize),
+    c: f32,
+    lambda: f32,
+    alpha: f32,
+) -> f32 {
+    let w = bodies[a].inverse_inertia + bodies[b].inverse_inertia + alpha;
+    if w <= 0.0 {
+        return 0.0;
+    }
+    let delta = (-c - alpha * lambda) / w;
+    bodies[a].angle -= delta * bodies[a].inverse_inertia;
+    bodies[b].angle += delta * bodies[b].inverse_inertia;
+    delta
+}
+
+/// Wrap an angle to (−π, π].
+fn wrap_angle(angle: f32) -> f32 {
+    let wrapped = (angle + std::f32::consts::PI).rem_euclid(std::f32::consts::TAU);
+    wrapped - std::f32::consts::PI
+}
+
+/// Project joint errors out of positions and angles, then correct velocities.
+///
+/// **NUMERICAL**: One XPBD step with `JointConfig::iterations` Gauss-Seidel passes;
+/// stiff chains converge better with more iterations or a smaller fixed timestep.
+///
+/// **DETERMINISM**: Joints are solved in entity order.
+#[allow(clippy::type_complexity)]
+pub(crate) fn solve_joints(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<JointConfig>,
+    mut joints: Query<(Entity, &Joint, &mut JointForce)>,
+    mut bodies: Query<(
+        &mut Transform,
+        Option<&Mass>,
+        Option<&mut Velocity>,
+        Option<&MomentOfInertia>,
+        Option<&Shape>,
+        Option<&Radius>,
+    )>,
+    mut breaks: MessageWriter<JointBreakEvent>,
+    mut ctx: Local<JointScratch>,
+) {
+    let dt = time.delta_secs();
+    if dt <= 0.0 {
+        return;
+    }
+    let ctx = &mut *ctx;
+    ctx.bodies.clear();
+    ctx.entities.clear();
+    ctx.lookup.clear();
+    ctx.rows.clear();
+
+    let body_index = |entity: Entity, ctx: &mut JointScratch| -> Option<usize> {
+        if let Some(&index) = ctx.lookup.get(&entity) {
+            return Some(index);
+        }
+        let (transform, mass, velocity, inertia, shape, radius) = bodies.get(entity).ok()?;
+        let dynamic = velocity.is_some();
+        let angle = transform.rotation.to_euler(EulerRot::ZYX).0;
+        let pivot = body_shape(shape, radius).map_or(Vec2::ZERO, |shape| shape.centroid());
+        let position = transform.translation.truncate() + Vec2::from_angle(angle).rotate(pivot);
+        let index = ctx.bodies.len();
+        ctx.bodies.push(JointBody {
+            position,
+            angle,
+            pivot,
+            inverse_mass: mass.filter(|_| dynamic).map_or(0.0, Mass::inverse),
+            inverse_inertia: inertia
+                .filter(|_| dynamic)
+                .map_or(0.0, MomentOfInertia::inverse),
+            start_position: position,
+            start_angle: angle,
+        });
+        ctx.entities.push(entity);
+        ctx.lookup.insert(entity, index);
+        Some(index)
+    };
+
+    let mut order: Vec<(Entity, Joint)> = joints.iter().map(|(e, j, _)| (e, *j)).collect();
+    order.sort_by_key(|(entity, _)| entity.to_bits());
+    for (entity, joint) in order {
+        let (Some(a), Some(b)) = (body_index(joint.a, ctx), body_index(joint.b, ctx)) else {
+            // A body is gone: the joint goes with it
+            commands.entity(entity).despawn();
+            continue;
+        };
+        ctx.rows.push(JointRow {
+            entity,
+            joint,
+            a,
+            b,
+            linear: Vec2::ZERO,
+            angular: 0.0,
+        });
+    }
+    if ctx.rows.is_empty() {
+        return;
+    }
+
+    let inv_dt_sq = 1.0 / (dt * dt);
+    for _ in 0..config.iterations {
+        for row in ctx.rows.iter_mut() {
+            let joint = row.joint;
+            let pair = (row.a, row.b);
+            let alpha = joint.compliance * inv_dt_sq;
+            let ra = ctx.bodies[row.a].lever(joint.anchor_a);
+            let rb = ctx.bodies[row.b].lever(joint.anchor_b);
+            let offset = ctx.bodies[row.b].world_anchor(joint.anchor_b)
+                - ctx.bodies[row.a].world_anchor(joint.anchor_a);
+            let relative_angle =
+                wrap_angle(ctx.bodies[row.b].angle - ctx.bodies[row.a].angle - joint.rest_angle);
+            let length = offset.length();
+            let direction = offset.try_normalize();
+            let frame_a = Vec2::from_angle(ctx.bodies[row.a].angle);
+
+            // Each part keeps its own multiplier, projected onto the vector λ
+            let mut positional = |n: Vec2, c: f32, alpha: f32, row: &mut JointRow| {
+                let lambda = row.linear.dot(n);
+                let delta = solve_positional(&mut ctx.bodies, pair, (ra, rb), n, c, lambda, alpha);
+                row.linear += delta * n;
+            };
+
+            match joint.kind {
+                JointKind::Distance { length: rest } => {
+                    if let Some(n) = direction {
+                        positional(n, length - rest, alpha, row);
+                    }
+                }
+                JointKind::Rope { max_length } => {
+                    if let Some(n) = direction
+                        && length > max_length
+                    {
+                        positional(n, length - max_length, alpha, row);
+                    }
+                }
+                JointKind::Spring {
+                    rest_length,
+                    stiffness,
+                } => {
+                    if let Some(n) = direction {
+                        let spring =
+                            (1.0 / stiffness.max(f32::EPSILON) + joint.compliance) * inv_dt_sq;
+                        positional(n, length - rest_length, spring, row);
+                    }
+                }
+                JointKind::Revolute { limits } => {
+                    if let Some(n) = direction {
+                        positional(n, length, alpha, row);
+                    }
+                    if let Some(limits) = limits {
+                        let c = limits.violation(relative_angle);
+                        if c != 0.0 {
+                            row.angular +=
+                                solve_angular(&mut ctx.bodies, pair, c, row.angular, alpha);
+                        }
+                    }
+                }
+                JointKind::Prismatic { axis, limits } => {
+                    let axis = frame_a.rotate(axis);
+                    let normal = axis.perp();
+                    positional(normal, offset.dot(normal), alpha, row);
+                    if let Some(limits) = limits {
+                        let c = limits.violation(offset.dot(axis));
+                        if c != 0.0 {
+                            positional(axis, c, alpha, row);
+                        }
+                    }
+                    row.angular +=
+                        solve_angular(&mut ctx.bodies, pair, relative_angle, row.angular, alpha);
+                }
+                JointKind::Weld => {
+                    if let Some(n) = direction {
+                        positional(n, length, alpha, row);
+                    }
+                    row.angular +=
+                        solve_angular(&mut ctx.bodies, pair, relative_angle, row.angular, alpha);
+                }
+            }
+        }
+    }
+
+    // Write back: positions, then velocities from the corrections (Δv = Δx/dt).
+    // `linvel` is the pivot's velocity, as in the contact solver.
+    for (entity, body) in ctx.entities.iter().zip(&ctx.bodies) {
+        let Ok((mut transform, _, velocity, _, _, _)) = bodies.get_mut(*entity) else {
+            continue;
+        };
+        let Some(mut velocity) = velocity else {
+            continue;
+        };
+        let shift = body.position - body.start_position;
+        let turn = body.angle - body.start_angle;
+        if shift != Vec2::ZERO || turn != 0.0 {
+            let origin = body.origin();
+            transform.translation.x = origin.x;
+            transform.translation.y = origin.y;
+        }
+        if shift != Vec2::ZERO {
+            velocity.linvel += (shift / dt).extend(0.0);
+        }
+        if turn != 0.0 {
+            transform.rotation = Quat::from_rotation_z(turn) * transform.rotation;
+            velocity.angvel.z += turn / dt;
+        }
+    }
+
+    for row in &ctx.rows {
+        let force = row.linear * inv_dt_sq;
+        let torque = row.angular * inv_dt_sq;
+        if let Ok((_, _, mut report)) = joints.get_mut(row.entity) {
+            report.force = force;
+            report.torque = torque;
+        }
+        let magnitude = force.length();
+        if magnitude > row.joint.break_force {
+            commands.entity(row.entity).despawn();
+            breaks.write(JointBreakEvent {
+                joint: row.entity,
+                a: row.joint.a,
+                b: row.joint.b,
+                force: magnitude,
+            });
+        }
+    }
+}
+
+/// Joint constraints for articulated bodies (creatures, vines, bridges).
+pub struct JointsPlugin;
+
+impl Plugin for JointsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<JointConfig>()
+            .register_type::<Joint>()
+            .register_type::<JointForce>()
+            .register_type::<JointConfig>()
+            .add_message::<JointBreakEvent>()
+            .add_systems(
+                FixedUpdate,
+                solve_joints.in_set(PhysicsSet::SolveConstraints),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use matter::geometry::ShapePart;
+    use std::f32::consts::FRAC_PI_2;
+    use std::time::Duration;
+
+    const DT: f32 = 1.0 / 60.0;
+
+    /// Joints run after a plain symplectic Euler step under gravity.
+    fn joint_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<JointConfig>()
+            .add_message::<JointBreakEvent>()
+            .insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+                Duration::from_secs_f32(DT),
+            ))
+            .add_systems(
+                Update,
+                (
+                    |time: Res<Time>, mut q: Query<(&mut Transform, &mut Velocity)>| {
+                        let dt = time.delta_secs();
+                        for (mut transform, mut velocity) in q.iter_mut() {
+                            velocity.linvel.y -= 9.81 * dt;
+                            transform.translation += velocity.linvel * dt;
+                            transform.rotation =
+                                Quat::from_rotation_z(velocity.angvel.z * dt) * transform.rotation;
+                        }
+                    },
+                    solve_joints,
+                )
+                    .chain(),
+            );
+        app
+    }
+
+    fn body(app: &mut App, position: Vec2, mass: f32) -> Entity {
+        app.world_mut()
+            .spawn((
+                Transform::from_translation(position.extend(0.0)),
+                Mass::new(mass),
+                Velocity::default(),
+                MomentOfInertia::disk(mass, 0.5),
+            ))
+            .id()
+    }
+
+    fn run(app: &mut App, steps: usize) {
+        for _ in 0..steps {
+            app.update();
+        }
+    }
+
+    #[test]
+    fn test_pendulum_holds_length_and_reports_tension() {
+        let mut app = joint_app();
+        let pivot = app.world_mut().spawn(Transform::default()).id();
+        let bob = body(&mut app, Vec2::new(0.0, -1.0), 2.0);
+        let joint = app.world_mut().spawn(Joint::distance(pivot, bob, 1.0)).id();
+        run(&mut app, 60);
+
+        let world = app.world();
+        let position = world.get::<Transform>(bob).unwrap().translation;
+        assert!((position.length() - 1.0).abs() < 1e-3);
+        // Hanging at rest: tension balances weight, pulling the bob up
+        let report = world.get::<JointForce>(joint).unwrap();
+        assert!((report.force.y - 2.0 * 9.81).abs() < 0.5, "{report:?}");
+        assert!(world.get::<Velocity>(bob).unwrap().linvel.length() < 0.05);
+    }
+
+    #[test]
+    fn test_joint_pulling_through_centroid_does_not_spin_offset_shape() {
+        // Shape centroid 1 m above the origin; the joint is anchored at the centroid
+        let mut app = joint_app();
+        let pivot = app.world_mut().spawn(Transform::default()).id();
+        let bob = body(&mut app, Vec2::new(1.5, -1.0), 1.0);
+        app.world_mut()
+            .entity_mut(bob)
+            .insert(Shape::compound(vec![ShapePart::new(
+                Vec2::Y,
+                0.0,
+                Shape::circle(0.5),
+            )]));
+        app.world_mut()
+            .spawn(Joint::distance(pivot, bob, 1.5).with_anchors(Vec2::ZERO, Vec2::Y));
+        run(&mut app, 30);
+
+        // Tension acts along the line through the centroid: no torque, as in a contact
+        let world = app.world();
+        let transform = world.get::<Transform>(bob).unwrap();
+        assert!(transform.rotation.to_euler(EulerRot::ZYX).0.abs() < 1e-4);
+        assert!(world.get::<Velocity>(bob).unwrap().angvel.z.abs() < 1e-3);
+        let centroid = transform.translation.truncate() + Vec2::Y;
+        assert!((centroid.length() - 1.5).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_revolute_limits_and_weld() {
+        // Arm hinged at its end to a fixed pivot, allowed to swing only to -45°
+        let mut app = joint_app();
+        let pivot = app.world_mut().spawn(Transform::default()).id();
+        let arm = body(&mut app, Vec2::new(0.5, 0.0), 1.0);
+        app.world_mut().spawn(
+            Joint::revolute(pivot, arm)
+                .with_anchors(Vec2::ZERO, Vec2::new(-0.5, 0.0))
+                .with_limits(-FRAC_PI_2 * 0.5, FRAC_PI_2 * 0.5),
+        );
+        run(&mut app, 120);
+        let transform = *app.world().get::<Transform>(arm).unwrap();
+        let angle = transform.rotation.to_euler(EulerRot::ZYX).0;
+        assert!((angle + FRAC_PI_2 * 0.5).abs() < 0.05, "angle {angle}");
+        // The hinge point stays on the pivot
+        let hinge =
+            transform.translation.truncate() + Vec2::from_angle(angle).rotate(Vec2::new(-0.5, 0.0));
+        assert!(hinge.length() < 1e-2, "hinge {hinge} angle {angle}");
+
+        // Welded pair falls as one body
+        let mut app = joint_app();
+        let a = body(&mut app, Vec2::ZERO, 1.0);
+        let b = body(&mut app, Vec2::X, 1.0);
+        app.world_mut()
+            .spawn(Joint::weld(a, b).with_anchors(Vec2::new(0.5, 0.0), Vec2::new(-0.5, 0.0)));
+        app.world_mut().get_mut::<Velocity>(b).unwrap().angvel.z = 2.0;
+        run(&mut app, 30);
+        let world = app.world();
+        let (ta, tb) = (
+            world.get::<Transform>(a).unwrap(),
+            world.get::<Transform>(b).unwrap(),
+        );
+        let (angle_a, angle_b) = (
+            ta.rotation.to_euler(EulerRot::ZYX).0,
+            tb.rotation.to_euler(EulerRot::ZYX).0,
+        );
+        assert!((angle_a - angle_b).abs() < 1e-2);
+        assert!((ta.translation.distance(tb.translation) - 1.0).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_overloaded_rope_breaks() {
+        let mut app = joint_app();
+        let ceiling = app.world_mut().spawn(Transform::default()).id();
+        let light = body(&mut app, Vec2::new(-1.0, -1.0), 1.0);
+        let heavy = body(&mut app, Vec2::new(1.0, -1.0), 10.0);
+        let holds = app
+            .world_mut()
+            .spawn(
+                Joint::rope(ceiling, light, 1.0 + 1e-3)
+                    .with_anchors(Vec2::new(-1.0, 0.0), Vec2::ZERO)
+                    .with_break_force(50.0),
+            )
+            .id();
+        let snaps = app
+            .world_mut()
+            .spawn(
+                Joint::rope(ceiling, heavy, 1.0 + 1e-3)
+                    .with_anchors(Vec2::new(1.0, 0.0), Vec2::ZERO)
+                    .with_break_force(50.0),
+            )
+            .id();
+        run(&mut app, 2);
+        let broken: Vec<_> = app
+            .world_mut()
+            .resource_mut::<Messages<JointBreakEvent>>()
+            .drain()
+            .collect();
+        assert_eq!(broken.len(), 1);
+        assert_eq!(broken[0].b, heavy);
+        assert!(broken[0].force > 50.0);
+
+        run(&mut app, 28);
+        assert!(app.world().get_entity(holds).is_ok());
+        assert!(app.world().get_entity(snaps).is_err());
+        // Heavy bob is now in free fall
+        assert!(app.world().get::<Transform>(heavy).unwrap().translation.y < -1.1);
+    }
+}
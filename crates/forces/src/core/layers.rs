@@ -0,0 +1,72 @@
+//! Collision layers: which bodies see each other.
+//!
+//! **ARCHITECTURE**: A body belongs to the layers in `memberships` and only sees
+//! bodies whose memberships intersect its `filters`. Bodies without
+//! [`CollisionLayers`] belong to [`CollisionLayers::DEFAULT`] and see everything.
+
+use bevy::prelude::*;
+
+/// Layer membership and mask bits of a body.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct CollisionLayers {
+    /// Layers this body belongs to
+    pub memberships: u32,
+    /// Layers this body interacts with
+    pub filters: u32,
+}
+
+impl CollisionLayers {
+    /// Layer of bodies that do not say otherwise.
+    pub const DEFAULT: u32 = 1;
+    /// Every layer.
+    pub const ALL: u32 = u32::MAX;
+    /// No layer.
+    pub const NONE: u32 = 0;
+
+    pub const fn new(memberships: u32, filters: u32) -> Self {
+        Self {
+            memberships,
+            filters,
+        }
+    }
+
+    /// Whether this body is in any layer of `mask`.
+    pub const fn in_mask(&self, mask: u32) -> bool {
+        self.memberships & mask != 0
+    }
+
+    /// Whether both bodies accept each other.
+    pub const fn interacts_with(&self, other: &Self) -> bool {
+        self.memberships & other.filters != 0 && other.memberships & self.filters != 0
+    }
+}
+
+impl Default for CollisionLayers {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT, Self::ALL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interacts_with() {
+        let default = CollisionLayers::default();
+        assert!(default.interacts_with(&default));
+
+        // Sees only layer 2, so a default body is ignored both ways
+        let picky = CollisionLayers::new(CollisionLayers::DEFAULT, 2);
+        assert!(!picky.interacts_with(&default));
+        assert!(!default.interacts_with(&picky));
+        assert!(picky.interacts_with(&CollisionLayers::new(2, CollisionLayers::ALL)));
+
+        // No membership: nobody can see it
+        let none = CollisionLayers::new(CollisionLayers::NONE, CollisionLayers::ALL);
+        assert!(!none.interacts_with(&default));
+        assert!(!default.interacts_with(&none));
+        assert!(!none.interacts_with(&none));
+    }
+}
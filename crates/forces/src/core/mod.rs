@@ -1,10 +1,29 @@
+pub mod character;
+pub mod contact;
 pub mod gravity;
+pub mod joints;
+pub mod layers;
+pub mod molecular;
 pub mod newton_laws;
+pub mod queries;
+pub mod triggers;
 
 /// Prelude for the forces core module.
 ///
 /// This includes the fundamental physics components and systems.
 pub mod prelude {
+    // Re-export from character module
+    pub use crate::core::character::{
+        CharacterController, CharacterControllerPlugin, CharacterInput, CharacterState,
+    };
+
+    // Re-export from contact module
+    pub use crate::core::contact::{
+        CastHit, Collider, Contact, ContactConfig, ContactPlugin, Contacts, ConvexPiece,
+        body_shape, cast_pieces, collider_pieces, piece_contacts, piece_separation,
+        transform_pieces,
+    };
+
     // Re-export from gravity module
     pub use crate::core::gravity::{
         DEFAULT_GRAVITATIONAL_CONSTANT, GravityAffected, GravityForceMode, GravityParams,
@@ -14,15 +33,40 @@ pub mod prelude {
         calculate_orbital_velocity, calculate_plummer_orbital_velocity,
     };
 
+    // Re-export from joints module
+    pub use crate::core::joints::{
+        Joint, JointBreakEvent, JointConfig, JointForce, JointKind, JointLimits, JointsPlugin,
+    };
+
+    // Re-export from layers module
+    pub use crate::core::layers::CollisionLayers;
+
+    // Re-export from molecular module
+    pub use crate::core::molecular::{
+        Bond, BondAngle, BondEvent, Bonding, MolecularConfig, MolecularEnergyEvent,
+        MolecularForcesPlugin, MolecularSet, PairPotential,
+    };
+
     // Re-export from newton_laws module
     pub use crate::core::newton_laws::{
-        AppliedForce, AppliedTorque, Distance, ForceImpulse, ForcesDiagnostics,
-        ForcesDiagnosticsPlugin, IntegratorKind, Mass, MomentOfInertia, NewtonLawsPlugin, Norm,
-        PairedForce, PairedForceInteraction, PreviousAcceleration, RotationalWorkEvent, Velocity,
-        WorkDoneEvent, calculate_angular_momentum, calculate_kinetic_energy, calculate_momentum,
-        calculate_rotational_kinetic_energy, calculate_torque_from_force,
-        integrate_newton_second_law, integrate_newton_second_law_velocity_verlet,
-        integrate_positions_symplectic_euler, integrate_positions_velocity_verlet,
-        integrate_torques, integrate_torques_velocity_verlet, update_forces_diagnostics,
+        AppliedForce, AppliedTorque, ContinuumEnergyEvent, Distance, ForceImpulse,
+        ForcesDiagnostics, ForcesDiagnosticsPlugin, IntegratorKind, Mass, MomentOfInertia,
+        NewtonLawsPlugin, Norm, PairedForce, PairedForceInteraction, PreviousAcceleration,
+        RotationalWorkEvent, Velocity, WorkDoneEvent, calculate_angular_momentum,
+        calculate_kinetic_energy, calculate_momentum, calculate_rotational_kinetic_energy,
+        calculate_torque_from_force, integrate_newton_second_law,
+        integrate_newton_second_law_velocity_verlet, integrate_positions_symplectic_euler,
+        integrate_positions_velocity_verlet, integrate_torques, integrate_torques_velocity_verlet,
+        update_forces_diagnostics,
+    };
+
+    // Re-export from queries module
+    pub use crate::core::queries::{
+        ColliderBounds, QueryFilter, QueryHit, SpatialQuery, SpatialQueryPlugin,
+    };
+
+    // Re-export from triggers module
+    pub use crate::core::triggers::{
+        Sensor, TriggerEnter, TriggerExit, TriggerStay, Triggers, TriggersPlugin,
     };
 }
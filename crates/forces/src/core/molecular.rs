@@ -0,0 +1,964 @@
+//! Interatomic forces: pair potentials, covalent bonds and bond angles.
+//!
+//! **PHYSICS**: Atoms with a [`PairPotential`] interact through Lennard-Jones or Morse
+//! wells. Atoms with [`Bonding`] sites form explicit harmonic [`Bond`]s when they meet
+//! close and slow enough, and break them when the stretch energy exceeds the
+//! dissociation energy. [`BondAngle`] terms keep molecule shapes.
+//!
+//! **CONSERVATION**: Forces are exact gradients of the reported energies (the cutoff
+//! switch multiplies the potential, see `utils::force_switch_derivative`), so
+//! KE + pair + bond + angle energy is conserved between bonding events. Forming or
+//! breaking a bond swaps the pair well for the bond well; the difference is reported
+//! in [`BondEvent::energy_released`].
+//!
+//! **UNITS**: distances in m, energies in J, stiffness in N/m (bonds) and N·m/rad²
+//! (angles). LP scenes use game-scale values, not atomic SI ones.
+
+use super::layers::CollisionLayers;
+use super::newton_laws::{AppliedForce, Mass, Velocity};
+use crate::PhysicsSet;
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+use utils::{
+    SpatialIndexSet, SpatiallyIndexed, UnifiedSpatialIndex, force_switch, force_switch_derivative,
+};
+
+/// Fraction of the equilibrium distance below which pair potentials continue linearly.
+///
+/// **NUMERICAL**: The r⁻¹² wall is unbounded; past 0.8·r_m the force is held at its
+/// value there (U stays its exact integral), which keeps overlapping spawns finite.
+pub const CORE_RADIUS_FRACTION: f32 = 0.8;
+
+/// System sets for molecular interactions, inside [`PhysicsSet::AccumulateForces`].
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MolecularSet {
+    /// Form and break bonds
+    Bonding,
+    /// Accumulate pair, bond and angle forces
+    Forces,
+}
+
+/// Non-bonded interaction between two atoms.
+///
+/// **Property-based**: Unlike pairs mix with Lorentz-Berthelot rules: geometric mean
+/// of well depths (and Morse stiffness), arithmetic mean of equilibrium distances.
+/// A Morse atom meeting a Lennard-Jones atom interacts through Lennard-Jones.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub enum PairPotential {
+    /// U = ε·((r_m/r)¹² - 2·(r_m/r)⁶)
+    LennardJones {
+        /// Well depth ε (J)
+        well_depth: f32,
+        /// Distance of the minimum r_m = 2^{1/6}·σ (m)
+        equilibrium_distance: f32,
+    },
+    /// U = D·((1 - e^{-a·(r - r_e)})² - 1)
+    Morse {
+        /// Well depth D (J)
+        well_depth: f32,
+        /// Distance of the minimum r_e (m)
+        equilibrium_distance: f32,
+        /// Width parameter a (1/m)
+        stiffness: f32,
+    },
+}
+
+impl PairPotential {
+    /// Lennard-Jones from the usual (ε, σ) parameters.
+    pub fn lennard_jones(epsilon: f32, sigma: f32) -> Self {
+        Self::LennardJones {
+            well_depth: epsilon,
+            equilibrium_distance: 2f32.powf(1.0 / 6.0) * sigma,
+        }
+    }
+
+    pub fn morse(well_depth: f32, equilibrium_distance: f32, stiffness: f32) -> Self {
+        Self::Morse {
+            well_depth,
+            equilibrium_distance,
+            stiffness,
+        }
+    }
+
+    /// Well depth (J).
+    pub fn well_depth(&self) -> f32 {
+        match *self {
+            Self::LennardJones { well_depth, .. } | Self::Morse { well_depth, .. } => well_depth,
+        }
+    }
+
+    /// Distance of the potential minimum (m).
+    pub fn equilibrium_distance(&self) -> f32 {
+        match *self {
+            Self::LennardJones {
+                equilibrium_distance,
+                ..
+            }
+            | Self::Morse {
+                equilibrium_distance,
+                ..
+            } => equilibrium_distance,
+        }
+    }
+
+    /// Potential between an atom of `self` and one of `other`.
+    pub fn mix(&self, other: &Self) -> Self {
+        let well_depth = (self.well_depth() * other.well_depth()).sqrt();
+        let equilibrium_distance =
+            0.5 * (self.equilibrium_distance() + other.equilibrium_distance());
+        match (*self, *other) {
+            (Self::Morse { stiffness: a, .. }, Self::Morse { stiffness: b, .. }) => Self::Morse {
+                well_depth,
+                equilibrium_distance,
+                stiffness: (a * b).sqrt(),
+            },
+            _ => Self::LennardJones {
+                well_depth,
+                equilibrium_distance,
+            },
+        }
+    }
+
+    /// Unswitched potential U(r) (J) and its derivative dU/dr (N).
+    pub fn energy_and_derivative(&self, r: f32) -> (f32, f32) {
+        let core = CORE_RADIUS_FRACTION * self.equilibrium_distance();
+        if r < core {
+            let (u, du) = self.bare(core);
+            return (u + du * (r - core), du);
+        }
+        self.bare(r)
+    }
+
+    /// Potential and derivative with the C¹ cutoff switch between `r_on` and `r_cut`.
+    pub fn switched(&self, r: f32, r_on: f32, r_cut: f32) -> (f32, f32) {
+        if r >= r_cut {
+            return (0.0, 0.0);
+        }
+        let (u, du) = self.energy_and_derivative(r);
+        let s = force_switch(r, r_on, r_cut);
+        (u * s, du * s + u * force_switch_derivative(r, r_on, r_cut))
+    }
+
+    fn bare(&self, r: f32) -> (f32, f32) {
+        match *self {
+            Self::LennardJones {
+                well_depth,
+                equilibrium_distance,
+            } => {
+                let x6 = (equilibrium_distance / r).powi(6);
+                let x12 = x6 * x6;
+                (
+                    well_depth * (x12 - 2.0 * x6),
+                    12.0 * well_depth / r * (x6 - x12),
+                )
+            }
+            Self::Morse {
+                well_depth,
+                equilibrium_distance,
+                stiffness,
+            } => {
+                let e = (-stiffness * (r - equilibrium_distance)).exp();
+                (
+                    well_depth * ((1.0 - e) * (1.0 - e) - 1.0),
+                    2.0 * stiffness * well_depth * (1.0 - e) * e,
+                )
+            }
+        }
+    }
+}
+
+/// Bonding site of an atom: how many bonds it takes and what they look like.
+///
+/// Bonds between unlike atoms use the mean bond length and the geometric mean of the
+/// stiffness and dissociation energy.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct Bonding {
+    /// Maximum number of bonds
+    pub valence: u32,
+    /// Rest length r₀ of bonds from this atom (m)
+    pub bond_length: f32,
+    /// Bond stiffness k (N/m)
+    pub stiffness: f32,
+    /// Energy D needed to break a bond (J)
+    pub dissociation_energy: f32,
+}
+
+impl Bonding {
+    pub fn new(valence: u32, bond_length: f32, stiffness: f32, dissociation_energy: f32) -> Self {
+        debug_assert!(bond_length > 0.0, "Bond length must be positive");
+        debug_assert!(stiffness >= 0.0, "Bond stiffness must be non-negative");
+        Self {
+            valence,
+            bond_length,
+            stiffness,
+            dissociation_energy,
+        }
+    }
+
+    /// Bond between atom `a` carrying `self` and atom `b` carrying `other`.
+    pub fn bond_with(&self, other: &Self, a: Entity, b: Entity) -> Bond {
+        Bond::new(
+            a,
+            b,
+            0.5 * (self.bond_length + other.bond_length),
+            (self.stiffness * other.stiffness).sqrt(),
+        )
+        .with_dissociation_energy((self.dissociation_energy * other.dissociation_energy).sqrt())
+    }
+}
+
+/// Harmonic bond between two atoms, on its own entity.
+///
+/// **PHYSICS**: U = ½·k·(r - r₀)² - D. The well depth D makes a bond at rest lower in
+/// energy than the free atoms; the bond breaks once ½·k·(r - r₀)² > D, where U = 0.
+/// Bonds without a dissociation energy never break and carry no well.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct Bond {
+    pub a: Entity,
+    pub b: Entity,
+    /// Rest length r₀ (m)
+    pub rest_length: f32,
+    /// Stiffness k (N/m)
+    pub stiffness: f32,
+    /// Dissociation energy D (J), infinite for unbreakable bonds
+    pub dissociation_energy: f32,
+}
+
+impl Bond {
+    /// Unbreakable bond.
+    pub fn new(a: Entity, b: Entity, rest_length: f32, stiffness: f32) -> Self {
+        Self {
+            a,
+            b,
+            rest_length,
+            stiffness,
+            dissociation_energy: f32::INFINITY,
+        }
+    }
+
+    pub fn with_dissociation_energy(mut self, dissociation_energy: f32) -> Self {
+        self.dissociation_energy = dissociation_energy;
+        self
+    }
+
+    /// Elastic energy ½·k·(r - r₀)² (J).
+    pub fn stretch_energy(&self, r: f32) -> f32 {
+        0.5 * self.stiffness * (r - self.rest_length).powi(2)
+    }
+
+    /// Bond energy U(r) (J), including the well depth of breakable bonds.
+    pub fn energy(&self, r: f32) -> f32 {
+        let well = if self.dissociation_energy.is_finite() {
+            self.dissociation_energy
+        } else {
+            0.0
+        };
+        self.stretch_energy(r) - well
+    }
+
+    pub fn is_broken_at(&self, r: f32) -> bool {
+        self.stretch_energy(r) > self.dissociation_energy
+    }
+
+    fn key(&self) -> (Entity, Entity) {
+        pair_key(self.a, self.b)
+    }
+}
+
+/// Harmonic angle term a-vertex-b, on its own entity.
+///
+/// **PHYSICS**: U = ½·k_θ·(θ - θ₀)² with θ ∈ [0, π] the unsigned angle at the vertex.
+/// The term is removed when either arm's bond breaks.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct BondAngle {
+    pub a: Entity,
+    pub vertex: Entity,
+    pub b: Entity,
+    /// Rest angle θ₀ (rad)
+    pub rest_angle: f32,
+    /// Stiffness k_θ (N·m/rad²)
+    pub stiffness: f32,
+}
+
+impl BondAngle {
+    pub fn new(a: Entity, vertex: Entity, b: Entity, rest_angle: f32, stiffness: f32) -> Self {
+        Self {
+            a,
+            vertex,
+            b,
+            rest_angle,
+            stiffness,
+        }
+    }
+
+    /// Energy (J) and forces on (a, vertex, b) for the given positions.
+    ///
+    /// **NUMERICAL**: Uses the signed angle φ = atan2(u × w, u · w), whose gradient is
+    /// regular everywhere except at coincident atoms; θ = |φ|.
+    pub fn energy_and_forces(&self, a: Vec2, vertex: Vec2, b: Vec2) -> (f32, [Vec2; 3]) {
+        let (u, w) = (a - vertex, b - vertex);
+        let (u2, w2) = (u.length_squared(), w.length_squared());
+        if u2 < f32::EPSILON || w2 < f32::EPSILON {
+            return (0.0, [Vec2::ZERO; 3]);
+        }
+        let phi = u.perp_dot(w).atan2(u.dot(w));
+        let theta = phi.abs();
+        let torque = self.stiffness * (theta - self.rest_angle) * phi.signum();
+        let grad_a = -u.perp() / u2;
+        let grad_b = w.perp() / w2;
+        let force_a = -torque * grad_a;
+        let force_b = -torque * grad_b;
+        (
+            0.5 * self.stiffness * (theta - self.rest_angle).powi(2),
+            [force_a, -(force_a + force_b), force_b],
+        )
+    }
+}
+
+/// Tuning for pair cutoffs and bond formation.
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct MolecularConfig {
+    /// Pair cutoff as a multiple of the mixed equilibrium distance (dimensionless)
+    ///
+    /// **PERFORMANCE APPROXIMATION**: 2.5 leaves < 1 % of the LJ well outside.
+    pub cutoff_factor: f32,
+    /// Start of the switch as a fraction of the cutoff (dimensionless)
+    pub switch_on_fraction: f32,
+    /// Bonds form when r < tolerance·r₀ (dimensionless)
+    pub formation_tolerance: f32,
+}
+
+impl Default for MolecularConfig {
+    fn default() -> Self {
+        Self {
+            cutoff_factor: 2.5,
+            switch_on_fraction: 0.8,
+            formation_tolerance: 1.2,
+        }
+    }
+}
+
+impl MolecularConfig {
+    /// Switched pair energy and derivative for `potential` at distance `r`.
+    pub fn pair_energy(&self, potential: &PairPotential, r: f32) -> (f32, f32) {
+        let r_cut = self.cutoff_factor * potential.equilibrium_distance();
+        potential.switched(r, self.switch_on_fraction * r_cut, r_cut)
+    }
+}
+
+/// A bond formed or broke this step.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct BondEvent {
+    pub a: Entity,
+    pub b: Entity,
+    pub formed: bool,
+    /// Potential energy released by the change (J): pair energy before minus bond
+    /// energy after when forming, the reverse when breaking
+    pub energy_released: f32,
+}
+
+/// Interatomic potential energy, reported once per fixed step.
+///
+/// **ARCHITECTURE**: Like `ContinuumEnergyEvent`, the energy crate's conservation
+/// ledger records it; kinetic energy is already tracked per body.
+#[derive(Message, Debug, Clone, Copy, Default, PartialEq)]
+pub struct MolecularEnergyEvent {
+    /// Σ switched pair potentials (J)
+    pub pair: f32,
+    /// Σ bond energies, including well depths (J)
+    pub bond: f32,
+    /// Σ angle energies (J)
+    pub angle: f32,
+}
+
+impl MolecularEnergyEvent {
+    /// Total interatomic potential energy (J).
+    pub fn potential_energy(&self) -> f32 {
+        self.pair + self.bond + self.angle
+    }
+}
+
+fn pair_key(a: Entity, b: Entity) -> (Entity, Entity) {
+    if a.to_bits() < b.to_bits() {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// Sorted neighbor candidates within `radius` of `position`, for replayable sums.
+fn sorted_candidates(
+    index: &UnifiedSpatialIndex,
+    position: Vec2,
+    radius: f32,
+    scratch: &mut Vec<Entity>,
+) {
+    scratch.clear();
+    index.for_each_neighbor_candidate_in_radius(position, radius, |entity| scratch.push(entity));
+    scratch.sort_by_key(|e| e.to_bits());
+}
+
+/// Mark atoms for the shared spatial index.
+#[allow(clippy::type_complexity)]
+pub fn mark_molecular_entities_spatially_indexed(
+    mut commands: Commands,
+    q: Query<
+        Entity,
+        (
+            Or<(With<PairPotential>, With<Bonding>)>,
+            Without<SpatiallyIndexed>,
+        ),
+    >,
+) {
+    for e in q.iter() {
+        commands.entity(e).insert(SpatiallyIndexed);
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct BondingScratch {
+    bonded: HashSet<(Entity, Entity)>,
+    bond_counts: HashMap<Entity, u32>,
+    sorted_bonds: Vec<(Entity, Bond)>,
+    sorted_atoms: Vec<Entity>,
+    neighbor_candidates: Vec<Entity>,
+}
+
+/// Break overstretched bonds, then bond atoms that are close and slow enough.
+///
+/// **PHYSICS**: A pair bonds when r < tolerance·r₀ and its relative kinetic energy
+/// ½·μ·|v_b - v_a|² is below D, i.e. it would be captured by the bond well. Both atoms
+/// need a free valence.
+///
+/// **DETERMINISM**: Bonds and atoms are visited in entity order, so competition for
+/// the last valence resolves the same way every run.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub(crate) fn update_bonds(
+    mut commands: Commands,
+    atoms: Query<(
+        Entity,
+        &Transform,
+        &Bonding,
+        Option<&Velocity>,
+        Option<&Mass>,
+        Option<&PairPotential>,
+    )>,
+    positions: Query<(&Transform, Option<&PairPotential>)>,
+    bonds: Query<(Entity, &Bond)>,
+    angles: Query<(Entity, &BondAngle)>,
+    index: Res<UnifiedSpatialIndex>,
+    config: Res<MolecularConfig>,
+    mut events: MessageWriter<BondEvent>,
+    mut ctx: Local<BondingScratch>,
+) {
+    let ctx = &mut *ctx;
+    let pair_energy = |a: Option<&PairPotential>, b: Option<&PairPotential>, r: f32| match (a, b) {
+        (Some(a), Some(b)) => config.pair_energy(&a.mix(b), r).0,
+        _ => 0.0,
+    };
+
+    ctx.bonded.clear();
+    ctx.bond_counts.clear();
+    ctx.sorted_bonds.clear();
+    ctx.sorted_bonds
+        .extend(bonds.iter().map(|(entity, bond)| (entity, *bond)));
+    ctx.sorted_bonds.sort_by_key(|(entity, _)| entity.to_bits());
+
+    for &(entity, bond) in &ctx.sorted_bonds {
+        let (Ok((ta, pa)), Ok((tb, pb))) = (positions.get(bond.a), positions.get(bond.b)) else {
+            // An atom is gone: the bond goes with it
+            commands.entity(entity).despawn();
+            continue;
+        };
+        let r = ta
+            .translation
+            .truncate()
+            .distance(tb.translation.truncate());
+        if bond.is_broken_at(r) {
+            commands.entity(entity).despawn();
+            events.write(BondEvent {
+                a: bond.a,
+                b: bond.b,
+                formed: false,
+                energy_released: bond.energy(r) - pair_energy(pa, pb, r),
+            });
+            continue;
+        }
+        ctx.bonded.insert(bond.key());
+        *ctx.bond_counts.entry(bond.a).or_default() += 1;
+        *ctx.bond_counts.entry(bond.b).or_default() += 1;
+    }
+
+    // Angle terms live only while both arms are bonded
+    for (entity, angle) in angles.iter() {
+        if !ctx.bonded.contains(&pair_key(angle.a, angle.vertex))
+            || !ctx.bonded.contains(&pair_key(angle.vertex, angle.b))
+        {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    let max_bond_length = atoms
+        .iter()
+        .map(|(_, _, bonding, ..)| bonding.bond_length)
+        .fold(0.0_f32, f32::max);
+    ctx.sorted_atoms.clear();
+    ctx.sorted_atoms.extend(atoms.iter().map(|(e, ..)| e));
+    ctx.sorted_atoms.sort_by_key(|e| e.to_bits());
+
+    let free = |e: Entity, b: &Bonding, counts: &HashMap<Entity, u32>| {
+        counts.get(&e).copied().unwrap_or(0) < b.valence
+    };
+    for i in 0..ctx.sorted_atoms.len() {
+        let entity_a = ctx.sorted_atoms[i];
+        let Ok((_, transform_a, bonding_a, velocity_a, mass_a, pair_a)) = atoms.get(entity_a)
+        else {
+            continue;
+        };
+        let pos_a = transform_a.translation.truncate();
+        let radius = config.formation_tolerance * 0.5 * (bonding_a.bond_length + max_bond_length);
+        sorted_candidates(&index, pos_a, radius, &mut ctx.neighbor_candidates);
+
+        for j in 0..ctx.neighbor_candidates.len() {
+            let entity_b = ctx.neighbor_candidates[j];
+            if entity_b.to_bits() <= entity_a.to_bits() {
+                continue;
+            }
+            if !free(entity_a, bonding_a, &ctx.bond_counts) {
+                break;
+            }
+            let Ok((_, transform_b, bonding_b, velocity_b, mass_b, pair_b)) = atoms.get(entity_b)
+            else {
+                continue;
+            };
+            if !free(entity_b, bonding_b, &ctx.bond_counts)
+                || ctx.bonded.contains(&pair_key(entity_a, entity_b))
+            {
+                continue;
+            }
+
+            let r = pos_a.distance(transform_b.translation.truncate());
+            let bond = bonding_a.bond_with(bonding_b, entity_a, entity_b);
+            if r >= config.formation_tolerance * bond.rest_length {
+                continue;
+            }
+            let finite_mass = |m: Option<&Mass>| m.filter(|m| !m.is_infinite).map(|m| m.value);
+            let reduced_mass = match (finite_mass(mass_a), finite_mass(mass_b)) {
+                (Some(ma), Some(mb)) => ma * mb / (ma + mb),
+                (Some(m), None) | (None, Some(m)) => m,
+                (None, None) => 0.0,
+            };
+            let linvel = |v: Option<&Velocity>| v.map_or(Vec2::ZERO, |v| v.linvel.truncate());
+            let relative_kinetic =
+                0.5 * reduced_mass * (linvel(velocity_b) - linvel(velocity_a)).length_squared();
+            if relative_kinetic >= bond.dissociation_energy {
+                continue;
+            }
+
+            commands.spawn(bond);
+            ctx.bonded.insert(bond.key());
+            *ctx.bond_counts.entry(entity_a).or_default() += 1;
+            *ctx.bond_counts.entry(entity_b).or_default() += 1;
+            events.write(BondEvent {
+                a: entity_a,
+                b: entity_b,
+                formed: true,
+                energy_released: pair_energy(pair_a, pair_b, r) - bond.energy(r),
+            });
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct MolecularScratch {
+    atoms: HashMap<Entity, (Vec2, PairPotential, CollisionLayers)>,
+    sorted_atoms: Vec<Entity>,
+    bonded: HashSet<(Entity, Entity)>,
+    forces: HashMap<Entity, Vec2>,
+    neighbor_candidates: Vec<Entity>,
+}
+
+/// Accumulate pair, bond and angle forces and report the potential energy.
+///
+/// **PHYSICS**: Bonded pairs are excluded from the pair potential (their interaction
+/// is the bond), as are pairs whose `CollisionLayers` do not interact. Every term
+/// applies equal and opposite forces, so momentum is conserved.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn apply_molecular_forces(
+    pair_atoms: Query<(Entity, &Transform, &PairPotential, Option<&CollisionLayers>)>,
+    positions: Query<&Transform>,
+    bonds: Query<&Bond>,
+    angles: Query<&BondAngle>,
+    mut applied: Query<&mut AppliedForce>,
+    index: Res<UnifiedSpatialIndex>,
+    config: Res<MolecularConfig>,
+    mut reports: MessageWriter<MolecularEnergyEvent>,
+    mut ctx: Local<MolecularScratch>,
+) {
+    if pair_atoms.is_empty() && bonds.is_empty() && angles.is_empty() {
+        return;
+    }
+    let ctx = &mut *ctx;
+    let mut report = MolecularEnergyEvent::default();
+    ctx.forces.clear();
+    let position = |e: Entity| positions.get(e).ok().map(|t| t.translation.truncate());
+
+    ctx.bonded.clear();
+    for bond in bonds.iter() {
+        let (Some(pa), Some(pb)) = (position(bond.a), position(bond.b)) else {
+            continue;
+        };
+        ctx.bonded.insert(bond.key());
+        let r_vec = pb - pa;
+        let r = r_vec.length();
+        report.bond += bond.energy(r);
+        if r > f32::EPSILON {
+            let force = bond.stiffness * (r - bond.rest_length) / r * r_vec;
+            *ctx.forces.entry(bond.a).or_default() += force;
+            *ctx.forces.entry(bond.b).or_default() -= force;
+        }
+    }
+
+    for angle in angles.iter() {
+        let (Some(pa), Some(pv), Some(pb)) =
+            (position(angle.a), position(angle.vertex), position(angle.b))
+        else {
+            continue;
+        };
+        let (energy, [fa, fv, fb]) = angle.energy_and_forces(pa, pv, pb);
+        report.angle += energy;
+        *ctx.forces.entry(angle.a).or_default() += fa;
+        *ctx.forces.entry(angle.vertex).or_default() += fv;
+        *ctx.forces.entry(angle.b).or_default() += fb;
+    }
+
+    ctx.atoms.clear();
+    ctx.atoms.extend(pair_atoms.iter().map(|(e, t, p, l)| {
+        (
+            e,
+            (t.translation.truncate(), *p, l.copied().unwrap_or_default()),
+        )
+    }));
+    ctx.sorted_atoms.clear();
+    ctx.sorted_atoms.extend(ctx.atoms.keys().copied());
+    ctx.sorted_atoms.sort_by_key(|e| e.to_bits());
+    let max_distance = ctx
+        .atoms
+        .values()
+        .map(|(_, p, _)| p.equilibrium_distance())
+        .fold(0.0_f32, f32::max);
+
+    for &entity_a in &ctx.sorted_atoms {
+        let (pos_a, potential_a, layers_a) = ctx.atoms[&entity_a];
+        let radius =
+            config.cutoff_factor * 0.5 * (potential_a.equilibrium_distance() + max_distance);
+        sorted_candidates(&index, pos_a, radius, &mut ctx.neighbor_candidates);
+        for &entity_b in &ctx.neighbor_candidates {
+            // Pair-once guarantee: only process pairs where B > A
+            if entity_b.to_bits() <= entity_a.to_bits()
+                || ctx.bonded.contains(&(entity_a, entity_b))
+            {
+                continue;
+            }
+            let Some((pos_b, potential_b, layers_b)) = ctx.atoms.get(&entity_b) else {
+                continue;
+            };
+            if !layers_a.interacts_with(layers_b) {
+                continue;
+            }
+            let r_vec = *pos_b - pos_a;
+            let r = r_vec.length();
+            if r <= f32::EPSILON {
+                continue;
+            }
+            let (energy, slope) = config.pair_energy(&potential_a.mix(potential_b), r);
+            report.pair += energy;
+            // F_a = (dU/dr)·r̂_ab: attraction (dU/dr > 0) pulls A toward B
+            let force = slope / r * r_vec;
+            *ctx.forces.entry(entity_a).or_default() += force;
+            *ctx.forces.entry(entity_b).or_default() -= force;
+        }
+    }
+
+    for (&entity, &force) in &ctx.forces {
+        if let Ok(mut applied) = applied.get_mut(entity) {
+            applied.force += force.extend(0.0);
+        }
+    }
+    reports.write(report);
+}
+
+/// Pair potentials, bonds and angles between atoms.
+pub struct MolecularForcesPlugin;
+
+impl Plugin for MolecularForcesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MolecularConfig>()
+            .init_resource::<UnifiedSpatialIndex>()
+            .register_type::<MolecularConfig>()
+            .register_type::<PairPotential>()
+            .register_type::<Bonding>()
+            .register_type::<Bond>()
+            .register_type::<BondAngle>()
+            .add_message::<BondEvent>()
+            .add_message::<MolecularEnergyEvent>()
+            .add_systems(
+                PreUpdate,
+                mark_molecular_entities_spatially_indexed.in_set(SpatialIndexSet::InjectMarkers),
+            )
+            .configure_sets(
+                FixedUpdate,
+                (MolecularSet::Bonding, MolecularSet::Forces)
+                    .chain()
+                    .in_set(PhysicsSet::AccumulateForces),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    (update_bonds, ApplyDeferred)
+                        .chain()
+                        .in_set(MolecularSet::Bonding),
+                    apply_molecular_forces.in_set(MolecularSet::Forces),
+                ),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pair_potentials_minimum_and_gradient() {
+        let config = MolecularConfig::default();
+        for potential in [
+            PairPotential::lennard_jones(2.0, 1.0),
+            PairPotential::morse(2.0, 1.122, 3.0),
+        ] {
+            let r_m = potential.equilibrium_distance();
+            let (u, du) = potential.energy_and_derivative(r_m);
+            assert!((u + 2.0).abs() < 1e-4, "{:?}: U(r_m) = {}", potential, u);
+            assert!(du.abs() < 1e-3);
+
+            // Forces are exact gradients, through the core and the cutoff switch
+            let r_cut = config.cutoff_factor * r_m;
+            for r in [0.5 * r_m, 0.9 * r_m, 1.5 * r_m, 0.9 * r_cut] {
+                let h = 1e-3;
+                let numeric = (config.pair_energy(&potential, r + h).0
+                    - config.pair_energy(&potential, r - h).0)
+                    / (2.0 * h);
+                let analytic = config.pair_energy(&potential, r).1;
+                assert!(
+                    (numeric - analytic).abs() < 1e-2 * (1.0 + analytic.abs()),
+                    "{:?} at {}: {} vs {}",
+                    potential,
+                    r,
+                    numeric,
+                    analytic
+                );
+            }
+            assert_eq!(config.pair_energy(&potential, r_cut), (0.0, 0.0));
+        }
+
+        // Lorentz-Berthelot mixing
+        let mixed =
+            PairPotential::lennard_jones(1.0, 1.0).mix(&PairPotential::lennard_jones(4.0, 3.0));
+        assert!((mixed.well_depth() - 2.0).abs() < 1e-6);
+        assert!((mixed.equilibrium_distance() - 2f32.powf(1.0 / 6.0) * 2.0).abs() < 1e-5);
+    }
+
+    fn spawn_atom(app: &mut App, position: Vec2, components: impl Bundle) -> Entity {
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::from_translation(position.extend(0.0)),
+                AppliedForce::new(Vec3::ZERO),
+                components,
+            ))
+            .id();
+        app.world_mut()
+            .resource_mut::<UnifiedSpatialIndex>()
+            .insert(entity, position);
+        entity
+    }
+
+    fn molecular_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<MolecularConfig>()
+            .init_resource::<UnifiedSpatialIndex>()
+            .add_message::<BondEvent>()
+            .add_message::<MolecularEnergyEvent>()
+            .add_systems(
+                Update,
+                (update_bonds, ApplyDeferred, apply_molecular_forces).chain(),
+            );
+        app
+    }
+
+    #[test]
+    fn test_bent_molecule_forces_are_conservative() {
+        let mut app = molecular_app();
+        let potential = PairPotential::lennard_jones(0.5, 0.8);
+        let o = spawn_atom(&mut app, Vec2::ZERO, potential);
+        let h1 = spawn_atom(&mut app, Vec2::new(1.1, 0.1), potential);
+        let h2 = spawn_atom(&mut app, Vec2::new(-0.2, 0.9), potential);
+        let spectator = spawn_atom(&mut app, Vec2::new(1.5, 1.3), potential);
+        app.world_mut().spawn(Bond::new(o, h1, 1.0, 50.0));
+        app.world_mut().spawn(Bond::new(o, h2, 1.0, 50.0));
+        app.world_mut()
+            .spawn(BondAngle::new(h1, o, h2, 104.5f32.to_radians(), 20.0));
+
+        fn total_energy(app: &mut App) -> f32 {
+            app.update();
+            let reports = app.world().resource::<Messages<MolecularEnergyEvent>>();
+            let report = *reports.iter_current_update_messages().last().unwrap();
+            report.potential_energy()
+        }
+        let energy = total_energy(&mut app);
+        let forces: Vec<Vec2> = [o, h1, h2, spectator]
+            .iter()
+            .map(|&e| app.world().get::<AppliedForce>(e).unwrap().force.truncate())
+            .collect();
+
+        // Newton's third law: internal forces sum to zero
+        let net: Vec2 = forces.iter().sum();
+        assert!(net.length() < 1e-3, "net force {:?}", net);
+
+        // F = -∇U: a small move of h2 changes U by -F·δ
+        let delta = Vec2::new(1e-3, -2e-3);
+        app.world_mut()
+            .get_mut::<Transform>(h2)
+            .unwrap()
+            .translation += delta.extend(0.0);
+        for &e in &[o, h1, h2, spectator] {
+            app.world_mut().get_mut::<AppliedForce>(e).unwrap().force = Vec3::ZERO;
+        }
+        let moved = total_energy(&mut app);
+        let predicted = -forces[2].dot(delta);
+        assert!(
+            (moved - energy - predicted).abs() < 0.05 * predicted.abs(),
+            "ΔU = {} vs -F·δ = {}",
+            moved - energy,
+            predicted
+        );
+    }
+
+    #[test]
+    fn test_filtered_atoms_feel_no_pair_force() {
+        let mut app = molecular_app();
+        let potential = PairPotential::lennard_jones(0.5, 0.8);
+        let a = spawn_atom(&mut app, Vec2::ZERO, potential);
+        let b = spawn_atom(&mut app, Vec2::new(1.0, 0.0), potential);
+        // Its own layer only: invisible to the default-layer atoms
+        let loner = spawn_atom(
+            &mut app,
+            Vec2::new(0.0, 1.0),
+            (potential, CollisionLayers::new(2, 2)),
+        );
+        app.update();
+
+        let force = |e: Entity| app.world().get::<AppliedForce>(e).unwrap().force;
+        assert_eq!(force(loner), Vec3::ZERO);
+        assert!(force(a).length() > 0.0);
+        assert!((force(a) + force(b)).length() < 1e-5);
+        assert!(force(a).y.abs() < 1e-6, "no pull toward the loner");
+    }
+
+    #[test]
+    fn test_bonds_form_break_and_drop_angles() {
+        let mut app = molecular_app();
+        let site = Bonding::new(1, 1.0, 100.0, 2.0);
+        let center = Bonding::new(2, 1.0, 100.0, 2.0);
+        let a = spawn_atom(&mut app, Vec2::ZERO, (center, Mass::new(1.0)));
+        let b = spawn_atom(&mut app, Vec2::new(1.05, 0.0), (site, Mass::new(1.0)));
+        let c = spawn_atom(&mut app, Vec2::new(-1.05, 0.0), (site, Mass::new(1.0)));
+        let f = spawn_atom(&mut app, Vec2::new(0.0, 3.0), (site, Mass::new(1.0)));
+        // Too fast to be captured: ½·μ·v² = ½·0.5·9 > D
+        spawn_atom(
+            &mut app,
+            Vec2::new(10.0, 0.0),
+            (site, Mass::new(1.0), Velocity::default()),
+        );
+        spawn_atom(
+            &mut app,
+            Vec2::new(11.0, 0.0),
+            (
+                site,
+                Mass::new(1.0),
+                Velocity {
+                    linvel: Vec3::new(3.0, 0.0, 0.0),
+                    angvel: Vec3::ZERO,
+                },
+            ),
+        );
+        app.update();
+
+        let mut bonds: Vec<(Entity, Entity)> = app
+            .world_mut()
+            .query::<&Bond>()
+            .iter(app.world())
+            .map(|bond| bond.key())
+            .collect();
+        let mut expected = vec![pair_key(a, b), pair_key(a, c)];
+        bonds.sort_by_key(|(x, y)| (x.to_bits(), y.to_bits()));
+        expected.sort_by_key(|(x, y)| (x.to_bits(), y.to_bits()));
+        assert_eq!(bonds, expected);
+        let formed = *app
+            .world()
+            .resource::<Messages<BondEvent>>()
+            .iter_current_update_messages()
+            .find(|event| pair_key(event.a, event.b) == pair_key(a, b))
+            .unwrap();
+        assert!(formed.formed);
+        // Released energy is the bond well minus the small stretch
+        assert!((formed.energy_released - (2.0 - 0.5 * 100.0 * 0.05 * 0.05)).abs() < 1e-4);
+
+        // The angle b-a-c survives while both arms are bonded, and f cannot bond to
+        // `a` while its valence is used up
+        let angle = app
+            .world_mut()
+            .spawn(BondAngle::new(b, a, c, std::f32::consts::PI, 1.0))
+            .id();
+        let near_a = Vec2::new(0.0, 1.05);
+        app.world_mut().get_mut::<Transform>(f).unwrap().translation = near_a.extend(0.0);
+        app.world_mut()
+            .resource_mut::<UnifiedSpatialIndex>()
+            .update(f, near_a);
+        app.update();
+        assert!(app.world().get_entity(angle).is_ok());
+        assert_eq!(
+            app.world_mut().query::<&Bond>().iter(app.world()).count(),
+            2
+        );
+
+        // Stretching a-b past ½·k·Δr² > D breaks it; f takes the freed valence
+        app.world_mut()
+            .get_mut::<Transform>(b)
+            .unwrap()
+            .translation
+            .x = 1.3;
+        app.update();
+        let events: Vec<BondEvent> = app
+            .world()
+            .resource::<Messages<BondEvent>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect();
+        assert!(
+            events
+                .iter()
+                .any(|event| !event.formed && pair_key(event.a, event.b) == pair_key(a, b))
+        );
+        assert!(
+            events
+                .iter()
+                .any(|event| event.formed && pair_key(event.a, event.b) == pair_key(a, f))
+        );
+        assert!(app.world().get_entity(angle).is_err());
+    }
+}
@@ -10,27 +10,33 @@
 //! - **Systems**: `integrate_newton_second_law`, `integrate_torques`
 //! - **Use cases**: Objects that don't deform or need particle-level resolution
 //!
-//! ## MPM Physics (Future: `crates/systems/mpm/`)
+//! ## MPM Physics (`crates/systems/mpm/`)
 //! - **Scope**: All continuum matter (water, soil, flesh, deformable solids)
 //! - **Representation**: Particles + grid (Material Point Method)
 //! - **Integration**: P2G transfer → grid solve → G2P transfer
 //! - **Use cases**: Anything that flows, deforms, or needs material-level physics
+//! - **Ownership**: MPM particles carry `IntegratorKind::External`, so the systems in this
+//!   module never integrate them a second time
+//! - **Coupling**: Entities with `Mass` + `AppliedForce` + an MPM collider exchange
+//!   momentum with the grid; the grid's reaction arrives here as `AppliedForce` /
+//!   `AppliedTorque` before integration
 //!
 //! ## Unified Conservation
 //! Both backends feed the same **energy ledger** (`crates/energy/conservation.rs`):
 //! - Entity backend: `WorkDoneEvent`, `RotationalWorkEvent` → ledger
-//! - MPM backend: Grid work events → same ledger (when implemented)
-//! - Diagnostics: Aggregate across both backends for global conservation tracking
+//! - MPM backend: per-step `ContinuumEnergyEvent` totals → same ledger
+//! - Diagnostics: `ForcesDiagnostics` aggregates both backends for global conservation tracking
 //!
 //! ## TODO (MPM Implementation)
-//! When MPM is implemented:
 //! - [ ] MPM will compute `L = Σ(r × m·v)` from particles (not `MomentOfInertia` components)
-//! - [ ] MPM will emit work events from grid-particle transfers
-//! - [ ] `ForcesDiagnostics` will aggregate both entity and MPM contributions
+//! - [x] MPM emits energy totals from grid-particle transfers
+//! - [x] `ForcesDiagnostics` aggregates both entity and MPM contributions
 //! - [ ] Gravity and other forces will have MPM-specific implementations
 
+use super::layers::CollisionLayers;
 use crate::PhysicsSet;
 use bevy::prelude::*;
+use matter::geometry::Shape;
 
 /// Trait for computing the squared norm of a vector efficiently
 pub trait Norm {
@@ -284,6 +290,31 @@ impl MomentOfInertia {
     pub fn point_mass(mass: f32, radius: f32) -> Self {
         Self::new(mass * radius * radius)
     }
+
+    /// Moment of inertia of a uniform body of any shape about its centroid
+    /// I = m * J / A (polar second moment of area over area)
+    pub fn from_shape(mass: f32, shape: &Shape) -> Self {
+        Self::new(shape.moment_of_inertia(mass))
+    }
+}
+
+/// Keep `MomentOfInertia` consistent with `Shape` and `Mass`.
+///
+/// Bodies with a `Shape` get I = m·J/A whenever the shape or mass changes (infinite
+/// mass means infinite inertia). Bodies without one keep their hand-set inertia.
+#[allow(clippy::type_complexity)]
+pub fn derive_moment_of_inertia(
+    mut commands: Commands,
+    bodies: Query<(Entity, &Shape, &Mass), Or<(Changed<Shape>, Changed<Mass>)>>,
+) {
+    for (entity, shape, mass) in bodies.iter() {
+        let inertia = if mass.is_infinite {
+            MomentOfInertia::infinite()
+        } else {
+            MomentOfInertia::from_shape(mass.value, shape)
+        };
+        commands.entity(entity).insert(inertia);
+    }
 }
 
 /// Component representing torque applied to an entity.
@@ -358,6 +389,7 @@ impl AppliedTorque {
 ///
 /// **TODO (MPM)**: When MPM implemented, continuum particles will use grid-based velocity
 /// updates during G2P transfer instead of this entity-based approach.
+#[allow(clippy::type_complexity)]
 pub fn integrate_newton_second_law_velocity_verlet(
     time: Res<Time>,
     config: Res<IntegrationConfig>,
@@ -367,12 +399,17 @@ pub fn integrate_newton_second_law_velocity_verlet(
         &mut Velocity,
         &mut PreviousAcceleration,
         &mut AppliedForce,
+        Option<&IntegratorKind>,
     )>,
     mut work_events: MessageWriter<WorkDoneEvent>,
 ) {
     let dt = time.delta_secs();
 
-    for (entity, mass, mut velocity, mut prev_accel, mut force) in query.iter_mut() {
+    for (entity, mass, mut velocity, mut prev_accel, mut force, kind) in query.iter_mut() {
+        if is_externally_integrated(kind) {
+            continue;
+        }
+
         if mass.is_infinite || mass.is_negligible() {
             continue;
         }
@@ -431,12 +468,22 @@ pub fn integrate_newton_second_law_velocity_verlet(
 pub fn integrate_newton_second_law(
     time: Res<Time>,
     config: Res<IntegrationConfig>,
-    mut query: Query<(Entity, &Mass, &mut Velocity, &mut AppliedForce)>,
+    mut query: Query<(
+        Entity,
+        &Mass,
+        &mut Velocity,
+        &mut AppliedForce,
+        Option<&IntegratorKind>,
+    )>,
     mut work_events: MessageWriter<WorkDoneEvent>,
 ) {
     let dt = time.delta_secs();
 
-    for (entity, mass, mut velocity, mut force) in query.iter_mut() {
+    for (entity, mass, mut velocity, mut force, kind) in query.iter_mut() {
+        if is_externally_integrated(kind) {
+            continue;
+        }
+
         if mass.is_infinite || mass.is_negligible() {
             continue;
         }
@@ -493,12 +540,21 @@ pub fn integrate_newton_second_law(
 /// `integrate_newton_second_law_velocity_verlet` which has access to forces/accelerations.
 pub fn integrate_positions_velocity_verlet(
     time: Res<Time>,
-    mut query: Query<(&Velocity, &PreviousAcceleration, &mut Transform)>,
+    mut query: Query<(
+        &Velocity,
+        &PreviousAcceleration,
+        &mut Transform,
+        Option<&IntegratorKind>,
+    )>,
 ) {
     let dt = time.delta_secs();
     let dt_sq_half = 0.5 * dt * dt;
 
-    for (velocity, prev_accel, mut transform) in query.iter_mut() {
+    for (velocity, prev_accel, mut transform, kind) in query.iter_mut() {
+        if is_externally_integrated(kind) {
+            continue;
+        }
+
         // x(t+dt) = x(t) + v(t)·dt + 0.5·a(t)·dt²
         transform.translation += velocity.linvel * dt + prev_accel.linaccel * dt_sq_half;
 
@@ -517,11 +573,15 @@ pub fn integrate_positions_velocity_verlet(
 /// System to apply symplectic Euler integration for position updates (deprecated, for comparison only)
 pub fn integrate_positions_symplectic_euler(
     time: Res<Time>,
-    mut query: Query<(&Velocity, &mut Transform)>,
+    mut query: Query<(&Velocity, &mut Transform, Option<&IntegratorKind>)>,
 ) {
     let dt = time.delta_secs();
 
-    for (velocity, mut transform) in query.iter_mut() {
+    for (velocity, mut transform, kind) in query.iter_mut() {
+        if is_externally_integrated(kind) {
+            continue;
+        }
+
         transform.translation += velocity.linvel * dt;
 
         if velocity.angvel.norm_squared() > 0.0 {
@@ -531,7 +591,11 @@ pub fn integrate_positions_symplectic_euler(
 }
 
 /// Selects which integration path the Newton systems should use.
-#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// As a resource it picks the global integrator. As a component it is a per-entity
+/// override: entities tagged `IntegratorKind::External` are skipped by every Newton
+/// integration system (MPM particles carry it so they are never double-integrated).
+#[derive(Resource, Component, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum IntegratorKind {
     /// Velocity Verlet (2nd order, ~0.01% energy drift over long runs) - RECOMMENDED
     VelocityVerlet,
@@ -547,6 +611,12 @@ impl Default for IntegratorKind {
     }
 }
 
+/// True when an entity opted out of Newton integration via `IntegratorKind::External`.
+#[inline]
+fn is_externally_integrated(kind: Option<&IntegratorKind>) -> bool {
+    matches!(kind, Some(IntegratorKind::External))
+}
+
 fn use_velocity_verlet(kind: Res<IntegratorKind>) -> bool {
     *kind == IntegratorKind::VelocityVerlet
 }
@@ -562,6 +632,7 @@ fn use_symplectic_euler(kind: Res<IntegratorKind>) -> bool {
 /// - Where α(t) stored in PreviousAcceleration, α(t+dt) computed from current torques
 ///
 /// **ACCURACY**: 2nd-order, preserves angular momentum for torque-free motion
+#[allow(clippy::type_complexity)]
 pub fn integrate_torques_velocity_verlet(
     time: Res<Time>,
     config: Res<IntegrationConfig>,
@@ -571,12 +642,17 @@ pub fn integrate_torques_velocity_verlet(
         &mut Velocity,
         &mut PreviousAcceleration,
         &mut AppliedTorque,
+        Option<&IntegratorKind>,
     )>,
     mut rotational_work_events: MessageWriter<RotationalWorkEvent>,
 ) {
     let dt = time.delta_secs();
 
-    for (entity, inertia, mut velocity, mut prev_accel, mut torque) in query.iter_mut() {
+    for (entity, inertia, mut velocity, mut prev_accel, mut torque, kind) in query.iter_mut() {
+        if is_externally_integrated(kind) {
+            continue;
+        }
+
         if inertia.is_infinite {
             continue;
         }
@@ -634,12 +710,22 @@ pub fn integrate_torques_velocity_verlet(
 pub fn integrate_torques(
     time: Res<Time>,
     config: Res<IntegrationConfig>,
-    mut query: Query<(Entity, &MomentOfInertia, &mut Velocity, &mut AppliedTorque)>,
+    mut query: Query<(
+        Entity,
+        &MomentOfInertia,
+        &mut Velocity,
+        &mut AppliedTorque,
+        Option<&IntegratorKind>,
+    )>,
     mut rotational_work_events: MessageWriter<RotationalWorkEvent>,
 ) {
     let dt = time.delta_secs();
 
-    for (entity, inertia, mut velocity, mut torque) in query.iter_mut() {
+    for (entity, inertia, mut velocity, mut torque, kind) in query.iter_mut() {
+        if is_externally_integrated(kind) {
+            continue;
+        }
+
         if inertia.is_infinite {
             continue;
         }
@@ -759,6 +845,10 @@ pub struct ForceImpulse {
     pub impulse1: Vec3,
     pub entity2: Entity,
     pub impulse2: Vec3,
+    /// Angular impulse on the first entity r₁ × J₁ (N·m·s)
+    pub angular_impulse1: Vec3,
+    /// Angular impulse on the second entity r₂ × J₂ (N·m·s)
+    pub angular_impulse2: Vec3,
 }
 
 impl ForceImpulse {
@@ -769,6 +859,24 @@ impl ForceImpulse {
             impulse1: impulse_on_first,
             entity2,
             impulse2: -impulse_on_first,
+            angular_impulse1: Vec3::ZERO,
+            angular_impulse2: Vec3::ZERO,
+        }
+    }
+
+    /// Balanced impulse pair applied at points offset `lever1`/`lever2` from each
+    /// body's center of mass, so off-center hits also spin the bodies.
+    pub fn at_points(
+        entity1: Entity,
+        entity2: Entity,
+        impulse_on_first: Vec3,
+        lever1: Vec3,
+        lever2: Vec3,
+    ) -> Self {
+        Self {
+            angular_impulse1: lever1.cross(impulse_on_first),
+            angular_impulse2: lever2.cross(-impulse_on_first),
+            ..Self::new_balanced(entity1, entity2, impulse_on_first)
         }
     }
 }
@@ -779,8 +887,8 @@ impl ForceImpulse {
 /// events when forces do work on entities. Energy crate (`conservation.rs`) listens and records
 /// to the energy ledger.
 ///
-/// **TODO (MPM)**: When MPM is implemented, it will emit equivalent work events from grid-particle
-/// transfers. Both entity and MPM events feed the same `EnergyBalance` ledger for unified tracking.
+/// MPM reports continuum totals through [`ContinuumEnergyEvent`] instead (particles are not
+/// tracked individually). Both feed the energy crate's ledger for unified tracking.
 ///
 /// Energy crate listens to this to track kinetic energy changes.
 #[derive(Message)]
@@ -806,6 +914,38 @@ pub struct RotationalWorkEvent {
     pub work: f32, // Joules
 }
 
+/// Per-step energy totals of a continuum backend (MPM), in Joules.
+///
+/// **ARCHITECTURE**: Counterpart of [`WorkDoneEvent`] for continuum matter. One message per
+/// fixed tick summarizes every material point; `conservation.rs` records it in the continuum
+/// ledger and `ForcesDiagnostics` adds it to the entity totals.
+///
+/// **CONSERVATION**: `dissipated` closes the balance:
+/// E_mech(prev) + external_work = E_mech(now) + dissipated, with E_mech = kinetic + elastic + potential.
+/// It collects plastic flow, boundary friction and numerical damping of the transfers.
+#[derive(Message, Debug, Clone, Copy, Default, PartialEq)]
+pub struct ContinuumEnergyEvent {
+    /// Σ ½·m·v² over all material points (J)
+    pub kinetic: f32,
+    /// Σ V₀·ψ(F) elastic strain energy (J)
+    pub elastic: f32,
+    /// Gravitational potential energy -Σ m·g·x (J, zero at the world origin)
+    pub potential: f32,
+    /// Mechanical energy lost this step (J, ≥ 0 for a stable solver)
+    pub dissipated: f32,
+    /// Work done on the continuum by coupled entities this step (J)
+    pub external_work: f32,
+    /// Total linear momentum Σ m·v (kg·m/s)
+    pub momentum: Vec3,
+}
+
+impl ContinuumEnergyEvent {
+    /// Kinetic + elastic + gravitational potential energy (J).
+    pub fn mechanical_energy(&self) -> f32 {
+        self.kinetic + self.elastic + self.potential
+    }
+}
+
 /// Plugin that adds Newton's Laws mechanics systems in the correct order
 #[derive(Default)]
 pub struct NewtonLawsPlugin;
@@ -834,6 +974,8 @@ impl Plugin for NewtonLawsPlugin {
             .add_message::<ForceImpulse>()
             .add_message::<WorkDoneEvent>()
             .add_message::<RotationalWorkEvent>()
+            .add_message::<ContinuumEnergyEvent>()
+            .add_systems(PreUpdate, derive_moment_of_inertia)
             // Configure physics sets in FixedUpdate for deterministic simulation.
             // FixedUpdate runs at a fixed timestep independent of frame rate, preventing
             // orbital drift and non-reproducible behavior at different FPS.
@@ -843,6 +985,7 @@ impl Plugin for NewtonLawsPlugin {
                     PhysicsSet::AccumulateForces,
                     PhysicsSet::ApplyForces,
                     PhysicsSet::Integrate,
+                    PhysicsSet::SolveConstraints,
                 )
                     .chain(),
             )
@@ -862,13 +1005,25 @@ impl Plugin for NewtonLawsPlugin {
 }
 
 /// System to compute paired forces and apply them to entities
+///
+/// Pairs whose [`CollisionLayers`] do not interact are skipped.
 pub fn compute_paired_forces<T: PairedForce + Resource>(
     paired_force: Res<T>,
-    entities: Query<(Entity, &Transform, &Mass), With<PairedForceInteraction>>,
+    entities: Query<
+        (Entity, &Transform, &Mass, Option<&CollisionLayers>),
+        With<PairedForceInteraction>,
+    >,
     mut forces: Query<&mut AppliedForce>,
 ) {
-    for [(entity1, transform1, mass1), (entity2, transform2, mass2)] in entities.iter_combinations()
+    for [
+        (entity1, transform1, mass1, layers1),
+        (entity2, transform2, mass2, layers2),
+    ] in entities.iter_combinations()
     {
+        let layers = |l: Option<&CollisionLayers>| l.copied().unwrap_or_default();
+        if !layers(layers1).interacts_with(&layers(layers2)) {
+            continue;
+        }
         let pair = ForcePair {
             first: (entity1, transform1, mass1),
             second: (entity2, transform2, mass2),
@@ -890,32 +1045,56 @@ pub fn compute_paired_forces<T: PairedForce + Resource>(
 /// System to apply impulses directly to velocities
 pub fn apply_impulses(
     mut impulses: MessageReader<ForceImpulse>,
-    mut velocities: Query<(&Mass, &mut Velocity)>,
+    mut velocities: Query<(&Mass, &mut Velocity, Option<&MomentOfInertia>)>,
 ) {
     for impulse in impulses.read() {
-        // Apply to first entity
-        if let Ok((mass, mut vel)) = velocities.get_mut(impulse.entity1) {
-            if !mass.is_infinite {
-                vel.linvel += impulse.impulse1 * mass.inverse();
-            }
-        }
-
-        // Apply to second entity
-        if let Ok((mass, mut vel)) = velocities.get_mut(impulse.entity2) {
-            if !mass.is_infinite {
-                vel.linvel += impulse.impulse2 * mass.inverse();
+        for (entity, linear, angular) in [
+            (impulse.entity1, impulse.impulse1, impulse.angular_impulse1),
+            (impulse.entity2, impulse.impulse2, impulse.angular_impulse2),
+        ] {
+            if let Ok((mass, mut vel, inertia)) = velocities.get_mut(entity) {
+                if !mass.is_infinite {
+                    vel.linvel += linear * mass.inverse();
+                }
+                // Δω = L/I; bodies without inertia do not spin
+                if let Some(inertia) = inertia
+                    && angular != Vec3::ZERO
+                {
+                    vel.angvel += angular * inertia.inverse();
+                }
             }
         }
     }
 }
 
 /// Snapshot diagnostics for total momentum, angular momentum, and kinetic energy.
+///
+/// `total_*` fields cover entity-based bodies; `continuum_*` fields hold the latest
+/// [`ContinuumEnergyEvent`] so both backends can be checked together.
 #[derive(Resource, Debug, Default, Clone)]
 pub struct ForcesDiagnostics {
     pub total_momentum: Vec3,
     pub total_angular_momentum: Vec3,
     pub total_kinetic_energy: f32,
     pub total_rotational_kinetic_energy: f32,
+    pub continuum_momentum: Vec3,
+    pub continuum_kinetic_energy: f32,
+    pub continuum_elastic_energy: f32,
+}
+
+impl ForcesDiagnostics {
+    /// Linear momentum of both backends (kg·m/s).
+    pub fn combined_momentum(&self) -> Vec3 {
+        self.total_momentum + self.continuum_momentum
+    }
+
+    /// Kinetic (linear + rotational) and elastic energy of both backends (J).
+    pub fn combined_kinetic_and_elastic_energy(&self) -> f32 {
+        self.total_kinetic_energy
+            + self.total_rotational_kinetic_energy
+            + self.continuum_kinetic_energy
+            + self.continuum_elastic_energy
+    }
 }
 
 /// Updates diagnostics after velocity changes are applied.
@@ -953,6 +1132,18 @@ pub fn update_forces_diagnostics(
     diagnostics.total_rotational_kinetic_energy = total_rotational_kinetic_energy;
 }
 
+/// Copies the latest continuum energy report into the diagnostics.
+pub fn update_continuum_diagnostics(
+    mut diagnostics: ResMut<ForcesDiagnostics>,
+    mut reports: MessageReader<ContinuumEnergyEvent>,
+) {
+    if let Some(report) = reports.read().last() {
+        diagnostics.continuum_momentum = report.momentum;
+        diagnostics.continuum_kinetic_energy = report.kinetic;
+        diagnostics.continuum_elastic_energy = report.elastic;
+    }
+}
+
 /// Plugin to enable Newton-law diagnostics.
 #[derive(Default)]
 pub struct ForcesDiagnosticsPlugin;
@@ -960,7 +1151,11 @@ pub struct ForcesDiagnosticsPlugin;
 impl Plugin for ForcesDiagnosticsPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<ForcesDiagnostics>()
-            .add_systems(Update, update_forces_diagnostics.after(apply_impulses));
+            .add_message::<ContinuumEnergyEvent>()
+            .add_systems(
+                Update,
+                (update_forces_diagnostics, update_continuum_diagnostics).after(apply_impulses),
+            );
     }
 }
 
@@ -1085,6 +1280,12 @@ mod tests {
         // Point mass: I = m·r²
         let point = MomentOfInertia::point_mass(mass, radius);
         assert_eq!(point.value, 10.0 * 2.0 * 2.0); // = 40.0
+
+        // Shape-derived: a circle is the disk, a 2×1 plank is m(w² + h²)/12
+        let circle = MomentOfInertia::from_shape(mass, &Shape::circle(radius));
+        assert!((circle.value - disk.value).abs() < 1e-4);
+        let plank = MomentOfInertia::from_shape(mass, &Shape::rectangle(Vec2::new(1.0, 0.5)));
+        assert!((plank.value - 10.0 * (4.0 + 1.0) / 12.0).abs() < 1e-4);
     }
 
     #[test]
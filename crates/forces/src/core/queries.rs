@@ -0,0 +1,494 @@
+//! Scene queries over [`Collider`] bodies: raycasts, shape casts and overlaps.
+//!
+//! **ARCHITECTURE**: [`SpatialQuery`] is a read-only `SystemParam`, so line of sight,
+//! lasers or occlusion can run in any system next to the physics without owning
+//! the bodies. Candidates come from `UnifiedSpatialIndex` (which stores body
+//! origins), widened by the largest collider radius kept in [`ColliderBounds`].
+//!
+//! **LP-0**: Narrowphase reuses the contact pieces, so queries see exactly the
+//! shapes that collide. Sensors are reported like any collider; keep them out with
+//! the filter mask.
+
+use super::contact::{
+    Collider, ConvexPiece, body_shape, cast_pieces, collider_pieces, piece_separation,
+    transform_pieces,
+};
+use super::layers::CollisionLayers;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use matter::geometry::{Radius, Shape};
+use std::collections::HashSet;
+use utils::UnifiedSpatialIndex;
+
+/// Which bodies a query may report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryFilter {
+    /// Only bodies in one of these layers ([`CollisionLayers::memberships`])
+    pub mask: u32,
+    /// Bodies never reported, e.g. the caster itself
+    pub excluded: Vec<Entity>,
+}
+
+impl Default for QueryFilter {
+    fn default() -> Self {
+        Self {
+            mask: CollisionLayers::ALL,
+            excluded: Vec::new(),
+        }
+    }
+}
+
+impl QueryFilter {
+    pub fn with_mask(mut self, mask: u32) -> Self {
+        self.mask = mask;
+        self
+    }
+
+    pub fn excluding(mut self, entity: Entity) -> Self {
+        self.excluded.push(entity);
+        self
+    }
+
+    fn accepts(&self, entity: Entity, layers: Option<&CollisionLayers>) -> bool {
+        layers.copied().unwrap_or_default().in_mask(self.mask) && !self.excluded.contains(&entity)
+    }
+}
+
+/// First body hit by a ray or swept shape.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QueryHit {
+    pub entity: Entity,
+    /// Distance travelled along the cast direction (m, 0 when starting inside)
+    pub distance: f32,
+    /// World-space point of first contact (m)
+    pub point: Vec2,
+    /// Surface normal of the hit body, pointing back at the caster
+    pub normal: Vec2,
+}
+
+/// Largest bounding radius of any collider, so origin-based index lookups do not
+/// miss large bodies, and the box holding every collider origin, so casts never
+/// walk empty space past it.
+///
+/// **UNITS**: meters.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct ColliderBounds {
+    pub max_radius: f32,
+    /// Box around all collider origins, `None` when there are no colliders
+    pub extent: Option<Rect>,
+}
+
+/// Refresh [`ColliderBounds`] from the current collider shapes.
+#[allow(clippy::type_complexity)]
+pub fn update_collider_bounds(
+    colliders: Query<(&Transform, Option<&Shape>, Option<&Radius>), With<Collider>>,
+    mut bounds: ResMut<ColliderBounds>,
+) {
+    let mut next = ColliderBounds::default();
+    for (transform, shape, radius) in colliders.iter() {
+        let Some(shape) = body_shape(shape, radius) else {
+            continue;
+        };
+        let origin = transform.translation.truncate();
+        next.max_radius = next.max_radius.max(shape.bounding_radius());
+        next.extent = Some(
+            next.extent
+                .map_or(Rect::from_center_size(origin, Vec2::ZERO), |r| {
+                    r.union_point(origin)
+                }),
+        );
+    }
+    bounds.set_if_neq(next);
+}
+
+/// Most index lookups a single cast makes; longer casts use wider spans.
+const MAX_SWEEP_SPANS: usize = 256;
+
+/// Part of the ray `origin + t·direction`, `t ∈ [0, max_distance]`, inside `rect`.
+fn clip_ray(origin: Vec2, direction: Vec2, max_distance: f32, rect: Rect) -> Option<(f32, f32)> {
+    let (mut enter, mut exit) = (0.0_f32, max_distance);
+    for axis in 0..2 {
+        let (o, d) = (origin[axis], direction[axis]);
+        let (lo, hi) = (rect.min[axis], rect.max[axis]);
+        if d.abs() < f32::EPSILON {
+            if o < lo || o > hi {
+                return None;
+            }
+            continue;
+        }
+        let (t0, t1) = ((lo - o) / d, (hi - o) / d);
+        enter = enter.max(t0.min(t1));
+        exit = exit.min(t0.max(t1));
+    }
+    (enter <= exit).then_some((enter, exit))
+}
+
+type ColliderData = (
+    &'static Transform,
+    Option<&'static Shape>,
+    Option<&'static Radius>,
+    Option<&'static CollisionLayers>,
+);
+
+/// Raycasts, shape casts and overlap tests against [`Collider`] bodies.
+///
+/// **DETERMINISM**: Candidates are visited in entity order and overlap results are
+/// sorted by entity, so equal inputs give equal answers.
+#[derive(SystemParam)]
+pub struct SpatialQuery<'w, 's> {
+    index: Res<'w, UnifiedSpatialIndex>,
+    bounds: Res<'w, ColliderBounds>,
+    colliders: Query<'w, 's, ColliderData, With<Collider>>,
+}
+
+impl SpatialQuery<'_, '_> {
+    /// First body along the ray from `origin` in `direction`, up to `max_distance`.
+    ///
+    /// A ray starting inside a body hits it at distance 0 unless it is leaving.
+    pub fn cast_ray(
+        &self,
+        origin: Vec2,
+        direction: Vec2,
+        max_distance: f32,
+        filter: &QueryFilter,
+    ) -> Option<QueryHit> {
+        self.cast_circle(origin, 0.0, direction, max_distance, filter)
+    }
+
+    /// First body touched by a circle of `radius` swept from `origin`.
+    pub fn cast_circle(
+        &self,
+        origin: Vec2,
+        radius: f32,
+        direction: Vec2,
+        max_distance: f32,
+        filter: &QueryFilter,
+    ) -> Option<QueryHit> {
+        let caster = [ConvexPiece {
+            vertices: vec![origin],
+            radius: radius.max(0.0),
+        }];
+        self.sweep(
+            &caster,
+            origin,
+            radius.max(0.0),
+            direction,
+            max_distance,
+            filter,
+        )
+    }
+
+    /// First body touched by `shape` (placed at `origin`, rotated by `rotation`
+    /// radians) swept along `direction` for up to `max_distance` (finite).
+    pub fn cast_shape(
+        &self,
+        shape: &Shape,
+        origin: Vec2,
+        rotation: f32,
+        direction: Vec2,
+        max_distance: f32,
+        filter: &QueryFilter,
+    ) -> Option<QueryHit> {
+        let mut caster = Vec::new();
+        collider_pieces(shape, origin, rotation, &mut caster);
+        self.sweep(
+            &caster,
+            origin,
+            shape.bounding_radius(),
+            direction,
+            max_distance,
+            filter,
+        )
+    }
+
+    fn sweep(
+        &self,
+        caster: &[ConvexPiece],
+        origin: Vec2,
+        caster_radius: f32,
+        direction: Vec2,
+        max_distance: f32,
+        filter: &QueryFilter,
+    ) -> Option<QueryHit> {
+        let direction = direction.try_normalize()?;
+        if !max_distance.is_finite() || max_distance < 0.0 {
+            return None;
+        }
+        let reach = caster_radius + self.bounds.max_radius;
+        // Only the stretch passing near some collider origin can hit anything
+        let (near, far) = clip_ray(
+            origin,
+            direction,
+            max_distance,
+            self.bounds.extent?.inflate(reach),
+        )?;
+
+        // Walk the sweep in spans so long rays only touch nearby cells; a hit
+        // inside the current span cannot be beaten by a later one
+        let length = far - near;
+        let span = (4.0 * self.index.cell_size())
+            .max(length / MAX_SWEEP_SPANS as f32)
+            .max(f32::EPSILON);
+        let spans = ((length / span).ceil() as usize).clamp(1, MAX_SWEEP_SPANS);
+        let mut best: Option<QueryHit> = None;
+        let mut tested = HashSet::new();
+        let mut candidates = Vec::new();
+        let mut target = Vec::new();
+        for k in 0..spans {
+            let start = near + k as f32 * span;
+            let end = if k + 1 == spans { far } else { start + span };
+            candidates.clear();
+            self.index.for_each_neighbor_candidate_in_radius(
+                origin + direction * (0.5 * (start + end)),
+                0.5 * (end - start) + reach,
+                |e| candidates.push(e),
+            );
+            candidates.sort_by_key(|e| e.to_bits());
+            for &entity in &candidates {
+                if !tested.insert(entity) {
+                    continue;
+                }
+                let Ok((transform, shape, radius, layers)) = self.colliders.get(entity) else {
+                    continue;
+                };
+                if !filter.accepts(entity, layers) {
+                    continue;
+                }
+                let Some(shape) = body_shape(shape, radius) else {
+                    continue;
+                };
+                target.clear();
+                transform_pieces(&shape, transform, &mut target);
+                if let Some(hit) = cast_pieces(caster, direction, max_distance, &target)
+                    && hit.distance <= max_distance
+                    && best.is_none_or(|b| hit.distance < b.distance)
+                {
+                    best = Some(QueryHit {
+                        entity,
+                        distance: hit.distance,
+                        point: hit.point,
+                        normal: hit.normal,
+                    });
+                }
+            }
+            if best.is_some_and(|b| b.distance <= end) {
+                break;
+            }
+        }
+        best
+    }
+
+    /// Bodies containing `point`, in entity order.
+    pub fn point_overlaps(&self, point: Vec2, filter: &QueryFilter) -> Vec<Entity> {
+        let probe = ConvexPiece {
+            vertices: vec![point],
+            radius: 0.0,
+        };
+        self.overlaps(&probe, point, 0.0, filter)
+    }
+
+    /// Bodies intersecting the axis-aligned box from `min` to `max`, in entity order.
+    pub fn aabb_overlaps(&self, min: Vec2, max: Vec2, filter: &QueryFilter) -> Vec<Entity> {
+        let (min, max) = (min.min(max), min.max(max));
+        let probe = ConvexPiece {
+            vertices: vec![min, Vec2::new(max.x, min.y), max, Vec2::new(min.x, max.y)],
+            radius: 0.0,
+        };
+        let center = 0.5 * (min + max);
+        self.overlaps(&probe, center, 0.5 * (max - min).length(), filter)
+    }
+
+    fn overlaps(
+        &self,
+        probe: &ConvexPiece,
+        center: Vec2,
+        probe_radius: f32,
+        filter: &QueryFilter,
+    ) -> Vec<Entity> {
+        let mut candidates = Vec::new();
+        self.index.for_each_neighbor_candidate_in_radius(
+            center,
+            probe_radius + self.bounds.max_radius,
+            |e| candidates.push(e),
+        );
+        candidates.sort_by_key(|e| e.to_bits());
+        candidates.dedup();
+        let mut target = Vec::new();
+        candidates.retain(|&entity| {
+            let Ok((transform, shape, radius, layers)) = self.colliders.get(entity) else {
+                return false;
+            };
+            let Some(shape) = body_shape(shape, radius).filter(|_| filter.accepts(entity, layers))
+            else {
+                return false;
+            };
+            target.clear();
+            transform_pieces(&shape, transform, &mut target);
+            target.iter().any(|q| piece_separation(probe, q).0 <= 0.0)
+        });
+        candidates
+    }
+}
+
+/// Scene queries over colliders.
+pub struct SpatialQueryPlugin;
+
+impl Plugin for SpatialQueryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UnifiedSpatialIndex>()
+            .init_resource::<ColliderBounds>()
+            .register_type::<ColliderBounds>()
+            .register_type::<CollisionLayers>()
+            .add_systems(PreUpdate, update_collider_bounds);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn world_with(bodies: &[(Vec2, Shape, Option<CollisionLayers>)]) -> (World, Vec<Entity>) {
+        let mut world = World::new();
+        world.init_resource::<UnifiedSpatialIndex>();
+        world.init_resource::<ColliderBounds>();
+        let mut entities = Vec::new();
+        for (position, shape, layers) in bodies {
+            let mut body = world.spawn((
+                Collider::default(),
+                shape.clone(),
+                Transform::from_translation(position.extend(0.0)),
+            ));
+            if let Some(layers) = layers {
+                body.insert(*layers);
+            }
+            let entity = body.id();
+            world
+                .resource_mut::<UnifiedSpatialIndex>()
+                .insert(entity, *position);
+            entities.push(entity);
+        }
+        world.run_system_once(update_collider_bounds).unwrap();
+        (world, entities)
+    }
+
+    #[test]
+    fn test_ray_and_circle_casts_report_nearest_hit() {
+        let (mut world, bodies) = world_with(&[
+            (Vec2::new(5.0, 0.0), Shape::circle(1.0), None),
+            (
+                Vec2::new(10.0, 0.0),
+                Shape::rectangle(Vec2::splat(1.0)),
+                None,
+            ),
+        ]);
+        let (ray, circle, sideways) = world
+            .run_system_once(|query: SpatialQuery| {
+                let filter = QueryFilter::default();
+                (
+                    query.cast_ray(Vec2::ZERO, Vec2::X, 20.0, &filter),
+                    query.cast_circle(Vec2::new(0.0, 1.0), 0.5, Vec2::X, 20.0, &filter),
+                    query.cast_ray(Vec2::ZERO, Vec2::Y, 20.0, &filter),
+                )
+            })
+            .unwrap();
+
+        let ray = ray.unwrap();
+        assert_eq!(ray.entity, bodies[0]);
+        assert!((ray.distance - 4.0).abs() < 1e-3);
+        assert!(ray.point.distance(Vec2::new(4.0, 0.0)) < 1e-3);
+        assert!(ray.normal.distance(Vec2::NEG_X) < 1e-3);
+
+        // Off-axis circle touches when the centers are 1.5 m apart
+        let circle = circle.unwrap();
+        assert_eq!(circle.entity, bodies[0]);
+        let expected = 5.0 - (1.5f32 * 1.5 - 1.0).sqrt();
+        assert!((circle.distance - expected).abs() < 1e-3);
+        assert!((circle.normal.length() - 1.0).abs() < 1e-4 && circle.normal.y > 0.0);
+
+        assert!(sideways.is_none());
+    }
+
+    #[test]
+    fn test_very_long_rays_stay_bounded() {
+        let (mut world, bodies) = world_with(&[
+            (Vec2::ZERO, Shape::circle(1.0), None),
+            (Vec2::new(5.0e5, 0.0), Shape::circle(1.0), None),
+        ]);
+        let (empty, far, beyond_f32_steps) = world
+            .run_system_once(|query: SpatialQuery| {
+                let filter = QueryFilter::default();
+                (
+                    // Sunlight-style occlusion ray into empty space
+                    query.cast_ray(Vec2::new(0.0, 5.0), Vec2::Y, 1.0e6, &filter),
+                    query.cast_ray(Vec2::new(2.0, 0.0), Vec2::X, 1.0e6, &filter),
+                    query.cast_ray(Vec2::new(0.0, 5.0), Vec2::Y, 1.0e30, &filter),
+                )
+            })
+            .unwrap();
+        assert!(empty.is_none());
+        assert!(beyond_f32_steps.is_none());
+        let far = far.unwrap();
+        assert_eq!(far.entity, bodies[1]);
+        assert!((far.distance - (5.0e5 - 3.0)).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_filter_skips_excluded_and_masked_bodies() {
+        let plants = CollisionLayers::new(2, CollisionLayers::ALL);
+        let (mut world, bodies) = world_with(&[
+            (Vec2::new(3.0, 0.0), Shape::circle(0.5), Some(plants)),
+            (Vec2::new(6.0, 0.0), Shape::circle(0.5), None),
+            (Vec2::new(9.0, 0.0), Shape::circle(0.5), None),
+        ]);
+        let first = bodies[1];
+        let hits = world
+            .run_system_once(move |query: SpatialQuery| {
+                let everything = QueryFilter::default();
+                let no_plants = QueryFilter::default().with_mask(CollisionLayers::DEFAULT);
+                let skip_first = no_plants.clone().excluding(first);
+                [everything, no_plants, skip_first]
+                    .map(|filter| query.cast_ray(Vec2::ZERO, Vec2::X, 20.0, &filter))
+            })
+            .unwrap();
+        let entities = hits.map(|hit| hit.map(|h| h.entity));
+        assert_eq!(
+            entities,
+            [Some(bodies[0]), Some(bodies[1]), Some(bodies[2])]
+        );
+    }
+
+    #[test]
+    fn test_point_and_aabb_overlaps() {
+        let (mut world, bodies) = world_with(&[
+            (Vec2::ZERO, Shape::circle(1.0), None),
+            (
+                Vec2::new(3.0, 0.0),
+                Shape::rectangle(Vec2::new(1.0, 0.5)),
+                None,
+            ),
+            // Large body whose origin is far from the probe
+            (
+                Vec2::new(0.0, 30.0),
+                Shape::rectangle(Vec2::new(2.0, 25.0)),
+                None,
+            ),
+        ]);
+        let (inside, between, boxed) = world
+            .run_system_once(|query: SpatialQuery| {
+                let filter = QueryFilter::default();
+                (
+                    query.point_overlaps(Vec2::new(0.5, 0.5), &filter),
+                    query.point_overlaps(Vec2::new(1.5, 0.0), &filter),
+                    query.aabb_overlaps(Vec2::new(0.5, -2.0), Vec2::new(2.5, 5.5), &filter),
+                )
+            })
+            .unwrap();
+        assert_eq!(inside, vec![bodies[0]]);
+        assert!(between.is_empty());
+        let mut expected = bodies.clone();
+        expected.sort_by_key(|e| e.to_bits());
+        assert_eq!(boxed, expected);
+    }
+}
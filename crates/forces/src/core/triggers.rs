@@ -0,0 +1,318 @@
+//! Sensor colliders: trigger volumes that report overlaps without pushing.
+//!
+//! **ARCHITECTURE**: A [`Sensor`] keeps its `Collider` shape but is skipped by the
+//! contact solver. After the physics step, overlaps between sensors and other
+//! colliders (filtered by [`CollisionLayers`]) are compared with the previous step
+//! and reported as [`TriggerEnter`], [`TriggerStay`] and [`TriggerExit`], so water
+//! zones, caves and hazards can be scripted from messages.
+
+use super::contact::{Collider, ConvexPiece, body_shape, piece_separation, transform_pieces};
+use super::layers::CollisionLayers;
+use super::queries::{ColliderBounds, SpatialQueryPlugin};
+use crate::PhysicsSet;
+use bevy::prelude::*;
+use matter::geometry::{Radius, Shape};
+use std::collections::HashSet;
+use utils::UnifiedSpatialIndex;
+
+/// Marks a collider as a trigger volume: overlaps are reported, never resolved.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+#[require(Collider)]
+pub struct Sensor;
+
+/// A collider started overlapping a sensor this step.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TriggerEnter {
+    pub sensor: Entity,
+    pub other: Entity,
+}
+
+/// A collider is still overlapping a sensor (sent every step after the enter).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TriggerStay {
+    pub sensor: Entity,
+    pub other: Entity,
+}
+
+/// A collider stopped overlapping a sensor, or one of them is gone.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TriggerExit {
+    pub sensor: Entity,
+    pub other: Entity,
+}
+
+/// Sensor overlaps found in the last step, as (sensor, other) pairs.
+#[derive(Resource, Debug, Default)]
+pub struct Triggers {
+    pairs: HashSet<(Entity, Entity)>,
+}
+
+impl Triggers {
+    pub fn contains(&self, sensor: Entity, other: Entity) -> bool {
+        self.pairs.contains(&(sensor, other))
+    }
+
+    /// Colliders currently inside `sensor`.
+    pub fn inside(&self, sensor: Entity) -> impl Iterator<Item = Entity> + '_ {
+        self.pairs
+            .iter()
+            .filter(move |(s, _)| *s == sensor)
+            .map(|(_, other)| *other)
+    }
+
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct TriggerScratch {
+    sensor: Vec<ConvexPiece>,
+    other: Vec<ConvexPiece>,
+    candidates: Vec<Entity>,
+    current: Vec<(Entity, Entity)>,
+    gone: Vec<(Entity, Entity)>,
+}
+
+/// Find sensor overlaps and send enter/stay/exit messages.
+///
+/// **DETERMINISM**: Pairs are reported in (sensor, other) entity order.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub(crate) fn detect_triggers(
+    sensors: Query<
+        (
+            Entity,
+            &Transform,
+            Option<&Shape>,
+            Option<&Radius>,
+            Option<&CollisionLayers>,
+        ),
+        With<Sensor>,
+    >,
+    colliders: Query<
+        (
+            &Transform,
+            Option<&Shape>,
+            Option<&Radius>,
+            Option<&CollisionLayers>,
+        ),
+        (With<Collider>, Without<Sensor>),
+    >,
+    index: Res<UnifiedSpatialIndex>,
+    bounds: Res<ColliderBounds>,
+    mut triggers: ResMut<Triggers>,
+    mut enter: MessageWriter<TriggerEnter>,
+    mut stay: MessageWriter<TriggerStay>,
+    mut exit: MessageWriter<TriggerExit>,
+    mut ctx: Local<TriggerScratch>,
+) {
+    let ctx = &mut *ctx;
+    ctx.current.clear();
+    for (sensor, transform, shape, radius, layers) in sensors.iter() {
+        let Some(shape) = body_shape(shape, radius) else {
+            continue;
+        };
+        let layers = layers.copied().unwrap_or_default();
+        ctx.sensor.clear();
+        transform_pieces(&shape, transform, &mut ctx.sensor);
+        ctx.candidates.clear();
+        index.for_each_neighbor_candidate_in_radius(
+            transform.translation.truncate(),
+            shape.bounding_radius() + bounds.max_radius,
+            |e| ctx.candidates.push(e),
+        );
+        ctx.candidates.sort_by_key(|e| e.to_bits());
+        ctx.candidates.dedup();
+        for &other in &ctx.candidates {
+            let Ok((other_transform, other_shape, other_radius, other_layers)) =
+                colliders.get(other)
+            else {
+                continue;
+            };
+            if !layers.interacts_with(&other_layers.copied().unwrap_or_default()) {
+                continue;
+            }
+            let Some(other_shape) = body_shape(other_shape, other_radius) else {
+                continue;
+            };
+            ctx.other.clear();
+            transform_pieces(&other_shape, other_transform, &mut ctx.other);
+            let overlapping = ctx
+                .sensor
+                .iter()
+                .any(|p| ctx.other.iter().any(|q| piece_separation(p, q).0 < 0.0));
+            if overlapping {
+                ctx.current.push((sensor, other));
+            }
+        }
+    }
+    ctx.current
+        .sort_by_key(|(sensor, other)| (sensor.to_bits(), other.to_bits()));
+
+    for &(sensor, other) in &ctx.current {
+        if triggers.pairs.remove(&(sensor, other)) {
+            stay.write(TriggerStay { sensor, other });
+        } else {
+            enter.write(TriggerEnter { sensor, other });
+        }
+    }
+    // Whatever is left was overlapping last step but not now
+    ctx.gone.clear();
+    ctx.gone.extend(triggers.pairs.drain());
+    ctx.gone
+        .sort_by_key(|(sensor, other)| (sensor.to_bits(), other.to_bits()));
+    for &(sensor, other) in &ctx.gone {
+        exit.write(TriggerExit { sensor, other });
+    }
+    triggers.pairs.extend(ctx.current.iter().copied());
+}
+
+/// Trigger volumes built from [`Sensor`] colliders.
+///
+/// Adds [`SpatialQueryPlugin`] when missing: it keeps [`ColliderBounds`] current, which
+/// the sensor lookups need to see large colliders.
+pub struct TriggersPlugin;
+
+impl Plugin for TriggersPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<SpatialQueryPlugin>() {
+            app.add_plugins(SpatialQueryPlugin);
+        }
+        app.init_resource::<Triggers>()
+            .register_type::<Sensor>()
+            .add_message::<TriggerEnter>()
+            .add_message::<TriggerStay>()
+            .add_message::<TriggerExit>()
+            // Report overlaps on the positions the step ended with
+            .add_systems(
+                FixedUpdate,
+                detect_triggers.after(PhysicsSet::SolveConstraints),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::queries::update_collider_bounds;
+
+    fn drain<M: Message + Copy>(app: &mut App) -> Vec<M> {
+        app.world_mut()
+            .resource_mut::<Messages<M>>()
+            .drain()
+            .collect()
+    }
+
+    #[test]
+    fn test_sensor_reports_enter_stay_exit_for_matching_layers() {
+        let mut app = App::new();
+        app.init_resource::<ColliderBounds>()
+            .init_resource::<UnifiedSpatialIndex>()
+            .init_resource::<Triggers>()
+            .add_message::<TriggerEnter>()
+            .add_message::<TriggerStay>()
+            .add_message::<TriggerExit>()
+            .add_systems(Update, (update_collider_bounds, detect_triggers).chain());
+
+        let water = app
+            .world_mut()
+            .spawn((
+                Sensor,
+                Shape::rectangle(Vec2::new(2.0, 1.0)),
+                Transform::default(),
+            ))
+            .id();
+        let swimmer = app
+            .world_mut()
+            .spawn((
+                Collider::default(),
+                Radius { value: 0.5 },
+                Transform::default(),
+            ))
+            .id();
+        // Outside the sensor's filter: never reported
+        let ghost = app
+            .world_mut()
+            .spawn((
+                Collider::default(),
+                Radius { value: 0.5 },
+                Transform::default(),
+                CollisionLayers::new(4, CollisionLayers::ALL),
+            ))
+            .id();
+        app.world_mut()
+            .entity_mut(water)
+            .insert(CollisionLayers::new(2, CollisionLayers::DEFAULT));
+        for entity in [water, swimmer, ghost] {
+            app.world_mut()
+                .resource_mut::<UnifiedSpatialIndex>()
+                .insert(entity, Vec2::ZERO);
+        }
+
+        app.update();
+        let pair = (water, swimmer);
+        let entered: Vec<_> = drain::<TriggerEnter>(&mut app)
+            .iter()
+            .map(|e| (e.sensor, e.other))
+            .collect();
+        assert_eq!(entered, vec![pair]);
+        assert!(app.world().resource::<Triggers>().contains(water, swimmer));
+
+        app.update();
+        assert!(drain::<TriggerEnter>(&mut app).is_empty());
+        let stayed = drain::<TriggerStay>(&mut app);
+        assert_eq!(stayed.len(), 1);
+        assert_eq!((stayed[0].sensor, stayed[0].other), pair);
+
+        // Leave the water
+        app.world_mut()
+            .get_mut::<Transform>(swimmer)
+            .unwrap()
+            .translation = Vec3::new(5.0, 0.0, 0.0);
+        app.world_mut()
+            .resource_mut::<UnifiedSpatialIndex>()
+            .update(swimmer, Vec2::new(5.0, 0.0));
+        app.update();
+        let exited = drain::<TriggerExit>(&mut app);
+        assert_eq!(exited.len(), 1);
+        assert_eq!((exited[0].sensor, exited[0].other), pair);
+        assert!(app.world().resource::<Triggers>().is_empty());
+    }
+
+    #[test]
+    fn test_plugin_sees_large_collider_with_distant_origin() {
+        let tick = std::time::Duration::from_millis(20);
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TriggersPlugin))
+            .insert_resource(Time::<Fixed>::from_duration(tick))
+            .insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(tick));
+
+        let sensor = app
+            .world_mut()
+            .spawn((Sensor, Radius { value: 0.5 }, Transform::default()))
+            .id();
+        // 32 m long wall reaching over the sensor, origin 15 m away
+        let wall = app
+            .world_mut()
+            .spawn((
+                Collider::default(),
+                Shape::rectangle(Vec2::new(16.0, 1.0)),
+                Transform::from_xyz(15.0, 0.0, 0.0),
+            ))
+            .id();
+        let mut index = app.world_mut().resource_mut::<UnifiedSpatialIndex>();
+        index.insert(sensor, Vec2::ZERO);
+        index.insert(wall, Vec2::new(15.0, 0.0));
+
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(app.world().resource::<ColliderBounds>().max_radius > 15.0);
+        assert!(app.world().resource::<Triggers>().contains(sensor, wall));
+    }
+}
@@ -4,7 +4,7 @@ use bevy::prelude::*;
 pub use core::newton_laws::NewtonLawsPlugin;
 
 // TODO: Upgrade from Symplectic Euler (1st order) to Velocity Verlet (2nd order) -- needed for orbital stability over long durations
-// TODO: Implement universal contact/collision physics (momentum, energy, mass conservation) -- required for solid bodies and MPM coupling
+// TODO: Couple rigid-body contacts (core::contact) with MPM colliders
 // NOTE: Current integration: Symplectic Euler achieves ~0.1% energy drift over 830+ seconds; sufficient for LP-0 but not for precise orbital mechanics
 
 /// System sets for physics execution order.
@@ -16,6 +16,8 @@ pub enum PhysicsSet {
     ApplyForces,
     /// Integrate velocities to update positions
     Integrate,
+    /// Project joint constraints out of the integrated state (XPBD)
+    SolveConstraints,
 }
 
 /// Interface for applying forces to entities
@@ -34,13 +36,22 @@ pub struct ForcesPlugin;
 
 impl Plugin for ForcesPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((NewtonLawsPlugin, core::gravity::GravityPlugin::new()))
-            .register_type::<core::newton_laws::Mass>()
-            .register_type::<core::newton_laws::Velocity>()
-            .register_type::<core::newton_laws::AppliedForce>()
-            .register_type::<core::gravity::GravityAffected>()
-            .register_type::<core::gravity::GravitySource>()
-            .register_type::<core::gravity::MassiveBody>();
+        app.add_plugins((
+            NewtonLawsPlugin,
+            core::gravity::GravityPlugin::new(),
+            core::molecular::MolecularForcesPlugin,
+            core::contact::ContactPlugin,
+            core::joints::JointsPlugin,
+            core::character::CharacterControllerPlugin,
+            core::queries::SpatialQueryPlugin,
+            core::triggers::TriggersPlugin,
+        ))
+        .register_type::<core::newton_laws::Mass>()
+        .register_type::<core::newton_laws::Velocity>()
+        .register_type::<core::newton_laws::AppliedForce>()
+        .register_type::<core::gravity::GravityAffected>()
+        .register_type::<core::gravity::GravitySource>()
+        .register_type::<core::gravity::MassiveBody>();
     }
 }
 
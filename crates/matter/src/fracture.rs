@@ -0,0 +1,68 @@
+//! Fracture properties of solids.
+//!
+//! **Property-based**: Tensile strength and fracture energy as a component; the
+//! continuum solver (MPM) turns them into a per-particle damage variable.
+//!
+//! **PHYSICS**: Rankine criterion: a crack starts when the largest principal stress
+//! exceeds the tensile strength σ_t. Opening it to a free surface costs the fracture
+//! energy G_f per unit crack area (Griffith / cohesive crack).
+//!
+//! **LP-0**: Presets are game-scale values matched to the game-stiffness constitutive
+//! presets (e.g. MPM wood at E = 10 MPa), so the strain at failure σ_t/E stays
+//! realistic. Real wood has σ_t ≈ 40 MPa at E ≈ 10 GPa.
+
+use bevy::prelude::*;
+
+/// Tensile failure data of a solid material.
+///
+/// **UNITS**: tensile strength in Pa, fracture energy in J/m².
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct FractureProperties {
+    /// Tensile strength σ_t (Pa)
+    pub tensile_strength: f32,
+    /// Fracture energy G_f, energy per unit crack area (J/m²)
+    pub fracture_energy: f32,
+}
+
+impl FractureProperties {
+    pub fn new(tensile_strength: f32, fracture_energy: f32) -> Self {
+        debug_assert!(tensile_strength > 0.0, "Tensile strength must be positive");
+        debug_assert!(fracture_energy > 0.0, "Fracture energy must be positive");
+        Self {
+            tensile_strength: tensile_strength.max(f32::EPSILON),
+            fracture_energy: fracture_energy.max(f32::EPSILON),
+        }
+    }
+
+    /// Rock at game stiffness (E ≈ 10 MPa): brittle, fails at ~1% strain.
+    pub fn rock() -> Self {
+        Self::new(1.0e5, 20.0)
+    }
+
+    /// Wood at game stiffness (E = 10 MPa): fails at ~2% strain, tough.
+    pub fn wood() -> Self {
+        Self::new(2.0e5, 200.0)
+    }
+
+    /// Ice at game stiffness (E = 500 kPa): brittle.
+    pub fn ice() -> Self {
+        Self::new(1.0e4, 5.0)
+    }
+
+    /// Soft tissue (E = 100 kPa): tears at ~30% strain, very tough.
+    pub fn flesh() -> Self {
+        Self::new(3.0e4, 500.0)
+    }
+
+    /// Strain at which cracking starts, ε_t = σ_t / E.
+    pub fn cracking_strain(&self, youngs_modulus: f32) -> f32 {
+        self.tensile_strength / youngs_modulus.max(f32::EPSILON)
+    }
+
+    /// Energy dissipated per unit volume by a crack smeared over a band of width
+    /// `band_width` (m): g_f = G_f / h (Bažant-Oh crack band).
+    pub fn dissipation_density(&self, band_width: f32) -> f32 {
+        self.fracture_energy / band_width.max(f32::EPSILON)
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! **Property-based**: Physical dimensions as components.
 
+use std::f32::consts::PI;
+
 use bevy::prelude::*;
 
 /// Radius component for spherical or circular matter.
@@ -11,11 +13,367 @@ use bevy::prelude::*;
 /// **Property-based**: No default radius, must be explicitly set.
 /// Used for thermal contact area (A = πr²), softening length, etc.
 ///
-/// **LP-0 assumption**: Assumes spherical particles.
-/// Future: Replace with volume/shape descriptors for MPM material points.
+/// **LP-0 assumption**: Assumes spherical particles. Non-round bodies use [`Shape`].
 #[derive(Component, Debug, Clone, Copy, Reflect)]
 #[reflect(Component)]
 pub struct Radius {
     /// Radius value in meters.
     pub value: f32,
 }
+
+/// Shape of a body in its local frame (component).
+///
+/// **Property-based**: Exact geometric measures (area, perimeter, centroid, polar
+/// second moment of area) derived from the shape, so non-round bodies get the right
+/// inertia (`forces::MomentOfInertia`) and thermal contact area.
+///
+/// **UNITS**: lengths in m, areas in m², second moments in m⁴.
+///
+/// **LP-0**: 2D bodies of uniform density. Local frame: x right, y up, rotated by the
+/// entity's `Transform`. A body with both `Shape` and `Radius` uses the shape.
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub enum Shape {
+    /// Disk centered on the origin
+    Circle { radius: f32 },
+    /// Segment from (0, -half_length) to (0, half_length) swept by `radius`
+    /// (same axis as `Capsule2d`)
+    Capsule { half_length: f32, radius: f32 },
+    /// Convex polygon, vertices counter-clockwise
+    ConvexPolygon { vertices: Vec<Vec2> },
+    /// Union of non-overlapping parts
+    Compound { parts: Vec<ShapePart> },
+}
+
+/// Placed part of a [`Shape::Compound`].
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct ShapePart {
+    /// Part origin in the compound's frame (m)
+    pub offset: Vec2,
+    /// Part rotation in the compound's frame (radians)
+    pub rotation: f32,
+    pub shape: Shape,
+}
+
+impl ShapePart {
+    pub fn new(offset: Vec2, rotation: f32, shape: Shape) -> Self {
+        Self {
+            offset,
+            rotation,
+            shape,
+        }
+    }
+
+    #[inline]
+    fn to_parent(&self, point: Vec2) -> Vec2 {
+        self.offset + Vec2::from_angle(self.rotation).rotate(point)
+    }
+}
+
+impl Shape {
+    pub fn circle(radius: f32) -> Self {
+        debug_assert!(radius > 0.0, "Circle radius must be positive");
+        Self::Circle { radius }
+    }
+
+    pub fn capsule(half_length: f32, radius: f32) -> Self {
+        debug_assert!(radius > 0.0, "Capsule radius must be positive");
+        Self::Capsule {
+            half_length: half_length.max(0.0),
+            radius,
+        }
+    }
+
+    /// Axis-aligned box with the given half extents, centered on the origin.
+    pub fn rectangle(half_extents: Vec2) -> Self {
+        let Vec2 { x, y } = half_extents;
+        Self::ConvexPolygon {
+            vertices: vec![
+                Vec2::new(-x, -y),
+                Vec2::new(x, -y),
+                Vec2::new(x, y),
+                Vec2::new(-x, y),
+            ],
+        }
+    }
+
+    /// Convex hull of `points`, counter-clockwise (Andrew's monotone chain).
+    ///
+    /// Concave input is wrapped, so the result is always convex. `None` if the points
+    /// do not span a positive area.
+    pub fn convex_hull(points: &[Vec2]) -> Option<Self> {
+        let mut sorted = points.to_vec();
+        sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+        sorted.dedup();
+        if sorted.len() < 3 {
+            return None;
+        }
+
+        let turn = |o: Vec2, a: Vec2, b: Vec2| (a - o).perp_dot(b - o);
+        let mut hull: Vec<Vec2> = Vec::with_capacity(2 * sorted.len());
+        for pass in [sorted.clone(), sorted.into_iter().rev().collect()] {
+            let start = hull.len();
+            for point in pass {
+                while hull.len() >= start + 2
+                    && turn(hull[hull.len() - 2], hull[hull.len() - 1], point) <= 0.0
+                {
+                    hull.pop();
+                }
+                hull.push(point);
+            }
+            // Last point of each chain starts the other one
+            hull.pop();
+        }
+
+        let shape = Self::ConvexPolygon { vertices: hull };
+        (shape.area() > 0.0).then_some(shape)
+    }
+
+    pub fn compound(parts: Vec<ShapePart>) -> Self {
+        Self::Compound { parts }
+    }
+
+    /// Area A (m²).
+    pub fn area(&self) -> f32 {
+        match self {
+            Self::Circle { radius } => PI * radius * radius,
+            Self::Capsule {
+                half_length,
+                radius,
+            } => 4.0 * half_length * radius + PI * radius * radius,
+            Self::ConvexPolygon { vertices } => {
+                0.5 * edges(vertices).map(|(a, b)| a.perp_dot(b)).sum::<f32>()
+            }
+            Self::Compound { parts } => parts.iter().map(|part| part.shape.area()).sum(),
+        }
+    }
+
+    /// Perimeter P (m). Compounds sum their parts (touching edges count twice).
+    pub fn perimeter(&self) -> f32 {
+        match self {
+            Self::Circle { radius } => 2.0 * PI * radius,
+            Self::Capsule {
+                half_length,
+                radius,
+            } => 4.0 * half_length + 2.0 * PI * radius,
+            Self::ConvexPolygon { vertices } => edges(vertices).map(|(a, b)| a.distance(b)).sum(),
+            Self::Compound { parts } => parts.iter().map(|part| part.shape.perimeter()).sum(),
+        }
+    }
+
+    /// Centroid in the local frame (m).
+    pub fn centroid(&self) -> Vec2 {
+        match self {
+            Self::Circle { .. } | Self::Capsule { .. } => Vec2::ZERO,
+            Self::ConvexPolygon { vertices } => {
+                let (mut area, mut moment) = (0.0, Vec2::ZERO);
+                for (a, b) in edges(vertices) {
+                    let cross = a.perp_dot(b);
+                    area += 0.5 * cross;
+                    moment += (a + b) * cross;
+                }
+                moment / (6.0 * area).max(f32::EPSILON)
+            }
+            Self::Compound { parts } => {
+                let (mut area, mut moment) = (0.0, Vec2::ZERO);
+                for part in parts {
+                    let part_area = part.shape.area();
+                    area += part_area;
+                    moment += part.to_parent(part.shape.centroid()) * part_area;
+                }
+                moment / area.max(f32::EPSILON)
+            }
+        }
+    }
+
+    /// Polar second moment of area about the centroid J = ∫|r - c|² dA (m⁴).
+    ///
+    /// **PHYSICS**:
+    /// - Circle: πr⁴/2
+    /// - Capsule: 2r × 2h rectangle plus the two half-disks moved out to the ends by
+    ///   the parallel axis theorem: πr⁴/2 + πr²h² + 8hr³/3 + 4hr(r² + h²)/3
+    /// - Polygon: Σ (pᵢ × pᵢ₊₁)(pᵢ² + pᵢ·pᵢ₊₁ + pᵢ₊₁²)/12, shifted to the centroid
+    /// - Compound: Σ (Jₖ + Aₖ·|cₖ - c|²)
+    pub fn second_moment(&self) -> f32 {
+        match self {
+            Self::Circle { radius } => 0.5 * PI * radius.powi(4),
+            Self::Capsule {
+                half_length: h,
+                radius: r,
+            } => {
+                let rectangle = 4.0 / 3.0 * h * r * (r * r + h * h);
+                let caps = 0.5 * PI * r.powi(4) + PI * r * r * h * h + 8.0 / 3.0 * h * r.powi(3);
+                rectangle + caps
+            }
+            Self::ConvexPolygon { vertices } => {
+                let origin_moment: f32 = edges(vertices)
+                    .map(|(a, b)| a.perp_dot(b) * (a.dot(a) + a.dot(b) + b.dot(b)))
+                    .sum::<f32>()
+                    / 12.0;
+                origin_moment - self.area() * self.centroid().length_squared()
+            }
+            Self::Compound { parts } => {
+                let centroid = self.centroid();
+                parts
+                    .iter()
+                    .map(|part| {
+                        let offset = part.to_parent(part.shape.centroid()) - centroid;
+                        part.shape.second_moment() + part.shape.area() * offset.length_squared()
+                    })
+                    .sum()
+            }
+        }
+    }
+
+    /// Moment of inertia about the centroid for a uniform body of `mass` kg,
+    /// I = m·J/A (kg·m²).
+    pub fn moment_of_inertia(&self, mass: f32) -> f32 {
+        mass * self.second_moment() / self.area().max(f32::EPSILON)
+    }
+
+    /// Support function: farthest extent of the shape along `direction` (m), measured
+    /// from the local origin.
+    pub fn support(&self, direction: Vec2) -> f32 {
+        let direction = direction.normalize_or_zero();
+        match self {
+            Self::Circle { radius } => *radius,
+            Self::Capsule {
+                half_length,
+                radius,
+            } => half_length * direction.y.abs() + radius,
+            Self::ConvexPolygon { vertices } => vertices
+                .iter()
+                .map(|v| v.dot(direction))
+                .fold(f32::NEG_INFINITY, f32::max),
+            Self::Compound { parts } => parts
+                .iter()
+                .map(|part| {
+                    let local = Vec2::from_angle(-part.rotation).rotate(direction);
+                    part.offset.dot(direction) + part.shape.support(local)
+                })
+                .fold(f32::NEG_INFINITY, f32::max),
+        }
+    }
+
+    /// Half of the shape's width across `direction` (m): ½(support(d) + support(-d)).
+    ///
+    /// For a circle this is the radius, whatever the direction.
+    pub fn half_width(&self, direction: Vec2) -> f32 {
+        0.5 * (self.support(direction) + self.support(-direction))
+    }
+
+    /// Radius of the smallest origin-centered circle containing the shape (m).
+    pub fn bounding_radius(&self) -> f32 {
+        match self {
+            Self::Circle { radius } => *radius,
+            Self::Capsule {
+                half_length,
+                radius,
+            } => half_length + radius,
+            Self::ConvexPolygon { vertices } => {
+                vertices.iter().map(|v| v.length()).fold(0.0, f32::max)
+            }
+            Self::Compound { parts } => parts
+                .iter()
+                .map(|part| part.offset.length() + part.shape.bounding_radius())
+                .fold(0.0, f32::max),
+        }
+    }
+}
+
+/// Consecutive vertex pairs of a closed polygon.
+fn edges(vertices: &[Vec2]) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| (*a, *b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() <= 1e-4 * b.abs().max(1.0), "{} != {}", a, b);
+    }
+
+    #[test]
+    fn test_primitive_measures_match_closed_forms() {
+        let circle = Shape::circle(2.0);
+        assert_close(circle.area(), 4.0 * PI);
+        assert_close(circle.perimeter(), 4.0 * PI);
+        assert_close(circle.moment_of_inertia(3.0), 0.5 * 3.0 * 4.0);
+
+        // Rectangle 2a × 2b: I = m(a² + b²)/3 about its center
+        let (a, b) = (1.5, 0.5);
+        let box_shape = Shape::rectangle(Vec2::new(a, b));
+        assert_close(box_shape.area(), 4.0 * a * b);
+        assert_close(box_shape.perimeter(), 4.0 * (a + b));
+        assert_close(
+            box_shape.moment_of_inertia(2.0),
+            2.0 * (a * a + b * b) / 3.0,
+        );
+
+        // Capsule without a shaft is a disk
+        assert_close(
+            Shape::capsule(0.0, 2.0).second_moment(),
+            circle.second_moment(),
+        );
+
+        // Right triangle with legs 3, 4: centroid at (1, 4/3), J_c = A(a² + b²)/18
+        let triangle =
+            Shape::convex_hull(&[Vec2::ZERO, Vec2::new(3.0, 0.0), Vec2::new(0.0, 4.0)]).unwrap();
+        assert_close(triangle.area(), 6.0);
+        assert_close(triangle.perimeter(), 12.0);
+        assert!(
+            triangle
+                .centroid()
+                .abs_diff_eq(Vec2::new(1.0, 4.0 / 3.0), 1e-5)
+        );
+        assert_close(triangle.second_moment(), 6.0 * 25.0 / 18.0);
+    }
+
+    #[test]
+    fn test_capsule_matches_compound_of_its_pieces() {
+        // Shaft box plus two 256-gon half-disks: same area and J as the analytic capsule
+        let (h, r) = (0.5, 0.3);
+        let half_disk = |sign: f32| {
+            let points: Vec<Vec2> = (0..=256)
+                .map(|i| Vec2::from_angle(PI * i as f32 / 256.0) * r * Vec2::new(1.0, sign))
+                .collect();
+            ShapePart::new(
+                Vec2::new(0.0, sign * h),
+                0.0,
+                Shape::convex_hull(&points).unwrap(),
+            )
+        };
+        let pieces = Shape::compound(vec![
+            ShapePart::new(Vec2::ZERO, 0.0, Shape::rectangle(Vec2::new(r, h))),
+            half_disk(1.0),
+            half_disk(-1.0),
+        ]);
+        let capsule = Shape::capsule(h, r);
+        assert!((pieces.area() - capsule.area()).abs() < 1e-3 * capsule.area());
+        assert!(
+            (pieces.second_moment() - capsule.second_moment()).abs()
+                < 1e-3 * capsule.second_moment()
+        );
+        assert!(pieces.centroid().length() < 1e-5);
+
+        // Width across the axis is 2r, along it 2(h + r)
+        assert_close(capsule.half_width(Vec2::X), r);
+        assert_close(capsule.half_width(Vec2::Y), h + r);
+
+        // Rotating a part does not change its own J
+        let turned = Shape::compound(vec![ShapePart::new(
+            Vec2::new(2.0, 0.0),
+            0.7,
+            Shape::rectangle(Vec2::new(1.0, 0.25)),
+        )]);
+        assert_close(
+            turned.second_moment(),
+            Shape::rectangle(Vec2::new(1.0, 0.25)).second_moment(),
+        );
+        assert!(turned.centroid().abs_diff_eq(Vec2::new(2.0, 0.0), 1e-5));
+    }
+}
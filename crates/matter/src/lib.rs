@@ -1,4 +1,7 @@
+pub mod fracture;
 pub mod geometry;
+pub mod material;
+pub mod phase;
 pub mod states;
 
 use bevy::prelude::*;
@@ -12,29 +15,51 @@ impl Plugin for MatterPlugin {
         app
             // Register geometric properties
             .register_type::<geometry::Radius>()
+            .register_type::<geometry::Shape>()
+            .register_type::<fracture::FractureProperties>()
+            // Material registry (built-ins; asset libraries merge in below)
+            .init_resource::<material::MaterialRegistry>()
+            .register_type::<material::MaterialId>()
+            .register_type::<material::MaterialRegistry>()
+            // Phase transitions
+            .register_type::<phase::PhaseState>()
+            .register_type::<phase::PhaseTransitions>()
+            .register_type::<phase::LatentHeatReservoir>()
+            .add_message::<phase::PhaseTransitionEvent>()
+            // Thermal ionization (driven by energy's thermal systems)
+            .register_type::<states::plasma::ionization::Ionizable>()
+            .register_type::<states::plasma::ionization::IonizationLevel>()
             // Initialize matter systems
             .insert_resource(MatterSystemsInitialized);
 
-        // TODO: Add state-specific plugins when implementations are complete
+        // Headless apps (MinimalPlugins) have no asset server; they use the built-ins
+        if app.is_plugin_added::<AssetPlugin>() {
+            app.init_asset::<material::MaterialLibrary>()
+                .init_asset_loader::<material::MaterialLibraryLoader>()
+                .add_systems(PreUpdate, material::register_loaded_materials);
+        }
+
+        // Gas grid (idle until a GasGrid resource is inserted)
+        app.add_plugins(states::gases::GasesPlugin);
+
+        // TODO: Add the remaining state plugins when implementations are complete
         // app.add_plugins((
         //     SolidsPlugin,
         //     FluidsPlugin,
-        //     GasesPlugin,
         //     PlasmaPlugin,
         // ));
     }
 }
 
 // NOTE: Matter crate is early-stage placeholder. Blocked on:
-// 1. MPM solver stabilization and full parameter exposure (currently in systems/mpm)
+// 1. MPM solver parameter exposure (2D MLS-MPM core lives in systems/mpm)
 // 2. Universal collision physics (momentum, energy, mass conservation contracts)
-// 3. Material constitutive models (elasticity, plasticity, viscosity)
-// 4. Phase transitions, equations of state (EOS), latent heat
+// 3. Material constitutive models (elastic/snow/sand/fluid models live in systems/mpm)
+// 4. Equations of state (EOS) (phase transitions with latent heat live in `phase`)
 // 5. Energy/matter ledger integration with forces and energy crates
 //
 // When unblocked, matter will provide:
 // - Solid/fluid/gas/plasma state machines with phase transitions
-// - Material properties (density, viscosity, elasticity, thermal capacity)
 // - Coupling to MPM solver for large-N deformable body simulation
 
 /// Resource to indicate matter systems are initialized
@@ -46,8 +71,17 @@ pub mod prelude {
     pub use crate::MatterPlugin;
 
     // Geometric properties
-    pub use crate::geometry::Radius;
+    pub use crate::geometry::{Radius, Shape, ShapePart};
+
+    // Material data
+    pub use crate::fracture::FractureProperties;
+    pub use crate::material::{Material, MaterialId, MaterialLibrary, MaterialRegistry};
+
+    // Phase transitions
+    pub use crate::phase::{
+        LatentHeatReservoir, PhaseState, PhaseTransitionEvent, PhaseTransitions,
+    };
 
-    // Re-export from states module when ready
-    //pub use crate::states::prelude::*;
+    // States of matter and their equations of state
+    pub use crate::states::prelude::*;
 }
@@ -0,0 +1,386 @@
+//! Data-driven material registry.
+//!
+//! **Property-based**: A [`Material`] holds every bulk property the simulation crates
+//! read (density, thermal, electromagnetic, acoustic, elastic, phase points). Entities
+//! carry a [`MaterialId`]; thermal conduction, electromagnetism, acoustics and MPM look
+//! the id up in the [`MaterialRegistry`] and derive their own components from it, so a
+//! material is defined once instead of per subsystem.
+//!
+//! Materials load from `*.materials.ron` / `*.materials.json` assets
+//! ([`MaterialLibrary`]) and merge into the registry by name. The built-in library
+//! (`materials.ron` next to this file) seeds [`MaterialRegistry::default`].
+//!
+//! **UNITS**: SI throughout: kg/m³, J/(kg·K), W/(m·K), S/m, m/s, Pa, Pa·s, degrees for
+//! the friction angle.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::phase::PhaseTransitions;
+
+/// Built-in library, parsed by [`MaterialRegistry::default`].
+pub const BUILTIN_MATERIALS: &str = include_str!("materials.ron");
+
+/// Bulk properties of a substance.
+///
+/// Fields left out of an asset take the [`Default`] (vacuum) values, so a library only
+/// lists what a material actually has. Zero means "not applicable": a material with
+/// no Young's modulus and no bulk modulus is not simulated as a continuum.
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Material {
+    /// Unique name; [`MaterialId`] is derived from it
+    pub name: String,
+    /// Mass density ρ (kg/m³)
+    pub density: f32,
+    /// Specific heat capacity c_p (J/(kg·K))
+    pub specific_heat: f32,
+    /// Thermal conductivity k (W/(m·K))
+    pub thermal_conductivity: f32,
+    /// Emissivity ε ∈ [0, 1] (dimensionless)
+    pub emissivity: f32,
+    /// Relative permittivity εᵣ (dimensionless, 1 for vacuum)
+    pub relative_permittivity: f32,
+    /// Relative permeability μᵣ (dimensionless, 1 for vacuum)
+    pub relative_permeability: f32,
+    /// Electrical conductivity σ (S/m)
+    pub electrical_conductivity: f32,
+    /// Speed of sound c (m/s)
+    pub sound_speed: f32,
+    /// Acoustic absorption coefficient α (1/m)
+    pub acoustic_absorption: f32,
+    /// Young's modulus E (Pa), 0 for fluids
+    pub youngs_modulus: f32,
+    /// Poisson's ratio ν (dimensionless)
+    pub poisson_ratio: f32,
+    /// Bulk modulus K (Pa) of fluids, 0 for solids
+    pub bulk_modulus: f32,
+    /// Dynamic viscosity η (Pa·s)
+    pub viscosity: f32,
+    /// Internal friction angle φ (degrees) of granular media, 0 otherwise
+    pub friction_angle: f32,
+    /// Melting/boiling points and latent heats, if the material changes phase
+    pub phase: Option<PhaseTransitions>,
+}
+
+impl Default for Material {
+    /// Vacuum: no mass, no heat capacity, unit relative permittivity and permeability.
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            density: 0.0,
+            specific_heat: 0.0,
+            thermal_conductivity: 0.0,
+            emissivity: 0.0,
+            relative_permittivity: 1.0,
+            relative_permeability: 1.0,
+            electrical_conductivity: 0.0,
+            sound_speed: 0.0,
+            acoustic_absorption: 0.0,
+            youngs_modulus: 0.0,
+            poisson_ratio: 0.3,
+            bulk_modulus: 0.0,
+            viscosity: 0.0,
+            friction_angle: 0.0,
+            phase: None,
+        }
+    }
+}
+
+impl Material {
+    /// Vacuum material with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..default()
+        }
+    }
+
+    /// Id of this material in the registry.
+    pub fn id(&self) -> MaterialId {
+        MaterialId::new(&self.name)
+    }
+
+    /// Heat capacity C = m·c_p (J/K) of `mass` kg.
+    pub fn heat_capacity(&self, mass: f32) -> f32 {
+        mass * self.specific_heat
+    }
+
+    /// Thermal diffusivity α = k/(ρ·c_p) (m²/s).
+    pub fn thermal_diffusivity(&self) -> f32 {
+        self.thermal_conductivity / (self.density * self.specific_heat).max(f32::EPSILON)
+    }
+
+    /// Characteristic acoustic impedance Z = ρ·c (Pa·s/m).
+    pub fn acoustic_impedance(&self) -> f32 {
+        self.density * self.sound_speed
+    }
+
+    /// Whether the material resists shear (has a Young's modulus).
+    pub fn is_solid(&self) -> bool {
+        self.youngs_modulus > 0.0
+    }
+}
+
+/// Reference to a [`Material`] in the [`MaterialRegistry`] (component).
+///
+/// **DETERMINISM**: The id is the 64-bit FNV-1a hash of the material name, so it is
+/// the same in every run and on every machine and can be written to save files.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
+pub struct MaterialId(pub u64);
+
+impl MaterialId {
+    /// Id of the material called `name`.
+    pub const fn new(name: &str) -> Self {
+        let bytes = name.as_bytes();
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        let mut i = 0;
+        while i < bytes.len() {
+            hash ^= bytes[i] as u64;
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+            i += 1;
+        }
+        Self(hash)
+    }
+}
+
+/// All materials known to the simulation, by id (resource).
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct MaterialRegistry {
+    materials: HashMap<MaterialId, Material>,
+}
+
+impl Default for MaterialRegistry {
+    /// Registry seeded with the built-in library ([`BUILTIN_MATERIALS`]).
+    fn default() -> Self {
+        let library =
+            MaterialLibrary::from_ron(BUILTIN_MATERIALS).expect("built-in materials.ron is valid");
+        let mut registry = Self::empty();
+        registry.extend(library.materials);
+        registry
+    }
+}
+
+impl MaterialRegistry {
+    /// Registry without any material.
+    pub fn empty() -> Self {
+        Self {
+            materials: HashMap::new(),
+        }
+    }
+
+    /// Add or replace a material; returns its id.
+    pub fn insert(&mut self, material: Material) -> MaterialId {
+        let id = material.id();
+        self.materials.insert(id, material);
+        id
+    }
+
+    /// Add or replace every material of a library.
+    pub fn extend(&mut self, materials: impl IntoIterator<Item = Material>) {
+        for material in materials {
+            self.insert(material);
+        }
+    }
+
+    pub fn get(&self, id: MaterialId) -> Option<&Material> {
+        self.materials.get(&id)
+    }
+
+    pub fn get_by_name(&self, name: &str) -> Option<&Material> {
+        self.get(MaterialId::new(name))
+    }
+
+    pub fn len(&self) -> usize {
+        self.materials.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.materials.is_empty()
+    }
+
+    /// Materials in name order.
+    pub fn iter(&self) -> impl Iterator<Item = (MaterialId, &Material)> {
+        let mut entries: Vec<_> = self.materials.iter().map(|(id, m)| (*id, m)).collect();
+        entries.sort_by(|a, b| a.1.name.cmp(&b.1.name));
+        entries.into_iter()
+    }
+}
+
+/// A list of materials loaded from a `*.materials.ron` or `*.materials.json` file.
+#[derive(Asset, TypePath, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MaterialLibrary {
+    pub materials: Vec<Material>,
+}
+
+impl MaterialLibrary {
+    pub fn from_ron(source: &str) -> Result<Self, MaterialLibraryError> {
+        Ok(ron::from_str(source)?)
+    }
+
+    pub fn from_json(source: &str) -> Result<Self, MaterialLibraryError> {
+        Ok(serde_json::from_str(source)?)
+    }
+}
+
+/// Failure to read or parse a [`MaterialLibrary`].
+#[derive(Debug)]
+pub enum MaterialLibraryError {
+    Io(std::io::Error),
+    Ron(ron::error::SpannedError),
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for MaterialLibraryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "could not read material library: {error}"),
+            Self::Ron(error) => write!(f, "invalid RON material library: {error}"),
+            Self::Json(error) => write!(f, "invalid JSON material library: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for MaterialLibraryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Ron(error) => Some(error),
+            Self::Json(error) => Some(error),
+        }
+    }
+}
+
+impl From<std::io::Error> for MaterialLibraryError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<ron::error::SpannedError> for MaterialLibraryError {
+    fn from(error: ron::error::SpannedError) -> Self {
+        Self::Ron(error)
+    }
+}
+
+impl From<serde_json::Error> for MaterialLibraryError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Json(error)
+    }
+}
+
+/// Asset loader for [`MaterialLibrary`] files.
+#[derive(Default, TypePath)]
+pub struct MaterialLibraryLoader;
+
+impl AssetLoader for MaterialLibraryLoader {
+    type Asset = MaterialLibrary;
+    type Settings = ();
+    type Error = MaterialLibraryError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<MaterialLibrary, MaterialLibraryError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let source = String::from_utf8_lossy(&bytes);
+        let is_json = load_context
+            .path()
+            .path()
+            .extension()
+            .is_some_and(|extension| extension == "json");
+        if is_json {
+            MaterialLibrary::from_json(&source)
+        } else {
+            MaterialLibrary::from_ron(&source)
+        }
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["materials.ron", "materials.json"]
+    }
+}
+
+/// Merge libraries into the registry as they finish loading or are hot-reloaded.
+pub fn register_loaded_materials(
+    mut events: MessageReader<AssetEvent<MaterialLibrary>>,
+    libraries: Res<Assets<MaterialLibrary>>,
+    mut registry: ResMut<MaterialRegistry>,
+) {
+    for event in events.read() {
+        if let AssetEvent::Added { id } | AssetEvent::Modified { id } = event
+            && let Some(library) = libraries.get(*id)
+        {
+            registry.extend(library.materials.iter().cloned());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_library_covers_legacy_constants() {
+        let registry = MaterialRegistry::default();
+        let water = registry.get_by_name("water").unwrap();
+        assert_eq!(water.density, 1000.0);
+        assert_eq!(water.phase, Some(PhaseTransitions::water()));
+        assert_eq!(registry.get_by_name("iron").unwrap().specific_heat, 449.0);
+        assert_eq!(
+            registry.get_by_name("aluminum").unwrap().specific_heat,
+            897.0
+        );
+        assert_eq!(registry.get_by_name("air").unwrap().sound_speed, 343.0);
+
+        // Vacuum is the all-defaults material
+        let vacuum = registry.get(MaterialId::new("vacuum")).unwrap();
+        assert_eq!(*vacuum, Material::new("vacuum"));
+        assert!(registry.get_by_name("unobtainium").is_none());
+    }
+
+    #[test]
+    fn test_ron_and_json_libraries_agree() {
+        let ron = r#"(materials: [(name: "brick", density: 1900.0, specific_heat: 840.0)])"#;
+        let json =
+            r#"{"materials": [{"name": "brick", "density": 1900.0, "specific_heat": 840.0}]}"#;
+        let from_ron = MaterialLibrary::from_ron(ron).unwrap();
+        assert_eq!(from_ron, MaterialLibrary::from_json(json).unwrap());
+        assert_eq!(from_ron.materials[0].relative_permittivity, 1.0);
+
+        // Loading a library overrides built-ins by name
+        let mut registry = MaterialRegistry::default();
+        let before = registry.len();
+        registry.extend(
+            MaterialLibrary::from_ron(r#"(materials: [(name: "water", density: 998.0)])"#)
+                .unwrap()
+                .materials,
+        );
+        registry.extend(from_ron.materials);
+        assert_eq!(registry.len(), before + 1);
+        assert_eq!(registry.get_by_name("water").unwrap().density, 998.0);
+        assert_eq!(registry.get_by_name("brick").unwrap().density, 1900.0);
+
+        assert!(MaterialLibrary::from_ron("(materials: [(density: )])").is_err());
+    }
+
+    #[test]
+    fn test_material_id_is_stable() {
+        // FNV-1a reference vectors
+        assert_eq!(MaterialId::new("").0, 0xcbf2_9ce4_8422_2325);
+        assert_eq!(MaterialId::new("a").0, 0xaf63_dc4c_8601_ec8c);
+        const WATER: MaterialId = MaterialId::new("water");
+        assert_eq!(WATER, Material::new("water").id());
+        assert_ne!(WATER, MaterialId::new("Water"));
+    }
+}
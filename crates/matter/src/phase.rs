@@ -0,0 +1,345 @@
+//! Phase transitions with latent heat.
+//!
+//! **Property-based**: A material's melting/boiling points, latent heats and per-phase
+//! specific heats live in [`PhaseTransitions`]; the current phase in [`PhaseState`];
+//! energy stored in an unfinished transition in [`LatentHeatReservoir`].
+//!
+//! **PHYSICS**: Enthalpy method. Specific enthalpy (J/kg, 0 at 0 K solid) is a
+//! piecewise-linear, monotonic function of temperature with two plateaus:
+//! - Solid: h = c_s·T
+//! - Melting plateau at T_m, width L_f
+//! - Liquid: slope c_l
+//! - Boiling plateau at T_b, width L_v
+//! - Gas: slope c_g
+//!
+//! Heat transport (conduction, MPM grid diffusion) only changes temperature with the
+//! current phase's c_p. [`PhaseTransitions::resolve`] converts that change into
+//! enthalpy and reads back phase, temperature and reservoir, so a material crossing a
+//! transition temperature is pinned there until the latent heat has been paid.
+//!
+//! **CONSERVATION**: Resolving never changes enthalpy; latent heat is neither created
+//! nor destroyed, only moved between sensible and latent storage.
+//!
+//! **LP-0**: Constant pressure (no Clausius-Clapeyron shift of T_b), constant c_p per
+//! phase, no supercooling. Plasma is handled by ionization, not by this table.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// State of matter.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
+pub enum PhaseState {
+    Solid,
+    Liquid,
+    Gas,
+    Plasma,
+}
+
+/// Latent heat absorbed by an unfinished transition (J/kg).
+///
+/// Positive while heading up (melting/boiling), negative while heading down
+/// (freezing/condensing). Non-zero means the material sits at the transition
+/// temperature.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct LatentHeatReservoir {
+    /// Stored latent heat per unit mass (J/kg)
+    pub stored: f32,
+}
+
+/// Transition temperatures, latent heats and per-phase specific heats of a material.
+///
+/// **UNITS**: temperatures in K, latent heats in J/kg, specific heats in J/(kg·K).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+#[reflect(Component)]
+#[require(LatentHeatReservoir)]
+pub struct PhaseTransitions {
+    /// Melting / freezing point T_m (K)
+    pub melting_point: f32,
+    /// Boiling / condensation point T_b (K)
+    pub boiling_point: f32,
+    /// Latent heat of fusion L_f (J/kg)
+    pub latent_heat_fusion: f32,
+    /// Latent heat of vaporization L_v (J/kg)
+    pub latent_heat_vaporization: f32,
+    /// Specific heat of the solid c_s (J/(kg·K))
+    pub specific_heat_solid: f32,
+    /// Specific heat of the liquid c_l (J/(kg·K))
+    pub specific_heat_liquid: f32,
+    /// Specific heat of the gas c_g (J/(kg·K))
+    pub specific_heat_gas: f32,
+}
+
+impl PhaseTransitions {
+    /// Water at 1 atm: ice / water / steam.
+    pub fn water() -> Self {
+        Self {
+            melting_point: 273.15,
+            boiling_point: 373.15,
+            latent_heat_fusion: 3.34e5,
+            latent_heat_vaporization: 2.257e6,
+            specific_heat_solid: 2090.0,
+            specific_heat_liquid: 4186.0,
+            specific_heat_gas: 2010.0,
+        }
+    }
+
+    /// Basaltic rock / lava.
+    ///
+    /// **LP-0**: Single melting point; real basalt melts over ~1000-1200 °C.
+    pub fn basalt() -> Self {
+        Self {
+            melting_point: 1400.0,
+            boiling_point: 2900.0,
+            latent_heat_fusion: 4.0e5,
+            latent_heat_vaporization: 5.0e6,
+            specific_heat_solid: 840.0,
+            specific_heat_liquid: 1200.0,
+            specific_heat_gas: 1000.0,
+        }
+    }
+
+    /// Iron at 1 atm.
+    pub fn iron() -> Self {
+        Self {
+            melting_point: 1811.0,
+            boiling_point: 3134.0,
+            latent_heat_fusion: 2.47e5,
+            latent_heat_vaporization: 6.09e6,
+            specific_heat_solid: 449.0,
+            specific_heat_liquid: 820.0,
+            specific_heat_gas: 520.0,
+        }
+    }
+
+    /// Specific heat of `phase` (J/(kg·K)). Plasma uses the gas value.
+    pub fn specific_heat(&self, phase: PhaseState) -> f32 {
+        match phase {
+            PhaseState::Solid => self.specific_heat_solid,
+            PhaseState::Liquid => self.specific_heat_liquid,
+            PhaseState::Gas | PhaseState::Plasma => self.specific_heat_gas,
+        }
+    }
+
+    /// Equilibrium phase at `temperature` (K), away from the plateaus.
+    pub fn phase_at(&self, temperature: f32) -> PhaseState {
+        if temperature < self.melting_point {
+            PhaseState::Solid
+        } else if temperature < self.boiling_point {
+            PhaseState::Liquid
+        } else {
+            PhaseState::Gas
+        }
+    }
+
+    /// Specific enthalpy at the start of each plateau and the end of it (J/kg):
+    /// (melting start, melting end, boiling start, boiling end).
+    fn plateaus(&self) -> (f32, f32, f32, f32) {
+        let melt_start = self.specific_heat_solid * self.melting_point;
+        let melt_end = melt_start + self.latent_heat_fusion;
+        let boil_start =
+            melt_end + self.specific_heat_liquid * (self.boiling_point - self.melting_point);
+        (
+            melt_start,
+            melt_end,
+            boil_start,
+            boil_start + self.latent_heat_vaporization,
+        )
+    }
+
+    /// Specific enthalpy (J/kg) of material in `phase` at `temperature` holding `latent`.
+    ///
+    /// Temperatures outside the phase's range extrapolate with that phase's c_p, which
+    /// is exactly how heat transport moved them there.
+    pub fn specific_enthalpy(&self, phase: PhaseState, temperature: f32, latent: f32) -> f32 {
+        let (_, melt_end, _, boil_end) = self.plateaus();
+        let sensible = match phase {
+            PhaseState::Solid => self.specific_heat_solid * temperature,
+            PhaseState::Liquid => {
+                melt_end + self.specific_heat_liquid * (temperature - self.melting_point)
+            }
+            PhaseState::Gas | PhaseState::Plasma => {
+                boil_end + self.specific_heat_gas * (temperature - self.boiling_point)
+            }
+        };
+        sensible + latent
+    }
+
+    /// Phase, temperature (K) and latent reservoir (J/kg) for specific enthalpy `h`.
+    ///
+    /// On a plateau the phase label stays the one the material came from: ice absorbing
+    /// heat at 0 °C is still `Solid` until all of L_f is stored.
+    pub fn resolve(&self, previous: PhaseState, h: f32) -> (PhaseState, f32, f32) {
+        let (melt_start, melt_end, boil_start, boil_end) = self.plateaus();
+        let from_below = |phase: PhaseState| previous as u8 <= phase as u8;
+        if h < melt_start {
+            (PhaseState::Solid, h / self.specific_heat_solid, 0.0)
+        } else if h < melt_end {
+            if from_below(PhaseState::Solid) {
+                (PhaseState::Solid, self.melting_point, h - melt_start)
+            } else {
+                (PhaseState::Liquid, self.melting_point, h - melt_end)
+            }
+        } else if h < boil_start {
+            let temperature = self.melting_point + (h - melt_end) / self.specific_heat_liquid;
+            (PhaseState::Liquid, temperature, 0.0)
+        } else if h < boil_end {
+            if from_below(PhaseState::Liquid) {
+                (PhaseState::Liquid, self.boiling_point, h - boil_start)
+            } else {
+                (PhaseState::Gas, self.boiling_point, h - boil_end)
+            }
+        } else {
+            let temperature = self.boiling_point + (h - boil_end) / self.specific_heat_gas;
+            (PhaseState::Gas, temperature, 0.0)
+        }
+    }
+
+    /// Fold a temperature change from heat transport into phase and latent storage.
+    ///
+    /// Updates `phase`, `temperature` and `reservoir` in place and returns the
+    /// transition that completed, if any. Plasma is left untouched.
+    pub fn update(
+        &self,
+        phase: &mut PhaseState,
+        temperature: &mut f32,
+        reservoir: &mut LatentHeatReservoir,
+    ) -> Option<(PhaseState, PhaseState)> {
+        if *phase == PhaseState::Plasma {
+            return None;
+        }
+        let h = self.specific_enthalpy(*phase, *temperature, reservoir.stored);
+        let (next, t, latent) = self.resolve(*phase, h);
+        let previous = *phase;
+        *phase = next;
+        *temperature = t;
+        reservoir.stored = latent;
+        (next != previous).then_some((previous, next))
+    }
+
+    /// Latent heat per kg released (+) or absorbed (-) to go from `from` to `to`.
+    pub fn latent_heat_released(&self, from: PhaseState, to: PhaseState) -> f32 {
+        let level = |phase: PhaseState| match phase {
+            PhaseState::Solid => 0.0,
+            PhaseState::Liquid => self.latent_heat_fusion,
+            PhaseState::Gas | PhaseState::Plasma => {
+                self.latent_heat_fusion + self.latent_heat_vaporization
+            }
+        };
+        level(from) - level(to)
+    }
+}
+
+/// Emitted when a body or material point completes a phase transition.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct PhaseTransitionEvent {
+    pub entity: Entity,
+    pub from: PhaseState,
+    pub to: PhaseState,
+    /// Temperature at which the transition completed (K)
+    pub temperature: f32,
+    /// Latent heat released (+, freezing/condensing) or absorbed (-) (J)
+    pub latent_heat: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Heat 1 kg of water state step by step, as a conduction solver would.
+    fn heat(
+        water: &PhaseTransitions,
+        phase: &mut PhaseState,
+        temperature: &mut f32,
+        reservoir: &mut LatentHeatReservoir,
+        joules: f32,
+    ) -> Option<(PhaseState, PhaseState)> {
+        *temperature += joules / water.specific_heat(*phase);
+        water.update(phase, temperature, reservoir)
+    }
+
+    #[test]
+    fn test_ice_melts_at_plateau_and_conserves_enthalpy() {
+        let water = PhaseTransitions::water();
+        let (mut phase, mut temperature) = (PhaseState::Solid, 263.15);
+        let mut reservoir = LatentHeatReservoir::default();
+        let start = water.specific_enthalpy(phase, temperature, 0.0);
+
+        // 10 K of ice warming, then a third of the fusion heat: pinned at 0 °C
+        heat(
+            &water,
+            &mut phase,
+            &mut temperature,
+            &mut reservoir,
+            20_900.0 + 1.0e5,
+        );
+        assert_eq!(phase, PhaseState::Solid);
+        assert_eq!(temperature, 273.15);
+        assert!((reservoir.stored - 1.0e5).abs() < 1.0);
+
+        // The rest of L_f plus 10 K of liquid warming
+        let transition = heat(
+            &water,
+            &mut phase,
+            &mut temperature,
+            &mut reservoir,
+            2.34e5 + 41_860.0,
+        );
+        assert_eq!(transition, Some((PhaseState::Solid, PhaseState::Liquid)));
+        assert!((temperature - 283.15).abs() < 1e-2);
+        assert_eq!(reservoir.stored, 0.0);
+
+        let end = water.specific_enthalpy(phase, temperature, reservoir.stored);
+        let added = 20_900.0 + 1.0e5 + 2.34e5 + 41_860.0;
+        assert!((end - start - added).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_water_boils_and_condenses_back() {
+        let water = PhaseTransitions::water();
+        let (mut phase, mut temperature) = (PhaseState::Liquid, 373.15);
+        let mut reservoir = LatentHeatReservoir::default();
+
+        let boiled = heat(&water, &mut phase, &mut temperature, &mut reservoir, 2.3e6);
+        assert_eq!(boiled, Some((PhaseState::Liquid, PhaseState::Gas)));
+        assert!(temperature > 373.15);
+
+        // Removing part of the heat again condenses only partially: still gas, pinned
+        heat(&water, &mut phase, &mut temperature, &mut reservoir, -1.0e6);
+        assert_eq!(phase, PhaseState::Gas);
+        assert_eq!(temperature, 373.15);
+        assert!(reservoir.stored < 0.0);
+
+        let condensed = heat(
+            &water,
+            &mut phase,
+            &mut temperature,
+            &mut reservoir,
+            -1.35e6,
+        );
+        assert_eq!(condensed, Some((PhaseState::Gas, PhaseState::Liquid)));
+        assert!(temperature < 373.15 && temperature > 350.0);
+        assert_eq!(
+            water.latent_heat_released(PhaseState::Gas, PhaseState::Liquid),
+            2.257e6
+        );
+    }
+
+    #[test]
+    fn test_phase_at_matches_resolved_state() {
+        let water = PhaseTransitions::water();
+        for (temperature, phase) in [
+            (250.0, PhaseState::Solid),
+            (300.0, PhaseState::Liquid),
+            (400.0, PhaseState::Gas),
+        ] {
+            assert_eq!(water.phase_at(temperature), phase);
+            let h = water.specific_enthalpy(phase, temperature, 0.0);
+            let (resolved, t, latent) = water.resolve(phase, h);
+            assert_eq!(resolved, phase);
+            assert!((t - temperature).abs() < 1e-2);
+            assert_eq!(latent, 0.0);
+        }
+    }
+}
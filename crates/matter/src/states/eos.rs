@@ -0,0 +1,270 @@
+//! Equations of state: pressure from density and temperature.
+//!
+//! **Property-based**: An [`EquationOfState`] is the thermodynamic closure a continuum
+//! solver needs. MPM fluid particles use [`Tait`] (through
+//! `mpm::constitutive::WeaklyCompressibleFluid`); Eulerian gas grids use
+//! [`IdealGas`] or [`VanDerWaals`].
+//!
+//! **UNITS**: density in kg/m³, temperature in K, pressure in Pa (gauge for Tait,
+//! absolute for the gas laws), molar quantities per mol.
+
+use bevy::prelude::*;
+
+/// Molar gas constant R (J/(mol·K)).
+pub const GAS_CONSTANT: f32 = 8.314_463;
+
+/// Pressure as a function of density and temperature.
+pub trait EquationOfState {
+    /// Pressure p (Pa) at density ρ (kg/m³) and temperature T (K).
+    fn pressure(&self, density: f32, temperature: f32) -> f32;
+
+    /// Bulk modulus K = ρ·(∂p/∂ρ) (Pa).
+    ///
+    /// **NUMERICAL**: Default is a central difference at fixed T (isothermal), with a
+    /// relative step of 1e-3; implementations override it when a closed form exists.
+    fn bulk_modulus(&self, density: f32, temperature: f32) -> f32 {
+        let step = (density * 1e-3).max(1e-6);
+        let slope = (self.pressure(density + step, temperature)
+            - self.pressure(density - step, temperature))
+            / (2.0 * step);
+        density * slope
+    }
+
+    /// Speed of sound c = √(K/ρ) (m/s).
+    fn sound_speed(&self, density: f32, temperature: f32) -> f32 {
+        (self.bulk_modulus(density, temperature).max(0.0) / density.max(f32::EPSILON)).sqrt()
+    }
+}
+
+/// Ideal gas: p = ρ·R·T/M.
+///
+/// **PHYSICS**: Sound is adiabatic, so [`EquationOfState::bulk_modulus`] is γ·p,
+/// not the isothermal p.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct IdealGas {
+    /// Molar mass M (kg/mol)
+    pub molar_mass: f32,
+    /// Heat capacity ratio γ = c_p/c_v (dimensionless)
+    pub heat_capacity_ratio: f32,
+}
+
+impl IdealGas {
+    pub fn new(molar_mass: f32, heat_capacity_ratio: f32) -> Self {
+        debug_assert!(molar_mass > 0.0, "Molar mass must be positive");
+        debug_assert!(
+            heat_capacity_ratio > 1.0,
+            "Heat capacity ratio must exceed 1"
+        );
+        Self {
+            molar_mass,
+            heat_capacity_ratio,
+        }
+    }
+
+    /// Dry air (M = 28.96 g/mol, γ = 1.4).
+    pub fn air() -> Self {
+        Self::new(0.028_965, 1.4)
+    }
+
+    /// Helium, monatomic (M = 4.003 g/mol, γ = 5/3).
+    pub fn helium() -> Self {
+        Self::new(0.004_003, 5.0 / 3.0)
+    }
+
+    /// Specific gas constant R/M (J/(kg·K)).
+    pub fn specific_gas_constant(&self) -> f32 {
+        GAS_CONSTANT / self.molar_mass
+    }
+
+    /// Density ρ = p·M/(R·T) (kg/m³) at absolute pressure `pressure` (Pa).
+    pub fn density(&self, pressure: f32, temperature: f32) -> f32 {
+        pressure / (self.specific_gas_constant() * temperature.max(f32::EPSILON))
+    }
+
+    /// Specific heat at constant volume c_v = R/(M·(γ - 1)) (J/(kg·K)).
+    pub fn specific_heat_volume(&self) -> f32 {
+        self.specific_gas_constant() / (self.heat_capacity_ratio - 1.0)
+    }
+}
+
+impl EquationOfState for IdealGas {
+    fn pressure(&self, density: f32, temperature: f32) -> f32 {
+        density * self.specific_gas_constant() * temperature
+    }
+
+    fn bulk_modulus(&self, density: f32, temperature: f32) -> f32 {
+        self.heat_capacity_ratio * self.pressure(density, temperature)
+    }
+}
+
+/// Tait equation for weakly compressible liquids: p = (K/γ)·((ρ/ρ₀)^γ - 1).
+///
+/// **PHYSICS**: Gauge pressure, zero at the rest density ρ₀, independent of T.
+/// K is the bulk modulus at rest.
+///
+/// **LP-0**: Real water has K ≈ 2.2 GPa. Game scenes lower K so that c = √(K/ρ₀)
+/// stays within the solver's stable range (see `mpm::MpmMaterial::water`).
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct Tait {
+    /// Rest density ρ₀ (kg/m³)
+    pub rest_density: f32,
+    /// Bulk modulus at rest K (Pa)
+    pub bulk_modulus: f32,
+    /// Exponent γ (dimensionless, ≈ 7 for water)
+    pub exponent: f32,
+}
+
+impl Tait {
+    pub fn new(rest_density: f32, bulk_modulus: f32, exponent: f32) -> Self {
+        debug_assert!(rest_density > 0.0, "Rest density must be positive");
+        debug_assert!(bulk_modulus >= 0.0, "Bulk modulus must be non-negative");
+        Self {
+            rest_density,
+            bulk_modulus,
+            exponent,
+        }
+    }
+
+    /// Water at 20 °C: ρ₀ = 998 kg/m³, K = 2.2 GPa, γ = 7.15 (Cole 1948).
+    pub fn water() -> Self {
+        Self::new(998.0, 2.2e9, 7.15)
+    }
+
+    /// Pressure (Pa) at volume ratio J = ρ₀/ρ, the form MPM particles track.
+    pub fn pressure_at_volume_ratio(&self, volume_ratio: f32) -> f32 {
+        let j = volume_ratio.max(1e-3);
+        self.bulk_modulus / self.exponent * (j.powf(-self.exponent) - 1.0)
+    }
+}
+
+impl EquationOfState for Tait {
+    fn pressure(&self, density: f32, _temperature: f32) -> f32 {
+        self.pressure_at_volume_ratio(self.rest_density / density.max(f32::EPSILON))
+    }
+
+    fn bulk_modulus(&self, density: f32, _temperature: f32) -> f32 {
+        // ρ·dp/dρ = K·(ρ/ρ₀)^γ
+        self.bulk_modulus * (density / self.rest_density).powf(self.exponent)
+    }
+}
+
+/// Van der Waals gas: p = R·T/(V_m - b) - a/V_m², V_m = M/ρ.
+///
+/// **PHYSICS**: a models intermolecular attraction, b the excluded molar volume.
+/// Critical point T_c = 8a/(27·R·b), p_c = a/(27·b²), V_c = 3b. Below T_c the
+/// isotherms have the unphysical loop where ∂p/∂ρ < 0 (no Maxwell construction).
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct VanDerWaals {
+    /// Attraction parameter a (Pa·m⁶/mol²)
+    pub attraction: f32,
+    /// Excluded volume b (m³/mol)
+    pub excluded_volume: f32,
+    /// Molar mass M (kg/mol)
+    pub molar_mass: f32,
+}
+
+impl VanDerWaals {
+    pub fn new(attraction: f32, excluded_volume: f32, molar_mass: f32) -> Self {
+        debug_assert!(excluded_volume > 0.0, "Excluded volume must be positive");
+        debug_assert!(molar_mass > 0.0, "Molar mass must be positive");
+        Self {
+            attraction,
+            excluded_volume,
+            molar_mass,
+        }
+    }
+
+    /// Carbon dioxide: a = 0.3640 Pa·m⁶/mol², b = 4.267e-5 m³/mol.
+    pub fn carbon_dioxide() -> Self {
+        Self::new(0.3640, 4.267e-5, 0.044_01)
+    }
+
+    /// Nitrogen: a = 0.1370 Pa·m⁶/mol², b = 3.87e-5 m³/mol.
+    pub fn nitrogen() -> Self {
+        Self::new(0.1370, 3.87e-5, 0.028_013)
+    }
+
+    /// Critical temperature T_c (K).
+    pub fn critical_temperature(&self) -> f32 {
+        8.0 * self.attraction / (27.0 * GAS_CONSTANT * self.excluded_volume)
+    }
+
+    /// Critical pressure p_c (Pa).
+    pub fn critical_pressure(&self) -> f32 {
+        self.attraction / (27.0 * self.excluded_volume * self.excluded_volume)
+    }
+
+    /// Critical density ρ_c = M/(3b) (kg/m³).
+    pub fn critical_density(&self) -> f32 {
+        self.molar_mass / (3.0 * self.excluded_volume)
+    }
+}
+
+impl EquationOfState for VanDerWaals {
+    fn pressure(&self, density: f32, temperature: f32) -> f32 {
+        // Clamp V_m above b: denser than close packing is not representable
+        let molar_volume =
+            (self.molar_mass / density.max(f32::EPSILON)).max(self.excluded_volume * 1.0001);
+        GAS_CONSTANT * temperature / (molar_volume - self.excluded_volume)
+            - self.attraction / (molar_volume * molar_volume)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_relative(actual: f32, expected: f32, tolerance: f32) {
+        assert!(
+            (actual - expected).abs() <= tolerance * expected.abs(),
+            "{} vs {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_ideal_gas_matches_standard_atmosphere() {
+        // ISA sea level: ρ = 1.225 kg/m³, T = 288.15 K → p = 101 325 Pa, c = 340.3 m/s
+        let air = IdealGas::air();
+        assert_relative(air.pressure(1.225, 288.15), 101_325.0, 1e-3);
+        assert_relative(air.density(101_325.0, 288.15), 1.225, 1e-3);
+        assert_relative(air.sound_speed(1.225, 288.15), 340.3, 1e-3);
+        assert_relative(air.specific_heat_volume(), 717.0, 2e-3);
+
+        // Helium at 0 °C: c ≈ 972 m/s
+        assert_relative(IdealGas::helium().sound_speed(0.1786, 273.15), 972.0, 5e-3);
+    }
+
+    #[test]
+    fn test_tait_water_compressibility() {
+        let water = Tait::water();
+        assert_eq!(water.pressure(998.0, 293.15), 0.0);
+        // 0.1 % compression ≈ K·0.001 = 2.2 MPa; c = √(K/ρ₀) ≈ 1485 m/s
+        assert_relative(water.pressure(998.0 * 1.001, 293.15), 2.2e6, 5e-3);
+        assert_relative(water.sound_speed(998.0, 293.15), 1484.7, 1e-3);
+        // Stiffens under compression, tension is bounded by -K/γ
+        assert!(water.bulk_modulus(1050.0, 0.0) > water.bulk_modulus(998.0, 0.0));
+        assert!(water.pressure(500.0, 0.0) > -2.2e9 / 7.15);
+    }
+
+    #[test]
+    fn test_van_der_waals_critical_point_and_dilute_limit() {
+        // CO₂: T_c = 304.2 K and p_c = 7.38 MPa measured; the vdW constants give 304 K
+        // and 7.40 MPa
+        let co2 = VanDerWaals::carbon_dioxide();
+        assert_relative(co2.critical_temperature(), 304.0, 2e-3);
+        assert_relative(co2.critical_pressure(), 7.40e6, 2e-3);
+        let (rho_c, t_c) = (co2.critical_density(), co2.critical_temperature());
+        assert_relative(co2.pressure(rho_c, t_c), co2.critical_pressure(), 1e-3);
+        // Inflection at the critical point: ∂p/∂ρ ≈ 0
+        assert!(co2.bulk_modulus(rho_c, t_c) < 1e-2 * co2.critical_pressure());
+
+        // Dilute gas approaches the ideal gas law
+        let ideal = IdealGas::new(co2.molar_mass, 1.3);
+        assert_relative(co2.pressure(0.01, 300.0), ideal.pressure(0.01, 300.0), 1e-3);
+        // 1 mol of N₂ in 1 L at 300 K: vdW 2.458 MPa vs ideal 2.494 MPa
+        let n2 = VanDerWaals::nitrogen();
+        assert_relative(n2.pressure(28.013, 300.0), 2.458e6, 1e-3);
+    }
+}
@@ -1,9 +1,13 @@
+//! Liquids: weakly compressible matter closed by the [`Tait`] equation.
+
 pub mod solver;
 
+pub use super::eos::Tait;
+
 /// Prelude for the fluids module.
 ///
 /// This includes components for modeling continuous substance flow and behavior.
 pub mod prelude {
-    // Currently minimal, will expand with implementation
+    pub use super::Tait;
     // pub use super::solver::FluidSolver;
 }
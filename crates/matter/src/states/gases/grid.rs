@@ -0,0 +1,687 @@
+//! Eulerian gas grid: wind, buoyant plumes, smoke and scent.
+//!
+//! **PHYSICS**: Stable fluids (Stam 1999) on a staggered MAC grid (Harlow-Welch 1965):
+//! 1. Buoyancy: a = g·(1 - T/T₀), exact for an ideal gas at uniform pressure
+//!    (hot air is lighter by ρ₀/ρ = T/T₀)
+//! 2. Semi-Lagrangian advection of velocity (RK2 backtrace, bilinear sampling)
+//! 3. Pressure projection: Gauss-Seidel/SOR solve of ∇²p = (ρ₀/Δt)·∇·u*, then
+//!    u = u* - (Δt/ρ₀)·∇p, leaving a divergence-free (incompressible) flow
+//! 4. Advection, implicit diffusion and decay of temperature and tracers
+//!
+//! Velocities live on cell faces (u on vertical faces, v on horizontal faces);
+//! pressure, temperature and tracers at cell centers. The domain is closed: no flow
+//! through its edges.
+//!
+//! **UNITS**: velocity m/s, gauge pressure Pa (add `ambient_pressure` for absolute),
+//! temperature K, tracer amounts per unit area (e.g. kg/m² of smoke). Cells are 2D
+//! with unit depth (1 m) where a volume is needed.
+//!
+//! **LP-0**: Incompressible (low Mach) flow; the ideal gas only sets the density ρ₀
+//! and the buoyancy. No viscosity beyond the numerical diffusion of the advection.
+//!
+//! **CONSERVATION**: Bilinear semi-Lagrangian advection is not conservative; tracer
+//! totals are rescaled after each advection so only emitters and decay change them
+//! (a global correction: mass may still shift slightly between regions).
+//! Temperature is not corrected.
+//!
+//! **DETERMINISM**: Every loop runs in row-major order on one thread; the same inputs
+//! give bit-identical fields.
+
+use bevy::prelude::*;
+
+use crate::states::eos::IdealGas;
+
+/// Handle of a tracer field registered on a [`GasGrid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub struct TracerId(pub usize);
+
+/// Passive scalar carried by the air (smoke, scent, CO₂).
+#[derive(Debug, Clone, Reflect)]
+pub struct GasTracer {
+    pub name: String,
+    /// Molecular diffusivity D (m²/s)
+    pub diffusivity: f32,
+    /// First-order decay rate k (1/s): c ← c·e^{-kΔt}
+    pub decay_rate: f32,
+    /// Amount per unit area at each cell center
+    values: Vec<f32>,
+}
+
+impl GasTracer {
+    /// Amount per unit area at each cell center, row-major.
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+}
+
+/// Solver settings for the gas grid (resource).
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct GasConfig {
+    /// Gravitational acceleration (m/s²); keep in sync with the body gravity
+    pub gravity: Vec2,
+    /// Gauss-Seidel/SOR sweeps of the pressure solve per step
+    pub pressure_iterations: u32,
+    /// Over-relaxation factor ω ∈ [1, 2) of the pressure solve
+    pub over_relaxation: f32,
+    /// Gauss-Seidel sweeps of each implicit diffusion solve
+    pub diffusion_iterations: u32,
+    /// Thermal diffusivity of the gas α (m²/s); 2.2e-5 for air
+    pub thermal_diffusivity: f32,
+}
+
+impl Default for GasConfig {
+    fn default() -> Self {
+        Self {
+            gravity: Vec2::new(0.0, -9.81),
+            pressure_iterations: 60,
+            over_relaxation: 1.7,
+            diffusion_iterations: 8,
+            thermal_diffusivity: 2.2e-5,
+        }
+    }
+}
+
+/// Gas state on a fixed rectangular grid (resource).
+///
+/// The gas systems run only while this resource exists; insert one sized to the
+/// playable area.
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct GasGrid {
+    /// World position of the lower-left domain corner (m)
+    origin: Vec2,
+    /// Cell edge length h (m)
+    cell_size: f32,
+    /// Cells along x and y
+    size: UVec2,
+    /// Equation of state of the gas
+    pub gas: IdealGas,
+    /// Temperature T₀ of the still, uniform atmosphere (K)
+    pub ambient_temperature: f32,
+    /// Absolute pressure of the still atmosphere p₀ (Pa)
+    pub ambient_pressure: f32,
+    /// x velocity on vertical faces, (nx + 1) × ny
+    u: Vec<f32>,
+    /// y velocity on horizontal faces, nx × (ny + 1)
+    v: Vec<f32>,
+    /// Gauge pressure at cell centers (Pa)
+    pressure: Vec<f32>,
+    /// Temperature at cell centers (K)
+    temperature: Vec<f32>,
+    tracers: Vec<GasTracer>,
+}
+
+impl GasGrid {
+    /// Still air at `ambient_temperature` and 1 atm over `size` cells of `cell_size`.
+    pub fn new(origin: Vec2, size: UVec2, cell_size: f32, ambient_temperature: f32) -> Self {
+        debug_assert!(
+            size.x > 1 && size.y > 1,
+            "Gas grid needs at least 2×2 cells"
+        );
+        debug_assert!(cell_size > 0.0, "Cell size must be positive");
+        let (nx, ny) = (size.x as usize, size.y as usize);
+        Self {
+            origin,
+            cell_size,
+            size,
+            gas: IdealGas::air(),
+            ambient_temperature,
+            ambient_pressure: 101_325.0,
+            u: vec![0.0; (nx + 1) * ny],
+            v: vec![0.0; nx * (ny + 1)],
+            pressure: vec![0.0; nx * ny],
+            temperature: vec![ambient_temperature; nx * ny],
+            tracers: Vec::new(),
+        }
+    }
+
+    /// Grid covering the rectangle `min`..`max` (m).
+    pub fn from_bounds(min: Vec2, max: Vec2, cell_size: f32, ambient_temperature: f32) -> Self {
+        let size = ((max - min) / cell_size)
+            .ceil()
+            .as_uvec2()
+            .max(UVec2::splat(2));
+        Self::new(min, size, cell_size, ambient_temperature)
+    }
+
+    /// Replace the gas (default: dry air).
+    pub fn with_gas(mut self, gas: IdealGas) -> Self {
+        self.gas = gas;
+        self
+    }
+
+    pub fn origin(&self) -> Vec2 {
+        self.origin
+    }
+
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    pub fn size(&self) -> UVec2 {
+        self.size
+    }
+
+    /// Density of the still atmosphere ρ₀ (kg/m³).
+    pub fn ambient_density(&self) -> f32 {
+        self.gas
+            .density(self.ambient_pressure, self.ambient_temperature)
+    }
+
+    /// Add a tracer field (or return the existing one with that name).
+    pub fn add_tracer(&mut self, name: &str, diffusivity: f32, decay_rate: f32) -> TracerId {
+        if let Some(id) = self.tracer_id(name) {
+            return id;
+        }
+        let cells = self.pressure.len();
+        self.tracers.push(GasTracer {
+            name: name.to_string(),
+            diffusivity,
+            decay_rate,
+            values: vec![0.0; cells],
+        });
+        TracerId(self.tracers.len() - 1)
+    }
+
+    pub fn tracer_id(&self, name: &str) -> Option<TracerId> {
+        self.tracers
+            .iter()
+            .position(|tracer| tracer.name == name)
+            .map(TracerId)
+    }
+
+    pub fn tracer(&self, id: TracerId) -> Option<&GasTracer> {
+        self.tracers.get(id.0)
+    }
+
+    #[inline]
+    fn cell_index(&self, x: usize, y: usize) -> usize {
+        y * self.size.x as usize + x
+    }
+
+    /// Cell containing `position`, clamped into the domain.
+    fn cell_at(&self, position: Vec2) -> usize {
+        let cell = ((position - self.origin) / self.cell_size)
+            .floor()
+            .as_ivec2()
+            .clamp(IVec2::ZERO, self.size.as_ivec2() - 1);
+        self.cell_index(cell.x as usize, cell.y as usize)
+    }
+
+    /// Continuous grid coordinates of `position` (cell units from the origin).
+    #[inline]
+    fn grid_space(&self, position: Vec2) -> Vec2 {
+        (position - self.origin) / self.cell_size
+    }
+
+    /// Air velocity at `position` (m/s).
+    pub fn velocity_at(&self, position: Vec2) -> Vec2 {
+        sample_velocity(&self.u, &self.v, self.size, self.grid_space(position))
+    }
+
+    /// Temperature at `position` (K).
+    pub fn temperature_at(&self, position: Vec2) -> f32 {
+        let g = self.grid_space(position) - Vec2::splat(0.5);
+        bilinear(&self.temperature, self.size, g)
+    }
+
+    /// Gauge pressure at `position` (Pa).
+    pub fn pressure_at(&self, position: Vec2) -> f32 {
+        let g = self.grid_space(position) - Vec2::splat(0.5);
+        bilinear(&self.pressure, self.size, g)
+    }
+
+    /// Tracer amount per unit area at `position`; 0 for an unknown tracer.
+    pub fn tracer_at(&self, id: TracerId, position: Vec2) -> f32 {
+        let g = self.grid_space(position) - Vec2::splat(0.5);
+        self.tracer(id)
+            .map_or(0.0, |tracer| bilinear(&tracer.values, self.size, g))
+    }
+
+    /// Total amount of a tracer in the domain (amount per area × area).
+    pub fn tracer_total(&self, id: TracerId) -> f32 {
+        let area = self.cell_size * self.cell_size;
+        self.tracer(id)
+            .map_or(0.0, |tracer| tracer.values.iter().sum::<f32>() * area)
+    }
+
+    /// Deposit `amount` of a tracer in the cell containing `position`.
+    pub fn add_tracer_amount(&mut self, id: TracerId, position: Vec2, amount: f32) {
+        let (cell, area) = (self.cell_at(position), self.cell_size * self.cell_size);
+        if let Some(tracer) = self.tracers.get_mut(id.0) {
+            tracer.values[cell] = (tracer.values[cell] + amount / area).max(0.0);
+        }
+    }
+
+    /// Deposit `energy` (J) of heat in the cell containing `position`.
+    ///
+    /// **PHYSICS**: ΔT = Q/(ρ₀·c_p·h²·1 m), c_p = γ·c_v of the gas.
+    pub fn add_heat(&mut self, position: Vec2, energy: f32) {
+        let specific_heat = self.gas.heat_capacity_ratio * self.gas.specific_heat_volume();
+        let capacity = self.ambient_density() * specific_heat * self.cell_size * self.cell_size;
+        let cell = self.cell_at(position);
+        self.temperature[cell] = (self.temperature[cell] + energy / capacity).max(0.0);
+    }
+
+    /// Add `delta` (m/s) to the air velocity around `position` (the four faces of its
+    /// cell).
+    pub fn add_velocity(&mut self, position: Vec2, delta: Vec2) {
+        let cell = ((position - self.origin) / self.cell_size)
+            .floor()
+            .as_ivec2()
+            .clamp(IVec2::ZERO, self.size.as_ivec2() - 1);
+        let (x, y, nx) = (cell.x as usize, cell.y as usize, self.size.x as usize);
+        self.u[y * (nx + 1) + x] += delta.x;
+        self.u[y * (nx + 1) + x + 1] += delta.x;
+        self.v[y * nx + x] += delta.y;
+        self.v[(y + 1) * nx + x] += delta.y;
+        self.enforce_walls();
+    }
+
+    /// Give the air in the cell containing `position` an `impulse` (N·s).
+    ///
+    /// **PHYSICS**: Δu = J/(ρ₀·h²·1 m), the cell's air mass per meter of depth.
+    pub fn add_momentum(&mut self, position: Vec2, impulse: Vec2) {
+        let mass = self.ambient_density() * self.cell_size * self.cell_size;
+        self.add_velocity(position, impulse / mass);
+    }
+
+    /// Largest |∇·u| over the cells (1/s).
+    pub fn max_divergence(&self) -> f32 {
+        let (nx, ny) = (self.size.x as usize, self.size.y as usize);
+        let mut max = 0.0f32;
+        for y in 0..ny {
+            for x in 0..nx {
+                max = max.max(self.divergence(x, y).abs());
+            }
+        }
+        max
+    }
+
+    #[inline]
+    fn divergence(&self, x: usize, y: usize) -> f32 {
+        let nx = self.size.x as usize;
+        let (u0, u1) = (self.u[y * (nx + 1) + x], self.u[y * (nx + 1) + x + 1]);
+        let (v0, v1) = (self.v[y * nx + x], self.v[(y + 1) * nx + x]);
+        (u1 - u0 + v1 - v0) / self.cell_size
+    }
+
+    /// No flow through the domain edges.
+    fn enforce_walls(&mut self) {
+        let (nx, ny) = (self.size.x as usize, self.size.y as usize);
+        for y in 0..ny {
+            self.u[y * (nx + 1)] = 0.0;
+            self.u[y * (nx + 1) + nx] = 0.0;
+        }
+        for x in 0..nx {
+            self.v[x] = 0.0;
+            self.v[ny * nx + x] = 0.0;
+        }
+    }
+
+    /// Advance the gas by `dt` seconds.
+    pub fn step(&mut self, config: &GasConfig, dt: f32) {
+        if dt <= 0.0 {
+            return;
+        }
+        self.apply_buoyancy(config.gravity, dt);
+        self.advect_velocity(dt);
+        self.project(config, dt);
+        self.advect_scalars(dt);
+        self.diffuse_scalars(config, dt);
+    }
+
+    /// a = g·(1 - T/T₀) on every interior face, T averaged from the two cells.
+    fn apply_buoyancy(&mut self, gravity: Vec2, dt: f32) {
+        let (nx, ny) = (self.size.x as usize, self.size.y as usize);
+        let inverse_ambient = 1.0 / self.ambient_temperature.max(f32::EPSILON);
+        for y in 0..ny {
+            for x in 1..nx {
+                let t = 0.5 * (self.temperature[y * nx + x - 1] + self.temperature[y * nx + x]);
+                self.u[y * (nx + 1) + x] += dt * gravity.x * (1.0 - t * inverse_ambient);
+            }
+        }
+        for y in 1..ny {
+            for x in 0..nx {
+                let t = 0.5 * (self.temperature[(y - 1) * nx + x] + self.temperature[y * nx + x]);
+                self.v[y * nx + x] += dt * gravity.y * (1.0 - t * inverse_ambient);
+            }
+        }
+        self.enforce_walls();
+    }
+
+    /// Departure point of a sample at grid position `g` (RK2 midpoint backtrace).
+    #[inline]
+    fn backtrace(&self, g: Vec2, dt: f32) -> Vec2 {
+        let scale = dt / self.cell_size;
+        let mid = g - 0.5 * scale * sample_velocity(&self.u, &self.v, self.size, g);
+        g - scale * sample_velocity(&self.u, &self.v, self.size, mid)
+    }
+
+    fn advect_velocity(&mut self, dt: f32) {
+        let (nx, ny) = (self.size.x as usize, self.size.y as usize);
+        let mut u = self.u.clone();
+        for y in 0..ny {
+            for x in 1..nx {
+                let from = self.backtrace(Vec2::new(x as f32, y as f32 + 0.5), dt);
+                u[y * (nx + 1) + x] = sample_velocity(&self.u, &self.v, self.size, from).x;
+            }
+        }
+        let mut v = self.v.clone();
+        for y in 1..ny {
+            for x in 0..nx {
+                let from = self.backtrace(Vec2::new(x as f32 + 0.5, y as f32), dt);
+                v[y * nx + x] = sample_velocity(&self.u, &self.v, self.size, from).y;
+            }
+        }
+        self.u = u;
+        self.v = v;
+        self.enforce_walls();
+    }
+
+    /// Make the velocity divergence-free and store the gauge pressure that did it.
+    ///
+    /// **NUMERICAL**: The previous step's pressure is the initial guess (warm start),
+    /// so a steady flow converges in few sweeps. Neumann (∂p/∂n = 0) at the walls.
+    fn project(&mut self, config: &GasConfig, dt: f32) {
+        let (nx, ny) = (self.size.x as usize, self.size.y as usize);
+        let h = self.cell_size;
+        let density = self.ambient_density();
+        let scale = density * h * h / dt;
+        let rhs: Vec<f32> = (0..nx * ny)
+            .map(|i| scale * self.divergence(i % nx, i / nx))
+            .collect();
+
+        let omega = config.over_relaxation.clamp(1.0, 1.95);
+        for _ in 0..config.pressure_iterations {
+            for y in 0..ny {
+                for x in 0..nx {
+                    let i = y * nx + x;
+                    let (mut sum, mut count) = (0.0, 0.0);
+                    if x > 0 {
+                        sum += self.pressure[i - 1];
+                        count += 1.0;
+                    }
+                    if x + 1 < nx {
+                        sum += self.pressure[i + 1];
+                        count += 1.0;
+                    }
+                    if y > 0 {
+                        sum += self.pressure[i - nx];
+                        count += 1.0;
+                    }
+                    if y + 1 < ny {
+                        sum += self.pressure[i + nx];
+                        count += 1.0;
+                    }
+                    let target = (sum - rhs[i]) / count;
+                    self.pressure[i] += omega * (target - self.pressure[i]);
+                }
+            }
+        }
+
+        let factor = dt / (density * h);
+        for y in 0..ny {
+            for x in 1..nx {
+                let gradient = self.pressure[y * nx + x] - self.pressure[y * nx + x - 1];
+                self.u[y * (nx + 1) + x] -= factor * gradient;
+            }
+        }
+        for y in 1..ny {
+            for x in 0..nx {
+                let gradient = self.pressure[y * nx + x] - self.pressure[(y - 1) * nx + x];
+                self.v[y * nx + x] -= factor * gradient;
+            }
+        }
+    }
+
+    fn advect_scalars(&mut self, dt: f32) {
+        let (nx, ny) = (self.size.x as usize, self.size.y as usize);
+        let departures: Vec<Vec2> = (0..nx * ny)
+            .map(|i| {
+                let center = Vec2::new((i % nx) as f32 + 0.5, (i / nx) as f32 + 0.5);
+                self.backtrace(center, dt) - Vec2::splat(0.5)
+            })
+            .collect();
+        let size = self.size;
+        let advect = |values: &[f32]| -> Vec<f32> {
+            departures
+                .iter()
+                .map(|from| bilinear(values, size, *from))
+                .collect()
+        };
+        self.temperature = advect(&self.temperature);
+        for tracer in &mut self.tracers {
+            let before: f32 = tracer.values.iter().sum();
+            tracer.values = advect(&tracer.values);
+            // Interpolation loses or gains a little mass; restore the total
+            let after: f32 = tracer.values.iter().sum();
+            if after > f32::EPSILON {
+                let scale = before / after;
+                tracer.values.iter_mut().for_each(|value| *value *= scale);
+            }
+        }
+    }
+
+    fn diffuse_scalars(&mut self, config: &GasConfig, dt: f32) {
+        let (size, h, sweeps) = (self.size, self.cell_size, config.diffusion_iterations);
+        diffuse(
+            &mut self.temperature,
+            size,
+            config.thermal_diffusivity * dt / (h * h),
+            sweeps,
+        );
+        for tracer in &mut self.tracers {
+            diffuse(
+                &mut tracer.values,
+                size,
+                tracer.diffusivity * dt / (h * h),
+                sweeps,
+            );
+            if tracer.decay_rate > 0.0 {
+                let keep = (-tracer.decay_rate * dt).exp();
+                tracer.values.iter_mut().for_each(|value| *value *= keep);
+            }
+        }
+    }
+}
+
+/// Bilinear interpolation of a row-major `size` field at grid coordinates `g`
+/// (sample i at g = i), clamped to the field.
+fn bilinear(values: &[f32], size: UVec2, g: Vec2) -> f32 {
+    let max = (size - 1).as_vec2();
+    let g = g.clamp(Vec2::ZERO, max);
+    let base = g.floor().min(max - 1.0).max(Vec2::ZERO);
+    let t = g - base;
+    let (x, y, w) = (base.x as usize, base.y as usize, size.x as usize);
+    let (x1, y1) = ((x + 1).min(w - 1), (y + 1).min(size.y as usize - 1));
+    let bottom = values[y * w + x] * (1.0 - t.x) + values[y * w + x1] * t.x;
+    let top = values[y1 * w + x] * (1.0 - t.x) + values[y1 * w + x1] * t.x;
+    bottom * (1.0 - t.y) + top * t.y
+}
+
+/// Velocity at grid coordinates `g` (cell units from the domain corner).
+fn sample_velocity(u: &[f32], v: &[f32], size: UVec2, g: Vec2) -> Vec2 {
+    Vec2::new(
+        bilinear(u, size + UVec2::X, g - Vec2::new(0.0, 0.5)),
+        bilinear(v, size + UVec2::Y, g - Vec2::new(0.5, 0.0)),
+    )
+}
+
+/// Implicit diffusion (I - a·∇²)c' = c with Gauss-Seidel, a = D·Δt/h². Zero flux
+/// through the walls, so the total is conserved.
+fn diffuse(values: &mut [f32], size: UVec2, a: f32, sweeps: u32) {
+    if a <= 0.0 {
+        return;
+    }
+    let (nx, ny) = (size.x as usize, size.y as usize);
+    let source = values.to_vec();
+    for _ in 0..sweeps {
+        for y in 0..ny {
+            for x in 0..nx {
+                let i = y * nx + x;
+                let (mut sum, mut count) = (0.0, 0.0);
+                for (inside, j) in [
+                    (x > 0, i.wrapping_sub(1)),
+                    (x + 1 < nx, i + 1),
+                    (y > 0, i.wrapping_sub(nx)),
+                    (y + 1 < ny, i + nx),
+                ] {
+                    if inside {
+                        sum += values[j];
+                        count += 1.0;
+                    }
+                }
+                values[i] = (source[i] + a * sum) / (1.0 + a * count);
+            }
+        }
+    }
+}
+
+/// Source of heat, tracer and wind at an entity's position (component).
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct GasEmitter {
+    /// Tracer released, by name, and its rate (amount/s)
+    pub tracer: Option<(String, f32)>,
+    /// Heat released into the air (W)
+    pub heat_power: f32,
+    /// Force (N per meter of depth, along world x/y) the emitter applies to the air in
+    /// its cell; the emitter itself receives no reaction.
+    pub thrust: Vec2,
+}
+
+/// Inject emitter output into the grid.
+pub fn apply_gas_emitters(
+    mut grid: ResMut<GasGrid>,
+    emitters: Query<(&GasEmitter, &GlobalTransform)>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+    for (emitter, transform) in emitters.iter() {
+        let position = transform.translation().truncate();
+        if let Some((name, rate)) = &emitter.tracer
+            && let Some(id) = grid.tracer_id(name)
+        {
+            grid.add_tracer_amount(id, position, rate * dt);
+        }
+        if emitter.heat_power != 0.0 {
+            grid.add_heat(position, emitter.heat_power * dt);
+        }
+        if emitter.thrust != Vec2::ZERO {
+            grid.add_momentum(position, emitter.thrust * dt);
+        }
+    }
+}
+
+/// Advance the gas grid by one fixed step.
+pub fn step_gas_grid(mut grid: ResMut<GasGrid>, config: Res<GasConfig>, time: Res<Time>) {
+    grid.step(&config, time.delta_secs());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_projection_removes_divergence() {
+        let mut grid = GasGrid::new(Vec2::ZERO, UVec2::new(32, 32), 0.1, 293.15);
+        // Radial jet out of the center: strongly divergent
+        for (offset, push) in [
+            (Vec2::new(0.1, 0.0), Vec2::X),
+            (Vec2::new(-0.1, 0.0), -Vec2::X),
+            (Vec2::new(0.0, 0.1), Vec2::Y),
+            (Vec2::new(0.0, -0.1), -Vec2::Y),
+        ] {
+            grid.add_velocity(Vec2::splat(1.6) + offset, push * 2.0);
+        }
+        let before = grid.max_divergence();
+        let config = GasConfig {
+            pressure_iterations: 400,
+            ..default()
+        };
+        grid.project(&config, 0.02);
+        grid.enforce_walls();
+        assert!(
+            grid.max_divergence() < 1e-2 * before,
+            "{}",
+            grid.max_divergence()
+        );
+        // Incompressible: the pressure that stops the outflow is lowest at the source
+        assert!(grid.pressure_at(Vec2::splat(1.6)) < grid.pressure_at(Vec2::splat(0.2)));
+    }
+
+    #[test]
+    fn test_momentum_moves_one_cell_of_air() {
+        let mut grid = GasGrid::new(Vec2::ZERO, UVec2::new(16, 16), 0.1, 293.15);
+        let center = Vec2::splat(0.85);
+        let mass = grid.ambient_density() * 0.1 * 0.1;
+        grid.add_momentum(center, Vec2::new(0.02, -0.01));
+        let velocity = grid.velocity_at(center);
+        assert!((velocity * mass - Vec2::new(0.02, -0.01)).length() < 1e-6);
+    }
+
+    #[test]
+    fn test_hot_smoky_blob_rises() {
+        let mut grid = GasGrid::new(Vec2::ZERO, UVec2::new(24, 48), 0.1, 293.15);
+        let smoke = grid.add_tracer("smoke", 0.0, 0.0);
+        let config = GasConfig::default();
+        for x in 10..14 {
+            for y in 4..8 {
+                let cell = Vec2::new(x as f32 + 0.5, y as f32 + 0.5) * 0.1;
+                grid.add_tracer_amount(smoke, cell, 1.0);
+                grid.add_heat(cell, 1500.0);
+            }
+        }
+        let total = grid.tracer_total(smoke);
+        let height = |grid: &GasGrid| {
+            let tracer = grid.tracer(smoke).unwrap();
+            let nx = grid.size().x as usize;
+            let weighted: f32 = tracer
+                .values
+                .iter()
+                .enumerate()
+                .map(|(i, c)| c * ((i / nx) as f32 + 0.5) * 0.1)
+                .sum();
+            weighted / tracer.values.iter().sum::<f32>()
+        };
+        let start = height(&grid);
+
+        for _ in 0..60 {
+            grid.step(&config, 1.0 / 60.0);
+        }
+
+        assert!(
+            height(&grid) > start + 0.2,
+            "{} -> {}",
+            start,
+            height(&grid)
+        );
+        assert!(grid.velocity_at(Vec2::new(1.2, 1.0)).y > 0.0);
+        // Closed box, no decay: smoke is conserved
+        assert!((grid.tracer_total(smoke) - total).abs() < 1e-3 * total);
+        assert!(grid.max_divergence() < 1.0);
+    }
+
+    #[test]
+    fn test_scent_diffuses_and_decays() {
+        let mut grid = GasGrid::new(Vec2::ZERO, UVec2::new(16, 16), 0.5, 293.15);
+        let scent = grid.add_tracer("scent", 0.05, 0.1);
+        assert_eq!(grid.add_tracer("scent", 1.0, 1.0), scent);
+        grid.add_tracer_amount(scent, Vec2::splat(4.1), 2.0);
+        assert!((grid.tracer_total(scent) - 2.0).abs() < 1e-5);
+
+        let config = GasConfig {
+            gravity: Vec2::ZERO,
+            ..default()
+        };
+        for _ in 0..100 {
+            grid.step(&config, 0.1);
+        }
+        // Spread to the neighbours, total decayed by e^{-0.1·10}
+        assert!(grid.tracer_at(scent, Vec2::new(5.1, 4.1)) > 0.0);
+        let expected = 2.0 * (-1.0f32).exp();
+        assert!((grid.tracer_total(scent) - expected).abs() < 0.02 * expected);
+    }
+}
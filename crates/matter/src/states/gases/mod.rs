@@ -1,11 +1,49 @@
-// Currently empty, will be expanded in the future
+//! Gases: compressible matter closed by [`IdealGas`] or [`VanDerWaals`], and the
+//! Eulerian air grid ([`GasGrid`]) that carries wind, heat, smoke and scent.
+
+pub mod grid;
+
+use bevy::prelude::*;
+
+pub use super::eos::{IdealGas, VanDerWaals};
+pub use grid::{GasConfig, GasEmitter, GasGrid, GasTracer, TracerId};
+
+/// Ordering of the gas grid in `FixedUpdate`.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GasSet {
+    /// Emitters deposit heat, tracers and wind
+    Emit,
+    /// Buoyancy, advection, projection, diffusion
+    Step,
+}
+
+/// Runs the gas grid while a [`GasGrid`] resource exists.
+#[derive(Default)]
+pub struct GasesPlugin;
+
+impl Plugin for GasesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GasConfig>()
+            .register_type::<GasConfig>()
+            .register_type::<GasGrid>()
+            .register_type::<GasEmitter>()
+            .configure_sets(FixedUpdate, (GasSet::Emit, GasSet::Step).chain())
+            .add_systems(
+                FixedUpdate,
+                (
+                    grid::apply_gas_emitters.in_set(GasSet::Emit),
+                    grid::step_gas_grid.in_set(GasSet::Step),
+                )
+                    .run_if(resource_exists::<GasGrid>),
+            );
+    }
+}
 
 /// Prelude for the gases module.
 ///
 /// This includes components for modeling compressible substances with rapid molecular movement.
 pub mod prelude {
-    // To be populated as gas-related components are implemented
-    // Example future exports:
-    // pub use super::pressure::PressureField;
-    // pub use super::diffusion::DiffusionRate;
+    pub use super::{
+        GasConfig, GasEmitter, GasGrid, GasSet, GasesPlugin, IdealGas, TracerId, VanDerWaals,
+    };
 }
@@ -1,3 +1,4 @@
+pub mod eos;
 pub mod fluids;
 pub mod gases;
 pub mod plasma;
@@ -7,9 +8,11 @@ pub mod solids;
 ///
 /// This includes components for all fundamental states of matter.
 pub mod prelude {
+    pub use super::eos::{EquationOfState, GAS_CONSTANT};
+
     // Re-export from state modules
     //pub use super::solids::prelude::*;
-    //pub use super::fluids::prelude::*;
-    //pub use super::gases::prelude::*;
-    //pub use super::plasma::prelude::*;
+    pub use super::fluids::prelude::*;
+    pub use super::gases::prelude::*;
+    pub use super::plasma::prelude::*;
 }
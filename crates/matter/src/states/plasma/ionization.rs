@@ -0,0 +1,305 @@
+//! Thermal ionization: how much of a hot gas is plasma.
+//!
+//! **PHYSICS**: Single ionization in local thermodynamic equilibrium follows the Saha
+//! equation
+//!
+//! x²/(1 - x) = (2g₁/g₀)·(2π·mₑ·k·T/h²)^{3/2}·e^{-χ/kT} / n
+//!
+//! where x is the ionized fraction, n the total number density of atoms and χ the
+//! first ionization energy. Freeing an electron costs χ, which comes out of the gas's
+//! thermal energy and is returned on recombination.
+//!
+//! **LP-0 APPROXIMATION**: One ionization stage per species, no radiative
+//! non-equilibrium. Lightning channels and flames are close enough to LTE for
+//! gameplay; coronae and nebulae are not.
+//!
+//! **UNITS**: temperature in K, energies in eV (per atom) unless noted, number
+//! density in m⁻³, conductivity in S/m.
+
+use bevy::prelude::*;
+
+/// Boltzmann constant k (eV/K).
+pub const BOLTZMANN_EV: f32 = 8.617_333e-5;
+
+/// Elementary charge e (C); also J per eV.
+pub const ELEMENTARY_CHARGE: f32 = 1.602_176_6e-19;
+
+/// Electron mass mₑ (kg).
+pub const ELECTRON_MASS: f32 = 9.109_384e-31;
+
+/// Avogadro constant N_A (1/mol).
+pub const AVOGADRO: f32 = 6.022_140_8e23;
+
+/// (2π·mₑ·k/h²)^{3/2} (m⁻³·K^{-3/2}), the thermal electron density prefactor.
+pub const SAHA_CONSTANT: f32 = 2.414_7e21;
+
+/// Fraction above which a gas is labelled `PhaseState::Plasma`.
+pub const PLASMA_THRESHOLD: f32 = 0.5;
+
+/// Equilibrium ionized fraction x ∈ [0, 1] from the Saha equation.
+///
+/// `weight_ratio` is 2g₁/g₀ (ion over neutral ground-state statistical weights, times
+/// the two electron spin states).
+///
+/// **NUMERICAL**: Solves x²/(1 - x) = S as x = 2/(1 + √(1 + 4/S)), which stays finite
+/// when S underflows to 0 (cold gas) or overflows (hot, dilute gas).
+pub fn saha_ionization_fraction(
+    temperature: f32,
+    number_density: f32,
+    ionization_energy: f32,
+    weight_ratio: f32,
+) -> f32 {
+    if temperature <= 0.0 || number_density <= 0.0 {
+        return 0.0;
+    }
+    let kt = BOLTZMANN_EV * temperature;
+    let saha = weight_ratio * SAHA_CONSTANT * temperature.powf(1.5) / number_density
+        * (-ionization_energy / kt).exp();
+    if saha <= 0.0 {
+        return 0.0;
+    }
+    (2.0 / (1.0 + (1.0 + 4.0 / saha).sqrt())).clamp(0.0, 1.0)
+}
+
+/// Ionization parameters of a gas species.
+///
+/// **Property-based**: Bodies carrying this component are ionized by the thermal
+/// system in `energy`, which updates their [`IonizationLevel`], `Charge`,
+/// electrical conductivity and temperature.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+#[require(IonizationLevel)]
+pub struct Ionizable {
+    /// First ionization energy χ (eV per atom)
+    pub ionization_energy: f32,
+    /// Statistical weight ratio 2g₁/g₀ (dimensionless)
+    pub weight_ratio: f32,
+    /// Molar mass M (kg/mol)
+    pub molar_mass: f32,
+    /// Number density of atoms n (m⁻³), neutral plus ionized
+    pub number_density: f32,
+    /// Electron-neutral momentum transfer cross-section (m²)
+    pub collision_cross_section: f32,
+    /// Coulomb logarithm ln Λ for electron-ion collisions (dimensionless)
+    pub coulomb_logarithm: f32,
+    /// Fraction of freed electrons that leave the body (dimensionless)
+    ///
+    /// **LP-0**: 0 keeps the plasma quasi-neutral. A lightning channel sets a small
+    /// value so stripped electrons leave a net positive `Charge` behind.
+    pub charge_separation: f32,
+}
+
+impl Ionizable {
+    pub fn new(
+        ionization_energy: f32,
+        weight_ratio: f32,
+        molar_mass: f32,
+        number_density: f32,
+    ) -> Self {
+        debug_assert!(
+            ionization_energy > 0.0,
+            "Ionization energy must be positive"
+        );
+        debug_assert!(molar_mass > 0.0, "Molar mass must be positive");
+        Self {
+            ionization_energy,
+            weight_ratio,
+            molar_mass,
+            number_density,
+            collision_cross_section: 1e-19,
+            coulomb_logarithm: 10.0,
+            charge_separation: 0.0,
+        }
+    }
+
+    /// Atomic hydrogen: χ = 13.598 eV, g₀ = 2, g₁ = 1.
+    pub fn hydrogen(number_density: f32) -> Self {
+        Self::new(13.598, 1.0, 0.001_008, number_density)
+    }
+
+    /// Atomic nitrogen, standing in for air: χ = 14.534 eV, g₀ = 4, g₁ = 9.
+    pub fn nitrogen(number_density: f32) -> Self {
+        Self::new(14.534, 4.5, 0.014_007, number_density)
+    }
+
+    /// Argon: χ = 15.760 eV, g₀ = 1, g₁ = 6.
+    pub fn argon(number_density: f32) -> Self {
+        Self::new(15.760, 12.0, 0.039_948, number_density)
+    }
+
+    /// Set the number density from a mass density ρ (kg/m³): n = ρ·N_A/M.
+    pub fn with_mass_density(mut self, density: f32) -> Self {
+        self.number_density = density * self.atoms_per_kilogram();
+        self
+    }
+
+    pub fn with_charge_separation(mut self, charge_separation: f32) -> Self {
+        self.charge_separation = charge_separation.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Atoms per kilogram N_A/M (1/kg).
+    pub fn atoms_per_kilogram(&self) -> f32 {
+        AVOGADRO / self.molar_mass
+    }
+
+    /// Equilibrium ionized fraction at `temperature` (K).
+    pub fn equilibrium_fraction(&self, temperature: f32) -> f32 {
+        saha_ionization_fraction(
+            temperature,
+            self.number_density,
+            self.ionization_energy,
+            self.weight_ratio,
+        )
+    }
+
+    /// Electrical conductivity σ (S/m) at ionized fraction x and temperature T.
+    ///
+    /// **PHYSICS**: Resistivities add: η = η_en + η_ei. Electron-neutral drag gives
+    /// η_en = mₑ·(1 - x)·σ_en·v̄/(x·e²) with v̄ = √(8kT/(π·mₑ)); fully ionized gas
+    /// follows Spitzer, η_ei ≈ 5.2e-5·ln Λ/T_eV^{3/2} Ω·m. The number density cancels
+    /// in both terms.
+    pub fn conductivity(&self, fraction: f32, temperature: f32) -> f32 {
+        if fraction <= 0.0 || temperature <= 0.0 {
+            return 0.0;
+        }
+        let kt_joules = BOLTZMANN_EV * temperature * ELEMENTARY_CHARGE;
+        let mean_speed = (8.0 * kt_joules / (std::f32::consts::PI * ELECTRON_MASS)).sqrt();
+        // Grouped as (mₑ/e)·(σ_en/e): the plain product mₑ·σ_en underflows f32
+        let neutral_resistivity = (ELECTRON_MASS / ELEMENTARY_CHARGE)
+            * (self.collision_cross_section / ELEMENTARY_CHARGE)
+            * mean_speed
+            * (1.0 - fraction)
+            / fraction;
+        let spitzer_resistivity =
+            5.2e-5 * self.coulomb_logarithm / (BOLTZMANN_EV * temperature).powf(1.5);
+        1.0 / (neutral_resistivity + spitzer_resistivity)
+    }
+
+    /// Relax to ionization equilibrium while conserving energy.
+    ///
+    /// Returns the new fraction and temperature for a body holding `atoms` atoms with
+    /// heat capacity `heat_capacity` (J/K), starting at `fraction` and `temperature`.
+    ///
+    /// **CONSERVATION**: C·T + N·χ·x is unchanged: ionizing cools the gas by exactly
+    /// the energy stored in freed electrons, recombining heats it back.
+    ///
+    /// **NUMERICAL**: g(x) = x - x_saha(T(x)) is increasing in x, so bisection on
+    /// [0, 1] always converges to the single self-consistent root.
+    pub fn equilibrate(
+        &self,
+        fraction: f32,
+        temperature: f32,
+        heat_capacity: f32,
+        atoms: f32,
+    ) -> (f32, f32) {
+        let full_energy = atoms * self.ionization_energy * ELEMENTARY_CHARGE;
+        if heat_capacity <= 0.0 || full_energy <= 0.0 {
+            return (self.equilibrium_fraction(temperature), temperature);
+        }
+        let total = heat_capacity * temperature + full_energy * fraction;
+        let temperature_at = |x: f32| ((total - full_energy * x) / heat_capacity).max(0.0);
+
+        let (mut low, mut high) = (0.0_f32, 1.0_f32);
+        for _ in 0..40 {
+            let mid = 0.5 * (low + high);
+            if mid < self.equilibrium_fraction(temperature_at(mid)) {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        let x = 0.5 * (low + high);
+        (x, temperature_at(x))
+    }
+}
+
+/// Ionized fraction of a body and the state derived from it.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct IonizationLevel {
+    /// Ionized fraction x ∈ [0, 1] (dimensionless)
+    pub fraction: f32,
+    /// Plasma contribution to the body's electrical conductivity (S/m)
+    pub conductivity: f32,
+}
+
+impl IonizationLevel {
+    pub fn is_plasma(&self) -> bool {
+        self.fraction >= PLASMA_THRESHOLD
+    }
+
+    /// Energy held by freed electrons N·χ·x (J) for `atoms` atoms of `species`.
+    pub fn stored_energy(&self, species: &Ionizable, atoms: f32) -> f32 {
+        atoms * species.ionization_energy * ELEMENTARY_CHARGE * self.fraction
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_saha_hydrogen_half_ionized_near_9600_k() {
+        // Stellar atmosphere with electron pressure 20 Pa: hydrogen is half ionized at
+        // ≈ 9600 K (Carroll & Ostlie, §8.1). At x = 0.5, n = 2·Pₑ/(kT).
+        let temperature = 9600.0;
+        let number_density = 2.0 * 20.0 / (BOLTZMANN_EV * ELEMENTARY_CHARGE * temperature);
+        let hydrogen = Ionizable::hydrogen(number_density);
+        let x = hydrogen.equilibrium_fraction(temperature);
+        assert!((x - 0.5).abs() < 0.05, "x = {}", x);
+
+        // Cold gas is neutral, hot dilute gas fully ionized, and the curve is monotone
+        assert_eq!(hydrogen.equilibrium_fraction(300.0), 0.0);
+        assert!(hydrogen.equilibrium_fraction(30_000.0) > 0.999);
+        assert!(hydrogen.equilibrium_fraction(8000.0) < x);
+
+        // Denser gas needs more heat to ionize
+        let dense = Ionizable::hydrogen(number_density * 1e5);
+        assert!(dense.equilibrium_fraction(temperature) < 0.05);
+    }
+
+    #[test]
+    fn test_equilibrate_conserves_energy_and_conducts() {
+        // 1 g of sea-level nitrogen heated to 20 000 K by a lightning stroke
+        let nitrogen = Ionizable::nitrogen(0.0).with_mass_density(1.2);
+        let atoms = 1e-3 * nitrogen.atoms_per_kilogram();
+        let heat_capacity = 1e-3 * 1040.0;
+        let (x, temperature) = nitrogen.equilibrate(0.0, 20_000.0, heat_capacity, atoms);
+
+        assert!(x > 0.0 && x < 1.0);
+        assert!(temperature < 20_000.0);
+        let stored = IonizationLevel {
+            fraction: x,
+            conductivity: 0.0,
+        }
+        .stored_energy(&nitrogen, atoms);
+        let before = heat_capacity * 20_000.0;
+        let after = heat_capacity * temperature + stored;
+        assert!(
+            (after - before).abs() < 1e-3 * before,
+            "{} vs {}",
+            after,
+            before
+        );
+        assert!((x - nitrogen.equilibrium_fraction(temperature)).abs() < 1e-4);
+
+        // Quenched into a large cold mass, the channel recombines and returns the energy
+        let quench_capacity = heat_capacity * 1e3;
+        let (x_cold, t_cold) = nitrogen.equilibrate(x, 1000.0, quench_capacity, atoms);
+        assert!(x_cold < 1e-6);
+        let released = quench_capacity * (t_cold - 1000.0);
+        assert!(
+            (released - stored).abs() < 0.05 * stored,
+            "{} vs {}",
+            released,
+            stored
+        );
+
+        // Neutral gas insulates; ionized gas conducts like a metal-poor plasma (~10⁴ S/m)
+        assert_eq!(nitrogen.conductivity(0.0, 300.0), 0.0);
+        let sigma = nitrogen.conductivity(0.9, 20_000.0);
+        assert!(sigma > 1e3 && sigma < 1e5, "σ = {}", sigma);
+        assert!(nitrogen.conductivity(1e-3, 20_000.0) < 0.1 * sigma);
+    }
+}
@@ -1,11 +1,10 @@
-// Currently empty, will be expanded in the future
+pub mod ionization;
 
 /// Prelude for the plasma module.
 ///
 /// This includes components for modeling ionized gas-like substance with electrical properties.
 pub mod prelude {
-    // To be populated as plasma-related components are implemented
+    pub use super::ionization::{Ionizable, IonizationLevel, saha_ionization_fraction};
     // Example future exports:
-    // pub use super::ionization::IonizationLevel;
     // pub use super::magnetic_coupling::MagneticCoupling;
 }
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use matter::material::{self, Material, MaterialId, MaterialRegistry};
 
 /// Acoustics plugin for physics-based sound generation
 ///
@@ -13,14 +14,18 @@ pub struct AcousticsPlugin;
 impl Plugin for AcousticsPlugin {
     fn build(&self, app: &mut App) {
         // TODO: Will integrate with energy crate's wave systems
-        // TODO: Will require matter crate's medium properties for propagation
         // TODO: White noise generation + frequency filtering system
-        app.register_type::<AcousticMedium>();
+        app.init_resource::<MaterialRegistry>()
+            .register_type::<AcousticMedium>()
+            .add_systems(
+                PreUpdate,
+                apply_material_acoustic_medium.after(material::register_loaded_materials),
+            );
     }
 }
 
 /// Properties of matter that affect sound propagation
-/// Will integrate with matter crate when ready
+/// Derived from the entity's `MaterialId` when it has one
 #[derive(Component, Debug, Clone, Reflect)]
 pub struct AcousticMedium {
     /// Speed of sound in this medium (m/s)
@@ -33,6 +38,7 @@ pub struct AcousticMedium {
 
 impl Default for AcousticMedium {
     fn default() -> Self {
+        // Same values as the "air" entry of matter's built-in material library
         Self {
             sound_speed: 343.0, // Air at 20°C
             density: 1.225,     // Air density kg/m³
@@ -41,6 +47,41 @@ impl Default for AcousticMedium {
     }
 }
 
+impl AcousticMedium {
+    /// Medium of a registry material.
+    pub fn from_material(material: &Material) -> Self {
+        Self {
+            sound_speed: material.sound_speed,
+            density: material.density,
+            absorption_coefficient: material.acoustic_absorption,
+        }
+    }
+
+    /// Characteristic impedance Z = ρ·c (Pa·s/m)
+    pub fn impedance(&self) -> f32 {
+        self.density * self.sound_speed
+    }
+}
+
+/// Derive [`AcousticMedium`] from an entity's [`MaterialId`] when it is added or changed,
+/// or for every medium when the registry changes (late or hot-reloaded libraries)
+pub fn apply_material_acoustic_medium(
+    mut commands: Commands,
+    registry: Res<MaterialRegistry>,
+    media: Query<(Entity, Ref<MaterialId>)>,
+) {
+    for (entity, id) in media.iter() {
+        if !id.is_changed() && !registry.is_changed() {
+            continue;
+        }
+        if let Some(material) = registry.get(*id) {
+            commands
+                .entity(entity)
+                .insert(AcousticMedium::from_material(material));
+        }
+    }
+}
+
 /// Prelude for acoustics (minimal for now)
 pub mod prelude {
     pub use super::{AcousticMedium, AcousticsPlugin};
@@ -48,7 +89,6 @@ pub mod prelude {
 
 // TODO: Future implementation will include:
 // - Integration with energy::waves for wave propagation
-// - Matter medium interaction for realistic sound physics
 // - White noise -> frequency filtering for emergent audio
 // - Doppler effects, reflection, interference patterns
 // - No audio files - pure procedural generation from physics
@@ -0,0 +1,634 @@
+//! Constitutive models: how a material point turns deformation into stress.
+//!
+//! Every model implements [`ConstitutiveModel`]; [`MpmMaterial`] is the per-particle
+//! component that selects one. Both solver kinds use the same model:
+//! - Explicit MLS-MPM scatters [`ConstitutiveModel::kirchhoff_stress`]
+//! - Position-based MPM uses [`ConstitutiveModel::constraint_moduli`] as constraint stiffness
+//! - Both run [`ConstitutiveModel::project_plasticity`] after particles are advected
+//!
+//! **PHYSICS**:
+//! - Neo-Hookean / fixed-corotated hyperelasticity (Stomakhin et al. 2012)
+//! - Snow: clamped singular values + hardening (Stomakhin et al. 2013)
+//! - Sand: Drucker-Prager return mapping on Hencky strain (Klár et al. 2016)
+//! - Fluid: weakly compressible Tait equation of state + Newtonian viscosity
+
+use bevy::prelude::*;
+use matter::material::{Material, MaterialId, MaterialRegistry};
+use matter::states::eos::Tait;
+
+use crate::particle::MpmParticle;
+use crate::thermal::MpmThermal;
+
+/// Stress response of a material point.
+///
+/// **Units**: Stresses and moduli in Pascals (Pa).
+pub trait ConstitutiveModel {
+    /// Kirchhoff stress τ = J·σ = P·Fᵀ (Pa) for the particle's current state.
+    fn kirchhoff_stress(&self, particle: &MpmParticle) -> Mat2;
+
+    /// Elastic strain energy density ψ(F) per unit rest volume (J/m², 2D).
+    fn strain_energy_density(&self, particle: &MpmParticle) -> f32;
+
+    /// Shear and bulk stiffness (μ, λ) in Pa, used to relax position-based constraints.
+    fn constraint_moduli(&self, particle: &MpmParticle) -> Vec2;
+
+    /// Plastic return mapping applied after F is advected. Default: purely elastic.
+    fn project_plasticity(&self, _particle: &mut MpmParticle) {}
+}
+
+/// Isotropic elastic parameters shared by the solid models.
+///
+/// **Units**: Young's modulus in Pascals (Pa), Poisson's ratio dimensionless.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct MpmElasticity {
+    /// Young's modulus E (Pa)
+    pub youngs_modulus: f32,
+    /// Poisson's ratio ν (dimensionless, must stay below 0.5)
+    pub poisson_ratio: f32,
+}
+
+impl MpmElasticity {
+    pub fn new(youngs_modulus: f32, poisson_ratio: f32) -> Self {
+        debug_assert!(youngs_modulus >= 0.0, "Young's modulus cannot be negative");
+        debug_assert!(
+            (0.0..0.5).contains(&poisson_ratio),
+            "Poisson's ratio must lie in [0, 0.5) for a compressible solid"
+        );
+        Self {
+            youngs_modulus: youngs_modulus.max(0.0),
+            poisson_ratio: poisson_ratio.clamp(0.0, 0.49),
+        }
+    }
+
+    /// Lamé parameters (μ, λ) in Pascals.
+    ///
+    /// μ = E / (2(1+ν)), λ = E·ν / ((1+ν)(1-2ν))
+    pub fn lame_parameters(&self) -> (f32, f32) {
+        let e = self.youngs_modulus;
+        let nu = self.poisson_ratio;
+        let mu = e / (2.0 * (1.0 + nu));
+        let lambda = e * nu / ((1.0 + nu) * (1.0 - 2.0 * nu));
+        (mu, lambda)
+    }
+
+    fn moduli(&self) -> Vec2 {
+        let (mu, lambda) = self.lame_parameters();
+        Vec2::new(mu, lambda)
+    }
+}
+
+/// Rotation part R of the polar decomposition F = R·S (2D closed form).
+///
+/// R is the rotation closest to F: θ = atan2(F₁₀ - F₀₁, F₀₀ + F₁₁).
+#[inline]
+pub fn polar_rotation(f: Mat2) -> Mat2 {
+    let angle = (f.x_axis.y - f.y_axis.x).atan2(f.x_axis.x + f.y_axis.y);
+    Mat2::from_angle(angle)
+}
+
+/// Singular value decomposition F = U·diag(σ)·Vᵀ with U, V rotations.
+///
+/// Built from the polar decomposition: S = Rᵀ·F is symmetric, so its eigenvectors
+/// give V and U = R·V. σ may be negative when F is inverted.
+pub fn svd2(f: Mat2) -> (Mat2, Vec2, Mat2) {
+    let r = polar_rotation(f);
+    let s = r.transpose() * f;
+    let (s00, s01, s11) = (s.x_axis.x, s.y_axis.x, s.y_axis.y);
+    let v = Mat2::from_angle(0.5 * (2.0 * s01).atan2(s00 - s11));
+    let d = v.transpose() * s * v;
+    (r * v, Vec2::new(d.x_axis.x, d.y_axis.y), v)
+}
+
+/// Fixed-corotated Kirchhoff stress: τ = 2μ(F - R)·Fᵀ + λ(J - 1)·J·I.
+fn fixed_corotated_stress(f: Mat2, mu: f32, lambda: f32) -> Mat2 {
+    let j = f.determinant();
+    let r = polar_rotation(f);
+    (f - r) * f.transpose() * (2.0 * mu) + Mat2::IDENTITY * (lambda * (j - 1.0) * j)
+}
+
+/// Fixed-corotated energy density: ψ = μ‖F - R‖² + λ/2·(J - 1)².
+fn fixed_corotated_energy(f: Mat2, mu: f32, lambda: f32) -> f32 {
+    let diff = f - polar_rotation(f);
+    let j = f.determinant();
+    mu * (diff.x_axis.length_squared() + diff.y_axis.length_squared())
+        + 0.5 * lambda * (j - 1.0) * (j - 1.0)
+}
+
+/// Compressible Neo-Hookean solid: soft tissue, rubber, flesh.
+///
+/// τ = μ(F·Fᵀ - I) + λ·ln(J)·I
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct NeoHookean {
+    pub elasticity: MpmElasticity,
+}
+
+impl ConstitutiveModel for NeoHookean {
+    fn kirchhoff_stress(&self, particle: &MpmParticle) -> Mat2 {
+        let (mu, lambda) = self.elasticity.lame_parameters();
+        let f = particle.deformation_gradient;
+        let j = f.determinant().max(1e-6);
+        (f * f.transpose() - Mat2::IDENTITY) * mu + Mat2::IDENTITY * (lambda * j.ln())
+    }
+
+    /// ψ = μ/2·(tr(FᵀF) - 2) - μ·ln J + λ/2·(ln J)²
+    fn strain_energy_density(&self, particle: &MpmParticle) -> f32 {
+        let (mu, lambda) = self.elasticity.lame_parameters();
+        let f = particle.deformation_gradient;
+        let log_j = f.determinant().max(1e-6).ln();
+        let trace = f.x_axis.length_squared() + f.y_axis.length_squared();
+        0.5 * mu * (trace - 2.0) - mu * log_j + 0.5 * lambda * log_j * log_j
+    }
+
+    fn constraint_moduli(&self, _particle: &MpmParticle) -> Vec2 {
+        self.elasticity.moduli()
+    }
+}
+
+/// Fixed-corotated solid: robust under large rotation and inversion (wood, bark, bone).
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct FixedCorotated {
+    pub elasticity: MpmElasticity,
+}
+
+impl ConstitutiveModel for FixedCorotated {
+    fn kirchhoff_stress(&self, particle: &MpmParticle) -> Mat2 {
+        let (mu, lambda) = self.elasticity.lame_parameters();
+        fixed_corotated_stress(particle.deformation_gradient, mu, lambda)
+    }
+
+    fn strain_energy_density(&self, particle: &MpmParticle) -> f32 {
+        let (mu, lambda) = self.elasticity.lame_parameters();
+        fixed_corotated_energy(particle.deformation_gradient, mu, lambda)
+    }
+
+    fn constraint_moduli(&self, _particle: &MpmParticle) -> Vec2 {
+        self.elasticity.moduli()
+    }
+}
+
+/// Snow: fixed-corotated elasticity with clamped singular values and hardening.
+///
+/// **PHYSICS**: Stretch beyond [1 - θc, 1 + θs] becomes permanent (plastic).
+/// Compacted snow hardens: μ, λ scale by e^{ξ(1 - Jp)} where Jp is the plastic
+/// volume ratio stored on the particle.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct SnowPlasticity {
+    pub elasticity: MpmElasticity,
+    /// Critical compression θc (dimensionless, ~0.025)
+    pub critical_compression: f32,
+    /// Critical stretch θs (dimensionless, ~0.0075)
+    pub critical_stretch: f32,
+    /// Hardening coefficient ξ (dimensionless, ~10)
+    pub hardening: f32,
+}
+
+impl SnowPlasticity {
+    fn hardened_moduli(&self, particle: &MpmParticle) -> Vec2 {
+        // Clamp the exponent so extreme compaction cannot overflow the moduli
+        let exponent = (self.hardening * (1.0 - particle.plastic_volume_ratio)).clamp(-10.0, 10.0);
+        self.elasticity.moduli() * exponent.exp()
+    }
+}
+
+impl ConstitutiveModel for SnowPlasticity {
+    fn kirchhoff_stress(&self, particle: &MpmParticle) -> Mat2 {
+        let moduli = self.hardened_moduli(particle);
+        fixed_corotated_stress(particle.deformation_gradient, moduli.x, moduli.y)
+    }
+
+    fn strain_energy_density(&self, particle: &MpmParticle) -> f32 {
+        let moduli = self.hardened_moduli(particle);
+        fixed_corotated_energy(particle.deformation_gradient, moduli.x, moduli.y)
+    }
+
+    fn constraint_moduli(&self, particle: &MpmParticle) -> Vec2 {
+        self.hardened_moduli(particle)
+    }
+
+    fn project_plasticity(&self, particle: &mut MpmParticle) {
+        let (u, sigma, v) = svd2(particle.deformation_gradient);
+        let clamped = sigma.clamp(
+            Vec2::splat(1.0 - self.critical_compression),
+            Vec2::splat(1.0 + self.critical_stretch),
+        );
+        // Volume removed from the elastic part moves into the plastic part
+        let elastic_before = sigma.x * sigma.y;
+        let elastic_after = clamped.x * clamped.y;
+        if elastic_after.abs() > f32::EPSILON {
+            particle.plastic_volume_ratio *= elastic_before / elastic_after;
+        }
+        particle.deformation_gradient = u * Mat2::from_diagonal(clamped) * v.transpose();
+    }
+}
+
+/// Granular material (sand, soil, gravel): Drucker-Prager yield on Hencky strain.
+///
+/// **PHYSICS**: Cohesionless. Tension separates grains (elastic strain reset),
+/// compression with shear beyond tan(friction angle) flows plastically.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct DruckerPrager {
+    pub elasticity: MpmElasticity,
+    /// Internal friction angle φ (radians, dry sand ~0.52 = 30°)
+    pub friction_angle: f32,
+}
+
+impl DruckerPrager {
+    /// Yield surface slope α = √(2/3)·2·sin φ / (3 - sin φ).
+    pub fn yield_slope(&self) -> f32 {
+        let s = self.friction_angle.sin();
+        (2.0f32 / 3.0).sqrt() * 2.0 * s / (3.0 - s)
+    }
+}
+
+impl ConstitutiveModel for DruckerPrager {
+    /// St. Venant-Kirchhoff on Hencky strain: τ = U·(2μ·ln Σ + λ·tr(ln Σ)·I)·Uᵀ.
+    fn kirchhoff_stress(&self, particle: &MpmParticle) -> Mat2 {
+        let (mu, lambda) = self.elasticity.lame_parameters();
+        let (u, sigma, _) = svd2(particle.deformation_gradient);
+        let epsilon = sigma.abs().max(Vec2::splat(1e-6)).ln();
+        let trace = epsilon.x + epsilon.y;
+        let principal = epsilon * (2.0 * mu) + Vec2::splat(lambda * trace);
+        u * Mat2::from_diagonal(principal) * u.transpose()
+    }
+
+    /// ψ = μ·tr(ε²) + λ/2·tr(ε)² on Hencky strain ε = ln Σ
+    fn strain_energy_density(&self, particle: &MpmParticle) -> f32 {
+        let (mu, lambda) = self.elasticity.lame_parameters();
+        let (_, sigma, _) = svd2(particle.deformation_gradient);
+        let epsilon = sigma.abs().max(Vec2::splat(1e-6)).ln();
+        let trace = epsilon.x + epsilon.y;
+        mu * epsilon.length_squared() + 0.5 * lambda * trace * trace
+    }
+
+    fn constraint_moduli(&self, _particle: &MpmParticle) -> Vec2 {
+        self.elasticity.moduli()
+    }
+
+    fn project_plasticity(&self, particle: &mut MpmParticle) {
+        let (mu, lambda) = self.elasticity.lame_parameters();
+        let (u, sigma, v) = svd2(particle.deformation_gradient);
+        let epsilon = sigma.abs().max(Vec2::splat(1e-6)).ln();
+        let trace = epsilon.x + epsilon.y;
+
+        let projected = if trace >= 0.0 {
+            // Tension: grains separate, no elastic strain survives
+            Vec2::ZERO
+        } else {
+            let deviatoric = epsilon - Vec2::splat(0.5 * trace);
+            let norm = deviatoric.length();
+            let yield_amount =
+                norm + (2.0 * lambda + 2.0 * mu) / (2.0 * mu) * trace * self.yield_slope();
+            if yield_amount <= 0.0 || norm <= f32::EPSILON {
+                // Inside the friction cone: elastic
+                return;
+            }
+            epsilon - deviatoric * (yield_amount / norm)
+        };
+
+        let sigma = Vec2::new(projected.x.exp(), projected.y.exp());
+        particle.deformation_gradient = u * Mat2::from_diagonal(sigma) * v.transpose();
+    }
+}
+
+/// Weakly compressible Newtonian fluid (water, mud, lava at game scale).
+///
+/// **PHYSICS**: Tait EOS p = (K/γ)·(J^{-γ} - 1) (`matter::states::eos::Tait`),
+/// viscous stress σᵥ = η(∇v + ∇vᵀ) with ∇v ≈ C (APIC affine). Only J is kept in F,
+/// so shear never accumulates.
+///
+/// **LP-0**: Real water has K ≈ 2.2 GPa; game scenes use a much smaller K so the
+/// speed of sound √(K/ρ) stays within the solver's stable range (~10× flow speed).
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct WeaklyCompressibleFluid {
+    /// Bulk modulus K (Pa)
+    pub bulk_modulus: f32,
+    /// Tait exponent γ (dimensionless, 7 for water)
+    pub exponent: f32,
+    /// Dynamic viscosity η (Pa·s)
+    pub viscosity: f32,
+}
+
+impl WeaklyCompressibleFluid {
+    /// The Tait equation of state of this fluid, per unit rest density (ρ/ρ₀ = 1/J).
+    pub fn equation_of_state(&self) -> Tait {
+        Tait::new(1.0, self.bulk_modulus, self.exponent)
+    }
+
+    /// Pressure p (Pa) at volume ratio J.
+    pub fn pressure(&self, volume_ratio: f32) -> f32 {
+        self.equation_of_state()
+            .pressure_at_volume_ratio(volume_ratio)
+    }
+}
+
+impl ConstitutiveModel for WeaklyCompressibleFluid {
+    fn kirchhoff_stress(&self, particle: &MpmParticle) -> Mat2 {
+        let j = particle.volume_ratio();
+        let c = particle.affine;
+        Mat2::IDENTITY * (-self.pressure(j) * j) + (c + c.transpose()) * (self.viscosity * j)
+    }
+
+    /// ψ(J) = -∫₁ᴶ p dJ = K/γ·(J^{1-γ}/(γ-1) + J) - K/(γ-1)
+    fn strain_energy_density(&self, particle: &MpmParticle) -> f32 {
+        let j = particle.volume_ratio().max(1e-3);
+        let (k, gamma) = (self.bulk_modulus, self.exponent);
+        k / gamma * (j.powf(1.0 - gamma) / (gamma - 1.0) + j) - k / (gamma - 1.0)
+    }
+
+    fn constraint_moduli(&self, _particle: &MpmParticle) -> Vec2 {
+        // Volume constraint only: fluids resist compression, not shape change
+        Vec2::new(0.0, self.bulk_modulus)
+    }
+
+    fn project_plasticity(&self, particle: &mut MpmParticle) {
+        let j = particle.volume_ratio().max(1e-3);
+        particle.deformation_gradient = Mat2::IDENTITY * j.sqrt();
+    }
+}
+
+/// Material model of a material point (component).
+///
+/// Particles without this component carry no stress (ballistic dust).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub enum MpmMaterial {
+    NeoHookean(NeoHookean),
+    FixedCorotated(FixedCorotated),
+    Snow(SnowPlasticity),
+    Sand(DruckerPrager),
+    Fluid(WeaklyCompressibleFluid),
+}
+
+impl MpmMaterial {
+    pub fn neo_hookean(youngs_modulus: f32, poisson_ratio: f32) -> Self {
+        Self::NeoHookean(NeoHookean {
+            elasticity: MpmElasticity::new(youngs_modulus, poisson_ratio),
+        })
+    }
+
+    pub fn fixed_corotated(youngs_modulus: f32, poisson_ratio: f32) -> Self {
+        Self::FixedCorotated(FixedCorotated {
+            elasticity: MpmElasticity::new(youngs_modulus, poisson_ratio),
+        })
+    }
+
+    /// Soft tissue (E ≈ 100 kPa, nearly incompressible).
+    pub fn flesh() -> Self {
+        Self::neo_hookean(1.0e5, 0.45)
+    }
+
+    /// Wood at game stiffness.
+    ///
+    /// **LP-0**: Real wood is E ≈ 10 GPa along the grain; 10 MPa keeps it stable
+    /// with a handful of substeps while still reading as rigid.
+    pub fn wood() -> Self {
+        Self::fixed_corotated(1.0e7, 0.3)
+    }
+
+    /// Snow with the parameters of Stomakhin et al. 2013.
+    pub fn snow() -> Self {
+        Self::Snow(SnowPlasticity {
+            elasticity: MpmElasticity::new(1.4e5, 0.2),
+            critical_compression: 2.5e-2,
+            critical_stretch: 7.5e-3,
+            hardening: 10.0,
+        })
+    }
+
+    /// Dry sand (Klár et al. 2016 stiffness, 30° friction angle).
+    pub fn sand() -> Self {
+        Self::Sand(DruckerPrager {
+            elasticity: MpmElasticity::new(3.537e5, 0.3),
+            friction_angle: 30f32.to_radians(),
+        })
+    }
+
+    /// Water at game compressibility (see [`WeaklyCompressibleFluid`]).
+    pub fn water() -> Self {
+        Self::Fluid(WeaklyCompressibleFluid {
+            bulk_modulus: 1.0e5,
+            exponent: 7.0,
+            viscosity: 1.0e-3,
+        })
+    }
+
+    /// Model for a registry material.
+    ///
+    /// A friction angle selects Drucker-Prager sand, a Young's modulus fixed-corotated
+    /// elasticity, a bulk modulus a Tait fluid (γ = 7). `None` for materials without
+    /// any of them (air, vacuum), which MPM treats as stress-free dust.
+    pub fn from_material(material: &Material) -> Option<Self> {
+        if material.friction_angle > 0.0 && material.youngs_modulus > 0.0 {
+            Some(Self::Sand(DruckerPrager {
+                elasticity: MpmElasticity::new(material.youngs_modulus, material.poisson_ratio),
+                friction_angle: material.friction_angle.to_radians(),
+            }))
+        } else if material.youngs_modulus > 0.0 {
+            Some(Self::fixed_corotated(
+                material.youngs_modulus,
+                material.poisson_ratio,
+            ))
+        } else if material.bulk_modulus > 0.0 {
+            Some(Self::Fluid(WeaklyCompressibleFluid {
+                bulk_modulus: material.bulk_modulus,
+                exponent: 7.0,
+                viscosity: material.viscosity,
+            }))
+        } else {
+            None
+        }
+    }
+
+    /// Elastic parameters of the solid models (`None` for fluids).
+    pub fn elasticity(&self) -> Option<MpmElasticity> {
+        match self {
+            Self::NeoHookean(model) => Some(model.elasticity),
+            Self::FixedCorotated(model) => Some(model.elasticity),
+            Self::Snow(model) => Some(model.elasticity),
+            Self::Sand(model) => Some(model.elasticity),
+            Self::Fluid(_) => None,
+        }
+    }
+
+    fn model(&self) -> &dyn ConstitutiveModel {
+        match self {
+            Self::NeoHookean(model) => model,
+            Self::FixedCorotated(model) => model,
+            Self::Snow(model) => model,
+            Self::Sand(model) => model,
+            Self::Fluid(model) => model,
+        }
+    }
+}
+
+impl ConstitutiveModel for MpmMaterial {
+    fn kirchhoff_stress(&self, particle: &MpmParticle) -> Mat2 {
+        self.model().kirchhoff_stress(particle)
+    }
+
+    fn strain_energy_density(&self, particle: &MpmParticle) -> f32 {
+        self.model().strain_energy_density(particle)
+    }
+
+    fn constraint_moduli(&self, particle: &MpmParticle) -> Vec2 {
+        self.model().constraint_moduli(particle)
+    }
+
+    fn project_plasticity(&self, particle: &mut MpmParticle) {
+        self.model().project_plasticity(particle)
+    }
+}
+
+/// Derive [`MpmMaterial`] and the thermal coefficients of [`MpmThermal`] from a
+/// particle's [`MaterialId`] when it is added or changed, or for every particle when
+/// the registry changes (late or hot-reloaded libraries).
+///
+/// Particle mass stays as spawned (seed with `Material::density`); an existing
+/// `MpmThermal` keeps its temperature.
+#[allow(clippy::type_complexity)]
+pub fn apply_particle_materials(
+    mut commands: Commands,
+    registry: Res<MaterialRegistry>,
+    mut particles: Query<(Entity, Ref<MaterialId>, Option<&mut MpmThermal>), With<MpmParticle>>,
+) {
+    for (entity, id, thermal) in particles.iter_mut() {
+        if !id.is_changed() && !registry.is_changed() {
+            continue;
+        }
+        let Some(material) = registry.get(*id) else {
+            continue;
+        };
+        match MpmMaterial::from_material(material) {
+            Some(model) => commands.entity(entity).insert(model),
+            None => commands.entity(entity).remove::<MpmMaterial>(),
+        };
+        if let Some(mut thermal) = thermal {
+            *thermal = MpmThermal::new(
+                thermal.temperature,
+                material.specific_heat,
+                material.thermal_conductivity,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn particle_with(deformation_gradient: Mat2) -> MpmParticle {
+        let mut particle = MpmParticle::new(Vec2::ZERO, 1.0, 0.01);
+        particle.deformation_gradient = deformation_gradient;
+        particle
+    }
+
+    #[test]
+    fn test_svd_reconstructs_matrix() {
+        let f = Mat2::from_cols(Vec2::new(1.1, 0.3), Vec2::new(-0.2, 0.9));
+        let (u, sigma, v) = svd2(f);
+        let rebuilt = u * Mat2::from_diagonal(sigma) * v.transpose();
+        assert!(rebuilt.abs_diff_eq(f, 1e-5));
+        assert!((u.determinant() - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_all_models_stress_free_at_rest() {
+        let rest = particle_with(Mat2::IDENTITY);
+        for material in [
+            MpmMaterial::flesh(),
+            MpmMaterial::wood(),
+            MpmMaterial::snow(),
+            MpmMaterial::sand(),
+            MpmMaterial::water(),
+        ] {
+            let stress = material.kirchhoff_stress(&rest);
+            assert!(stress.abs_diff_eq(Mat2::ZERO, 1e-2), "{:?}", material);
+        }
+    }
+
+    #[test]
+    fn test_strain_energy_is_zero_at_rest_and_positive_when_deformed() {
+        let rest = particle_with(Mat2::IDENTITY);
+        let deformed = particle_with(Mat2::from_cols(Vec2::new(1.1, 0.05), Vec2::new(0.1, 0.93)));
+        for material in [
+            MpmMaterial::flesh(),
+            MpmMaterial::wood(),
+            MpmMaterial::snow(),
+            MpmMaterial::sand(),
+            MpmMaterial::water(),
+        ] {
+            assert!(material.strain_energy_density(&rest).abs() < 1e-2);
+            assert!(
+                material.strain_energy_density(&deformed) > 0.0,
+                "{:?}",
+                material
+            );
+        }
+    }
+
+    #[test]
+    fn test_compression_produces_pressure() {
+        // Uniform compression must push outward: negative diagonal stress (pressure).
+        let compressed = particle_with(Mat2::from_diagonal(Vec2::splat(0.9)));
+        for material in [
+            MpmMaterial::flesh(),
+            MpmMaterial::wood(),
+            MpmMaterial::water(),
+        ] {
+            let stress = material.kirchhoff_stress(&compressed);
+            assert!(stress.x_axis.x < 0.0 && stress.y_axis.y < 0.0);
+        }
+    }
+
+    #[test]
+    fn test_snow_plastic_compaction_hardens() {
+        let snow = MpmMaterial::snow();
+        let mut particle = particle_with(Mat2::from_diagonal(Vec2::splat(0.9)));
+        let soft = snow.constraint_moduli(&particle);
+        snow.project_plasticity(&mut particle);
+
+        // Elastic part clamped to 1 - θc, the rest became permanent compaction
+        assert!((particle.deformation_gradient.x_axis.x - 0.975).abs() < 1e-5);
+        assert!(particle.plastic_volume_ratio < 1.0);
+        assert!(snow.constraint_moduli(&particle).x > soft.x);
+    }
+
+    #[test]
+    fn test_sand_cannot_hold_tension() {
+        let sand = MpmMaterial::sand();
+        let mut particle = particle_with(Mat2::from_diagonal(Vec2::new(1.1, 1.05)));
+        sand.project_plasticity(&mut particle);
+        assert!(
+            particle
+                .deformation_gradient
+                .abs_diff_eq(Mat2::IDENTITY, 1e-5)
+        );
+
+        // Pure compression sits inside the friction cone and is left elastic
+        let compressed = Mat2::from_diagonal(Vec2::splat(0.98));
+        let mut particle = particle_with(compressed);
+        sand.project_plasticity(&mut particle);
+        assert!(particle.deformation_gradient.abs_diff_eq(compressed, 1e-5));
+    }
+
+    #[test]
+    fn test_fluid_forgets_shear() {
+        let water = MpmMaterial::water();
+        let sheared = Mat2::from_cols(Vec2::new(1.0, 0.0), Vec2::new(0.4, 1.0));
+        let mut particle = particle_with(sheared);
+        water.project_plasticity(&mut particle);
+
+        assert!((particle.volume_ratio() - sheared.determinant()).abs() < 1e-5);
+        assert!(particle.deformation_gradient.y_axis.x.abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_registry_materials_match_presets() {
+        let registry = MaterialRegistry::default();
+        let model = |name: &str| MpmMaterial::from_material(registry.get_by_name(name).unwrap());
+        assert_eq!(model("wood"), Some(MpmMaterial::wood()));
+        assert_eq!(model("sand"), Some(MpmMaterial::sand()));
+        assert_eq!(model("water"), Some(MpmMaterial::water()));
+        assert_eq!(model("air"), None);
+    }
+}
@@ -0,0 +1,201 @@
+//! Two-way coupling between MPM continua and Newtonian rigid bodies.
+//!
+//! A rigid body joins the grid solve by carrying an `MpmCollider` next to its `Mass`,
+//! `Velocity` and `AppliedForce`:
+//! - **Body → grid**: the body's velocity (v + ω × r) is the boundary condition for
+//!   every grid node inside its shape (see [`crate::collider`])
+//! - **Grid → body**: the momentum those nodes lose is summed over all substeps and
+//!   written back as `AppliedForce` / `AppliedTorque` before Newton integration
+//!
+//! **PHYSICS**: Buoyancy is not modeled separately. Fluid pressure on the nodes
+//! around a body produces the Archimedes force, so a body floats or sinks according
+//! to its `Mass` versus the displaced continuum.
+//!
+//! **CONSERVATION**: Grid impulse and body impulse are equal and opposite per node,
+//! so total linear momentum is exchanged, not created.
+
+use bevy::prelude::*;
+use forces::core::newton_laws::{AppliedForce, AppliedTorque};
+
+use crate::collider::MpmColliders;
+use crate::solver::MpmStepPlan;
+
+/// Convert accumulated grid impulses into forces on coupled rigid bodies: F = J / Δt.
+///
+/// Δt is the time the substeps actually simulated ([`MpmStepPlan::simulated_time`]),
+/// which is shorter than the fixed tick when the CFL substep limit was hit.
+/// Runs once per fixed tick after all substeps, inside `MpmSet::Step`.
+pub fn apply_coupling_forces(
+    plan: Res<MpmStepPlan>,
+    colliders: Res<MpmColliders>,
+    mut bodies: Query<(&mut AppliedForce, Option<&mut AppliedTorque>)>,
+) {
+    let dt = plan.simulated_time();
+    if dt <= 0.0 {
+        return;
+    }
+
+    for collider in colliders.colliders.iter().filter(|c| c.coupled) {
+        let Ok((mut force, torque)) = bodies.get_mut(collider.entity) else {
+            continue;
+        };
+        force.force += (collider.impulse / dt).extend(0.0);
+        if let Some(mut torque) = torque {
+            torque.torque.z += collider.angular_impulse / dt;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MPMPlugin;
+    use crate::collider::{BoundaryCondition, ColliderShape, MpmCollider};
+    use crate::constitutive::MpmMaterial;
+    use crate::particle::MpmParticle;
+    use bevy::time::TimeUpdateStrategy;
+    use forces::core::gravity::{GravityAffected, GravityPlugin};
+    use forces::core::newton_laws::{Mass, NewtonLawsPlugin, PreviousAcceleration, Velocity};
+    use std::time::Duration;
+
+    const POOL_SURFACE: f32 = 1.2;
+
+    /// Water pool 2 m wide resting on the grid floor, with a 0.4 m box dropped in.
+    fn drop_box_into_pool(box_density: f32) -> (App, Entity) {
+        let tick = Duration::from_millis(10);
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            NewtonLawsPlugin,
+            GravityPlugin::new(),
+            MPMPlugin,
+        ))
+        .insert_resource(Time::<Fixed>::from_duration(tick))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(tick));
+        app.update();
+
+        // Side walls keep the pool from spreading over the whole grid
+        for x in [4.9, 7.5] {
+            app.world_mut().spawn((
+                MpmCollider::new(ColliderShape::rectangle(0.2, 4.0), BoundaryCondition::Slip),
+                Transform::from_xyz(x, 2.0, 0.0),
+            ));
+        }
+
+        let spacing = 0.05;
+        let mut y = 0.225;
+        while y < POOL_SURFACE {
+            let mut x = 5.025;
+            while x < 7.4 {
+                app.world_mut().spawn((
+                    MpmParticle::from_density(Vec2::new(x, y), 1000.0, spacing * spacing),
+                    MpmMaterial::water(),
+                ));
+                x += spacing;
+            }
+            y += spacing;
+        }
+
+        let size = 0.4;
+        let body = app
+            .world_mut()
+            .spawn((
+                MpmCollider::new(
+                    ColliderShape::rectangle(size, size),
+                    BoundaryCondition::Slip,
+                ),
+                Mass::new(box_density * size * size),
+                Velocity::default(),
+                PreviousAcceleration::default(),
+                AppliedForce::default(),
+                GravityAffected,
+                Transform::from_xyz(6.2, POOL_SURFACE + 0.5, 0.0),
+            ))
+            .id();
+        (app, body)
+    }
+
+    fn run_ticks(app: &mut App, body: Entity, ticks: u32) -> f32 {
+        for _ in 0..ticks {
+            app.update();
+        }
+        app.world().get::<Transform>(body).unwrap().translation.y
+    }
+
+    #[test]
+    fn test_force_uses_simulated_time_when_substeps_are_capped() {
+        use crate::collider::ResolvedCollider;
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        let body = world.spawn(AppliedForce::default()).id();
+        // Substep cap hit: 2 × 2 ms simulated of a 10 ms tick
+        world.insert_resource(MpmStepPlan {
+            substeps: 2,
+            dt: 0.002,
+            ..default()
+        });
+        world.insert_resource(MpmColliders {
+            colliders: vec![ResolvedCollider {
+                entity: body,
+                shape: ColliderShape::rectangle(0.4, 0.4),
+                boundary: BoundaryCondition::Slip,
+                center: Vec2::ZERO,
+                rotation: 0.0,
+                linear_velocity: Vec2::ZERO,
+                angular_velocity: 0.0,
+                coupled: true,
+                impulse: Vec2::new(0.1, 0.0),
+                angular_impulse: 0.0,
+            }],
+        });
+        world.run_system_once(apply_coupling_forces).unwrap();
+        let force = world.get::<AppliedForce>(body).unwrap().force;
+        assert!((force.x - 0.1 / 0.004).abs() < 1e-3, "force {force}");
+    }
+
+    #[test]
+    fn test_light_body_floats() {
+        let (mut app, body) = drop_box_into_pool(400.0);
+        let height = run_ticks(&mut app, body, 300);
+        // Floating box center sits near the surface, well above the floor
+        assert!(height > POOL_SURFACE - 0.3, "box center at {}", height);
+        assert!(height < POOL_SURFACE + 0.3, "box center at {}", height);
+    }
+
+    #[test]
+    fn test_heavy_body_sinks() {
+        let (mut app, body) = drop_box_into_pool(3000.0);
+        let height = run_ticks(&mut app, body, 60);
+        // Fully submerged after 0.6 s, but slowed down by the water compared to
+        // free fall (which would have reached y ≈ -0.07)
+        let free_fall = POOL_SURFACE + 0.5 - 0.5 * 9.81 * 0.6 * 0.6;
+        assert!(
+            height < POOL_SURFACE - 0.4,
+            "dense box center at {}",
+            height
+        );
+        assert!(
+            height > free_fall + 0.2,
+            "no drag: {} vs {}",
+            height,
+            free_fall
+        );
+    }
+
+    #[test]
+    fn test_impact_pushes_fluid() {
+        let (mut app, body) = drop_box_into_pool(1500.0);
+        for _ in 0..60 {
+            app.update();
+        }
+        // The splash must put some water in motion
+        let mut query = app.world_mut().query::<&MpmParticle>();
+        let max_speed = query
+            .iter(app.world())
+            .map(|p| p.velocity.length())
+            .fold(0.0, f32::max);
+        assert!(max_speed > 0.3, "fluid max speed {}", max_speed);
+        assert!(app.world().get::<Transform>(body).unwrap().translation.y < POOL_SURFACE + 0.5);
+    }
+}
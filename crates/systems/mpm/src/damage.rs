@@ -0,0 +1,287 @@
+//! Continuum damage and fracture for MPM solids.
+//!
+//! A particle with `matter::fracture::FractureProperties` gets an [`MpmDamage`] state.
+//! After every G2P its largest principal strain is checked against the Rankine
+//! cracking strain; past it, damage grows and the particle's tensile stiffness is
+//! scaled by (1 - d). At [`CRITICAL_DAMAGE`] the particle no longer holds its
+//! neighbours together and a [`FractureEvent`] is sent.
+//!
+//! **PHYSICS**: Crack band model (Bažant-Oh 1983) with exponential softening
+//! (Oliver 1996): d(κ) = 1 - (ε_t/κ)·exp(-(κ - ε_t)/ε_s), where κ is the largest
+//! principal Hencky strain reached, ε_t = σ_t/E and ε_s follows from dissipating
+//! G_f/Δx per unit volume (the crack is smeared over one grid cell).
+//!
+//! **CONSERVATION**: Only tension and shear are degraded; compressive volumetric
+//! stress is kept so broken pieces still collide. Elastic energy lost to damage
+//! is reported as `FractureEvent::released_energy` and shows up in
+//! `ContinuumEnergyEvent::dissipated`.
+
+use bevy::prelude::*;
+use matter::fracture::FractureProperties;
+
+use crate::constitutive::{ConstitutiveModel, MpmMaterial, svd2};
+use crate::grid::MpmGrid;
+use crate::particle::MpmParticle;
+
+/// Damage above which a particle counts as fractured.
+pub const CRITICAL_DAMAGE: f32 = 0.99;
+
+/// Damage state of a material point.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct MpmDamage {
+    /// Damage d ∈ [0, 1]: 0 intact, 1 fully cracked
+    pub damage: f32,
+    /// Largest principal Hencky strain reached so far κ (dimensionless)
+    pub max_strain: f32,
+    /// Elastic energy released since the particle started cracking (J)
+    pub released_energy: f32,
+    /// `FractureEvent` already sent for this particle
+    pub fractured: bool,
+}
+
+impl MpmDamage {
+    /// Remaining tensile stiffness (1 - d).
+    #[inline]
+    pub fn stiffness_factor(&self) -> f32 {
+        1.0 - self.damage
+    }
+
+    /// Degrade a Kirchhoff stress: tension and shear scale with (1 - d), compressive
+    /// pressure (J < 1) is kept.
+    pub fn degrade_stress(&self, stress: Mat2, volume_ratio: f32) -> Mat2 {
+        let factor = self.stiffness_factor();
+        if volume_ratio >= 1.0 {
+            return stress * factor;
+        }
+        let pressure = Mat2::IDENTITY * (0.5 * (stress.x_axis.x + stress.y_axis.y));
+        (stress - pressure) * factor + pressure
+    }
+
+    /// Constraint moduli (μ, λ) with the same split as [`MpmDamage::degrade_stress`].
+    pub fn degrade_moduli(&self, moduli: Vec2, volume_ratio: f32) -> Vec2 {
+        let factor = self.stiffness_factor();
+        if volume_ratio >= 1.0 {
+            moduli * factor
+        } else {
+            Vec2::new(moduli.x * factor, moduli.y)
+        }
+    }
+
+    /// Degrade a strain energy density consistently with the stress split.
+    pub fn degrade_energy(&self, energy_density: f32, volume_ratio: f32) -> f32 {
+        if volume_ratio >= 1.0 {
+            energy_density * self.stiffness_factor()
+        } else {
+            energy_density
+        }
+    }
+}
+
+/// Emitted once when a particle's damage crosses [`CRITICAL_DAMAGE`].
+///
+/// Acoustics can turn `released_energy` into a crack sound, AI can treat the location
+/// as a wound or a breach.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct FractureEvent {
+    /// Fractured particle
+    pub entity: Entity,
+    /// Particle position at fracture (m)
+    pub position: Vec2,
+    /// Elastic energy released while the crack opened (J)
+    pub released_energy: f32,
+}
+
+/// Softening strain ε_s (dimensionless) from the crack band energy balance.
+///
+/// **PHYSICS**: Exponential softening dissipates σ_t·(ε_t/2 + ε_s) per unit volume;
+/// setting that equal to G_f/h gives ε_s = G_f/(h·σ_t) - ε_t/2. Clamped positive
+/// (a band too wide for the fracture energy would otherwise snap back).
+pub fn softening_strain(properties: &FractureProperties, youngs_modulus: f32, band: f32) -> f32 {
+    let cracking = properties.cracking_strain(youngs_modulus);
+    let softening =
+        properties.dissipation_density(band) / properties.tensile_strength - 0.5 * cracking;
+    softening.max(1e-3 * cracking)
+}
+
+/// Damage for a strain history κ: 0 up to ε_t, then exponential softening toward 1.
+pub fn damage_from_strain(max_strain: f32, cracking_strain: f32, softening_strain: f32) -> f32 {
+    if max_strain <= cracking_strain {
+        return 0.0;
+    }
+    let decay = (-(max_strain - cracking_strain) / softening_strain.max(f32::EPSILON)).exp();
+    (1.0 - cracking_strain / max_strain * decay).clamp(0.0, 1.0)
+}
+
+/// Insert a fresh [`MpmDamage`] on particles that received fracture properties.
+#[allow(clippy::type_complexity)]
+pub fn init_particle_damage(
+    mut commands: Commands,
+    particles: Query<
+        Entity,
+        (
+            With<MpmParticle>,
+            With<FractureProperties>,
+            Without<MpmDamage>,
+        ),
+    >,
+) {
+    for entity in particles.iter() {
+        commands.entity(entity).insert(MpmDamage::default());
+    }
+}
+
+/// Grow damage from the principal strain after G2P and report completed fractures.
+pub fn update_particle_damage(
+    grid: Res<MpmGrid>,
+    mut particles: Query<(
+        Entity,
+        &MpmParticle,
+        &MpmMaterial,
+        &FractureProperties,
+        &mut MpmDamage,
+    )>,
+    mut fractures: MessageWriter<FractureEvent>,
+) {
+    let band = grid.cell_size();
+    for (entity, particle, material, properties, mut state) in particles.iter_mut() {
+        let Some(elasticity) = material.elasticity() else {
+            continue;
+        };
+        let (_, sigma, _) = svd2(particle.deformation_gradient);
+        let strain = sigma.max_element().max(f32::EPSILON).ln();
+        if strain <= state.max_strain {
+            continue;
+        }
+
+        let e = elasticity.youngs_modulus;
+        let cracking = properties.cracking_strain(e);
+        let damage = damage_from_strain(strain, cracking, softening_strain(properties, e, band));
+        let growth = damage - state.damage;
+        let energy = material.strain_energy_density(particle);
+        state.max_strain = strain;
+        if growth <= 0.0 {
+            continue;
+        }
+        state.damage = damage;
+        state.released_energy += growth * energy * particle.initial_volume;
+
+        if !state.fractured && state.damage >= CRITICAL_DAMAGE {
+            state.fractured = true;
+            fractures.write(FractureEvent {
+                entity,
+                position: particle.position,
+                released_energy: state.released_energy,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MPMPlugin;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    #[test]
+    fn test_damage_law_softens_past_cracking_strain() {
+        let (cracking, softening) = (0.02, 0.05);
+        assert_eq!(damage_from_strain(0.01, cracking, softening), 0.0);
+        let mut previous = 0.0;
+        for step in 1..50 {
+            let d = damage_from_strain(cracking + step as f32 * 0.01, cracking, softening);
+            assert!(d > previous && d < 1.0);
+            previous = d;
+        }
+        assert!(previous > CRITICAL_DAMAGE);
+
+        let state = MpmDamage {
+            damage: 0.75,
+            ..default()
+        };
+        let stress = Mat2::from_diagonal(Vec2::new(-100.0, -100.0));
+        // Broken material still resists compression
+        assert_eq!(state.degrade_stress(stress, 0.9), stress);
+        assert_eq!(state.degrade_stress(-stress, 1.1), -stress * 0.25);
+    }
+
+    #[derive(Resource, Default)]
+    struct Fractures(Vec<FractureEvent>);
+
+    fn collect_fractures(mut reader: MessageReader<FractureEvent>, mut out: ResMut<Fractures>) {
+        out.0.extend(reader.read().copied());
+    }
+
+    /// 1.6 m × 0.2 m bar centered at x = 6.4 whose halves move apart at `speed`.
+    fn pulled_bar(speed: f32) -> App {
+        let tick = Duration::from_millis(10);
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, MPMPlugin))
+            .insert_resource(Time::<Fixed>::from_duration(tick))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(tick))
+            .init_resource::<Fractures>()
+            .add_systems(FixedPostUpdate, collect_fractures);
+        app.update();
+
+        let spacing = 0.05;
+        for j in 0..4 {
+            for i in 0..32 {
+                let position = Vec2::new(5.625 + i as f32 * spacing, 6.0 + j as f32 * spacing);
+                let direction = if position.x < 6.4 { -1.0 } else { 1.0 };
+                app.world_mut().spawn((
+                    MpmParticle::from_density(position, 1000.0, spacing * spacing)
+                        .with_velocity(Vec2::new(direction * speed, 0.0)),
+                    MpmMaterial::neo_hookean(1.0e5, 0.3),
+                    FractureProperties::new(5.0e3, 1.0),
+                ));
+            }
+        }
+        app
+    }
+
+    #[test]
+    fn test_pulled_bar_snaps_in_the_middle() {
+        let mut app = pulled_bar(2.0);
+        let mut steps = 0;
+        while app.world().resource::<Fractures>().0.is_empty() {
+            assert!(steps < 60, "bar did not break");
+            app.update();
+            steps += 1;
+        }
+
+        // The crack starts within about one grid cell (0.1 m) of the midpoint
+        for fracture in &app.world().resource::<Fractures>().0 {
+            assert!((fracture.position.x - 6.4).abs() < 0.1, "{:?}", fracture);
+        }
+        for _ in steps..60 {
+            app.update();
+        }
+        let fractures = &app.world().resource::<Fractures>().0;
+        for fracture in fractures {
+            assert!(fracture.released_energy > 0.0);
+        }
+        // and spreads symmetrically from there
+        let mean = fractures.iter().map(|f| f.position.x).sum::<f32>() / fractures.len() as f32;
+        assert!((mean - 6.4).abs() < 0.01, "mean crack position {}", mean);
+
+        // The pieces keep flying apart instead of springing back: widest gap along x
+        let mut query = app.world_mut().query::<&MpmParticle>();
+        let mut xs: Vec<f32> = query.iter(app.world()).map(|p| p.position.x).collect();
+        xs.sort_by(f32::total_cmp);
+        let gap = xs.windows(2).map(|w| w[1] - w[0]).fold(0.0, f32::max);
+        assert!(gap > 0.2, "gap {}", gap);
+    }
+
+    #[test]
+    fn test_gentle_pull_leaves_bar_intact() {
+        let mut app = pulled_bar(0.01);
+        for _ in 0..60 {
+            app.update();
+        }
+
+        assert!(app.world().resource::<Fractures>().0.is_empty());
+        let mut query = app.world_mut().query::<&MpmDamage>();
+        assert!(query.iter(app.world()).all(|d| d.damage == 0.0));
+    }
+}
@@ -0,0 +1,160 @@
+//! Energy reporting for the MPM backend.
+//!
+//! Once per fixed tick the solver sums kinetic, elastic and gravitational energy over
+//! all material points and sends a `ContinuumEnergyEvent`. The energy crate's
+//! `EnergyConservationPlugin` records it next to the entity ledger, and
+//! `ForcesDiagnostics` aggregates it with the entity totals.
+//!
+//! **CONSERVATION**: Dissipation is the residual of the mechanical balance,
+//! D = E(prev) + W_ext - E(now). Plasticity, boundary friction and the smoothing of
+//! grid transfers all land there; a negative value means the solver created energy.
+
+use bevy::prelude::*;
+use forces::core::gravity::UniformGravity;
+use forces::core::newton_laws::ContinuumEnergyEvent;
+
+use crate::collider::MpmColliders;
+use crate::constitutive::{ConstitutiveModel, MpmMaterial};
+use crate::damage::MpmDamage;
+use crate::particle::MpmParticle;
+
+/// Previous tick's balance (reset whenever particles are added or removed).
+#[derive(Debug, Default)]
+pub struct EnergyReportState {
+    previous_energy: Option<f32>,
+    particle_count: usize,
+}
+
+/// Sum particle energies and emit one `ContinuumEnergyEvent` per fixed tick.
+pub fn report_mpm_energy(
+    mut state: Local<EnergyReportState>,
+    gravity: Option<Res<UniformGravity>>,
+    colliders: Res<MpmColliders>,
+    particles: Query<(&MpmParticle, Option<&MpmMaterial>, Option<&MpmDamage>)>,
+    mut reports: MessageWriter<ContinuumEnergyEvent>,
+) {
+    let g = gravity
+        .map(|g| g.acceleration.truncate())
+        .unwrap_or(Vec2::ZERO);
+
+    let mut report = ContinuumEnergyEvent::default();
+    let mut momentum = Vec2::ZERO;
+    let mut count = 0;
+    for (particle, material, damage) in particles.iter() {
+        report.kinetic += particle.kinetic_energy();
+        report.potential -= particle.mass * g.dot(particle.position);
+        if let Some(material) = material {
+            let mut psi = material.strain_energy_density(particle);
+            if let Some(damage) = damage {
+                psi = damage.degrade_energy(psi, particle.volume_ratio());
+            }
+            report.elastic += particle.initial_volume * psi;
+        }
+        momentum += particle.velocity * particle.mass;
+        count += 1;
+    }
+    report.momentum = momentum.extend(0.0);
+
+    // Work done by the continuum on coupled bodies, W = J·v, taken from the continuum
+    report.external_work = -colliders
+        .colliders
+        .iter()
+        .filter(|c| c.coupled)
+        .map(|c| c.impulse.dot(c.linear_velocity) + c.angular_impulse * c.angular_velocity)
+        .sum::<f32>();
+
+    let energy = report.mechanical_energy();
+    if count == state.particle_count
+        && let Some(previous) = state.previous_energy
+    {
+        report.dissipated = previous + report.external_work - energy;
+    }
+    state.previous_energy = Some(energy);
+    state.particle_count = count;
+
+    if count > 0 {
+        reports.write(report);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MPMPlugin;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    #[derive(Resource, Default)]
+    struct Reports(Vec<ContinuumEnergyEvent>);
+
+    fn collect_reports(
+        mut reader: MessageReader<ContinuumEnergyEvent>,
+        mut reports: ResMut<Reports>,
+    ) {
+        reports.0.extend(reader.read().copied());
+    }
+
+    fn test_app() -> App {
+        let tick = Duration::from_millis(10);
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, MPMPlugin))
+            .insert_resource(UniformGravity::default())
+            .insert_resource(Time::<Fixed>::from_duration(tick))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(tick))
+            .init_resource::<Reports>()
+            .add_systems(FixedPostUpdate, collect_reports);
+        app.update();
+        app
+    }
+
+    #[test]
+    fn test_free_fall_conserves_mechanical_energy() {
+        let mut app = test_app();
+        app.world_mut()
+            .spawn(MpmParticle::new(Vec2::new(6.4, 10.0), 1.0, 0.01));
+
+        for _ in 0..50 {
+            app.update();
+        }
+
+        let reports = &app.world().resource::<Reports>().0;
+        assert_eq!(reports.len(), 50);
+        let first = reports.first().unwrap().mechanical_energy();
+        let last = reports.last().unwrap();
+        // 0.5 s of free fall: ~12 J converted from potential to kinetic
+        assert!(last.kinetic > 10.0);
+        assert!((last.mechanical_energy() - first).abs() < 0.05 * last.kinetic);
+        let total_dissipated: f32 = reports.iter().map(|r| r.dissipated).sum();
+        assert!((first - last.mechanical_energy() - total_dissipated).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_impact_dissipates_without_creating_energy() {
+        let mut app = test_app();
+        let spacing = 0.05;
+        for j in 0..8 {
+            for i in 0..8 {
+                let position = Vec2::new(6.0 + i as f32 * spacing, 0.6 + j as f32 * spacing);
+                app.world_mut().spawn((
+                    MpmParticle::from_density(position, 1000.0, spacing * spacing),
+                    MpmMaterial::neo_hookean(5.0e4, 0.3),
+                ));
+            }
+        }
+
+        for _ in 0..150 {
+            app.update();
+        }
+
+        let reports = &app.world().resource::<Reports>().0;
+        let total_dissipated: f32 = reports.iter().map(|r| r.dissipated).sum();
+        let initial = reports.first().unwrap().mechanical_energy();
+        let last = reports.last().unwrap();
+        assert!(
+            last.elastic > 0.0,
+            "resting block is compressed by its weight"
+        );
+        assert!(total_dissipated > 0.0);
+        assert!(last.mechanical_energy() < initial);
+    }
+}
@@ -1,18 +1,138 @@
 use bevy::prelude::*;
+use forces::PhysicsSet;
 
+pub mod collider;
+pub mod constitutive;
+pub mod coupling;
+pub mod damage;
+pub mod diagnostics;
 pub mod grid;
 pub mod particle;
+pub mod phase;
+pub mod position_based;
+pub mod scatter;
+pub mod seeding;
 pub mod solver;
+pub mod surface;
+pub mod thermal;
 pub mod transfer;
 
+use collider::{MpmColliders, apply_collider_boundaries, cache_mpm_colliders};
+use constitutive::apply_particle_materials;
+use coupling::apply_coupling_forces;
+use damage::{FractureEvent, init_particle_damage, update_particle_damage};
+use diagnostics::report_mpm_energy;
+use forces::core::newton_laws::ContinuumEnergyEvent;
+use grid::MpmGrid;
+use matter::phase::PhaseTransitionEvent;
+use phase::update_mpm_phases;
+use position_based::{integrate_position_based, solve_grid_constraints};
+use solver::{
+    MpmConfig, MpmSet, MpmSolverKind, MpmStepPlan, MpmSubstep, MpmSubstepLimitEvent, MpmSubstepSet,
+    MpmTime, clear_grid, gather_grid_to_particles, plan_mpm_substeps, run_mpm_substeps,
+    scatter_particles_to_grid, solver_is, sync_particle_transforms, update_grid_velocities,
+};
+use thermal::{diffuse_grid_heat, gather_particle_heat, scatter_particle_heat};
+
 /// Plugin for the Material Point Method physics solver
+///
+/// Runs a 2D MPM step in `FixedUpdate`, between force accumulation and Newton
+/// integration. Particles are owned by MPM (`IntegratorKind::External`).
+/// `MpmConfig::solver` selects explicit MLS-MPM or position-based MPM.
 #[derive(Default)]
 pub struct MPMPlugin;
 
 impl Plugin for MPMPlugin {
-    fn build(&self, _app: &mut App) {
-        // TODO: Will integrate MPM systems when implementation is ready
-        // Plugin structure prepared for upcoming development
+    fn build(&self, app: &mut App) {
+        let mut substep = Schedule::new(MpmSubstep);
+        substep
+            .configure_sets(
+                (
+                    MpmSubstepSet::ParticleToGrid,
+                    MpmSubstepSet::GridUpdate,
+                    MpmSubstepSet::ConstraintSolve,
+                    MpmSubstepSet::GridToParticle,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                (clear_grid, scatter_particles_to_grid, scatter_particle_heat)
+                    .chain()
+                    .in_set(MpmSubstepSet::ParticleToGrid),
+            )
+            .add_systems(
+                (
+                    (update_grid_velocities, apply_collider_boundaries).chain(),
+                    diffuse_grid_heat,
+                )
+                    .in_set(MpmSubstepSet::GridUpdate),
+            )
+            .add_systems(
+                solve_grid_constraints
+                    .in_set(MpmSubstepSet::ConstraintSolve)
+                    .run_if(solver_is(MpmSolverKind::PositionBased)),
+            )
+            .add_systems(
+                (
+                    gather_particle_heat,
+                    (
+                        gather_grid_to_particles.run_if(solver_is(MpmSolverKind::Explicit)),
+                        integrate_position_based.run_if(solver_is(MpmSolverKind::PositionBased)),
+                    ),
+                    update_particle_damage,
+                )
+                    .chain()
+                    .in_set(MpmSubstepSet::GridToParticle),
+            );
+
+        app.init_resource::<MpmGrid>()
+            .init_resource::<MpmConfig>()
+            .init_resource::<matter::material::MaterialRegistry>()
+            .init_resource::<MpmTime>()
+            .init_resource::<MpmStepPlan>()
+            .init_resource::<MpmColliders>()
+            .register_type::<MpmConfig>()
+            .register_type::<MpmSolverKind>()
+            .register_type::<MpmStepPlan>()
+            .register_type::<particle::MpmParticle>()
+            .register_type::<constitutive::MpmMaterial>()
+            .register_type::<collider::MpmCollider>()
+            .register_type::<thermal::MpmThermal>()
+            .register_type::<phase::MpmPhaseMaterials>()
+            .register_type::<damage::MpmDamage>()
+            .add_message::<FractureEvent>()
+            .add_message::<MpmSubstepLimitEvent>()
+            .add_message::<PhaseTransitionEvent>()
+            .add_schedule(substep)
+            .configure_sets(
+                FixedUpdate,
+                (MpmSet::Step, MpmSet::Sync)
+                    .chain()
+                    .after(PhysicsSet::AccumulateForces)
+                    .before(PhysicsSet::Integrate),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    init_particle_damage,
+                    cache_mpm_colliders,
+                    plan_mpm_substeps,
+                    run_mpm_substeps,
+                    apply_coupling_forces,
+                    update_mpm_phases,
+                )
+                    .chain()
+                    .in_set(MpmSet::Step),
+            )
+            .add_message::<ContinuumEnergyEvent>()
+            .add_systems(
+                PreUpdate,
+                apply_particle_materials.after(matter::material::register_loaded_materials),
+            )
+            .add_systems(
+                FixedUpdate,
+                (sync_particle_transforms, report_mpm_energy).in_set(MpmSet::Sync),
+            );
     }
 }
 
@@ -22,9 +142,34 @@ impl Plugin for MPMPlugin {
 pub mod prelude {
     pub use super::MPMPlugin;
 
-    // TODO: Export main types when implementation is complete
-    // pub use crate::grid::*;
-    // pub use crate::particles::*;
-    // pub use crate::solver::*;
-    // pub use crate::transfer::*;
+    pub use crate::collider::{BoundaryCondition, ColliderShape, MpmCollider, MpmColliders};
+    pub use crate::constitutive::{
+        ConstitutiveModel, DruckerPrager, FixedCorotated, MpmElasticity, MpmMaterial, NeoHookean,
+        SnowPlasticity, WeaklyCompressibleFluid, polar_rotation, svd2,
+    };
+    pub use crate::damage::{CRITICAL_DAMAGE, FractureEvent, MpmDamage};
+    pub use crate::diagnostics::report_mpm_energy;
+    pub use crate::grid::{GRID_TILE_SIZE, GridNode, MpmGrid};
+    pub use crate::particle::MpmParticle;
+    pub use crate::phase::{MpmPhaseMaterials, particle_enthalpy};
+    pub use crate::position_based::project_elastic_constraint;
+    pub use crate::scatter::{GridBlock, SCATTER_BLOCK_SIZE, scatter_in_blocks};
+    pub use crate::seeding::{
+        AlphaMask, ParticleBatch, PoissonDiskSampler, SdfRegion, SeedRegion, ShapeRegion,
+    };
+    pub use crate::solver::{
+        MpmConfig, MpmSet, MpmSolverKind, MpmStepPlan, MpmSubstep, MpmSubstepLimitEvent,
+        MpmSubstepSet, MpmTime, wave_speed,
+    };
+    pub use crate::surface::{
+        DensityField, MpmSurfaceMesh, MpmSurfacePlugin, MpmSurfaceSettings, MpmSurfaceSource,
+        MpmSurfaces, SurfaceBody, contour_polylines, extract_surfaces,
+    };
+    pub use crate::thermal::{
+        MpmThermal, gather_temperature_change, scatter_heat, solve_heat_diffusion,
+    };
+    pub use crate::transfer::{
+        QuadraticStencil, ScatterTarget, gather_velocity, grid_to_particle, particle_to_grid,
+        scatter_momentum,
+    };
 }
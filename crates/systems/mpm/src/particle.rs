@@ -1 +1,88 @@
+//! Material points: the Lagrangian samples that carry continuum state.
+//!
+//! **Property-based**: Mass, volume and deformation live on the particle; the grid is
+//! rebuilt from them every substep.
 
+use bevy::prelude::*;
+use forces::core::newton_laws::IntegratorKind;
+
+/// A single material point of a continuum body (2D MLS-MPM).
+///
+/// **Units**: SI (meters, kilograms, seconds)
+///
+/// **Integration**: Position and velocity are owned by the MPM solver (G2P transfer).
+/// The required `IntegratorKind::External` tag keeps `NewtonLawsPlugin` from integrating
+/// the entity a second time, even if a `Velocity` component is attached for readers.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+#[require(Transform, IntegratorKind::External)]
+pub struct MpmParticle {
+    /// Position in meters (authoritative; `Transform` is synced from it).
+    pub position: Vec2,
+    /// Velocity in meters per second.
+    pub velocity: Vec2,
+    /// APIC affine velocity field C (1/s), carries angular momentum between transfers.
+    pub affine: Mat2,
+    /// Particle mass in kilograms.
+    pub mass: f32,
+    /// Rest volume V₀ in m² (2D "volume" is area).
+    pub initial_volume: f32,
+    /// Deformation gradient F (dimensionless), identity at rest.
+    /// Elastic part only: plastic models project permanent deformation out of it.
+    pub deformation_gradient: Mat2,
+    /// Plastic volume ratio Jp (dimensionless, 1.0 = no permanent compaction).
+    /// Hardening state for plastic constitutive models.
+    pub plastic_volume_ratio: f32,
+}
+
+impl MpmParticle {
+    pub fn new(position: Vec2, mass: f32, initial_volume: f32) -> Self {
+        debug_assert!(mass > 0.0, "Material point mass must be positive");
+        debug_assert!(
+            initial_volume > 0.0,
+            "Material point volume must be positive"
+        );
+        Self {
+            position,
+            velocity: Vec2::ZERO,
+            affine: Mat2::ZERO,
+            mass: mass.max(f32::EPSILON),
+            initial_volume: initial_volume.max(f32::EPSILON),
+            deformation_gradient: Mat2::IDENTITY,
+            plastic_volume_ratio: 1.0,
+        }
+    }
+
+    /// Create a particle from material density: m = ρ·V₀.
+    pub fn from_density(position: Vec2, density: f32, initial_volume: f32) -> Self {
+        Self::new(position, density * initial_volume, initial_volume)
+    }
+
+    pub fn with_velocity(mut self, velocity: Vec2) -> Self {
+        self.velocity = velocity;
+        self
+    }
+
+    /// Volume ratio J = det(F) (1.0 = rest volume, <1 compressed, >1 expanded).
+    pub fn volume_ratio(&self) -> f32 {
+        self.deformation_gradient.determinant()
+    }
+
+    /// Current volume V = J·V₀ (m²).
+    pub fn current_volume(&self) -> f32 {
+        self.volume_ratio() * self.initial_volume
+    }
+
+    /// Move with the current velocity and deform with the current affine field:
+    /// xₚ += Δt·vₚ, F ← (I + Δt·C)·F.
+    #[inline]
+    pub fn advect(&mut self, dt: f32) {
+        self.position += self.velocity * dt;
+        self.deformation_gradient = (Mat2::IDENTITY + self.affine * dt) * self.deformation_gradient;
+    }
+
+    /// Translational kinetic energy 0.5·m·v² (J).
+    pub fn kinetic_energy(&self) -> f32 {
+        0.5 * self.mass * self.velocity.length_squared()
+    }
+}
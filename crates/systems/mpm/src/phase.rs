@@ -0,0 +1,268 @@
+//! Phase transitions of MPM particles.
+//!
+//! A particle with `MpmThermal`, `PhaseState` and `matter::phase::PhaseTransitions`
+//! melts, freezes, boils and condenses as the grid heat solve moves its temperature.
+//! Latent heat is stored in `LatentHeatReservoir` while the particle sits at the
+//! transition temperature (see `matter::phase` for the enthalpy method).
+//!
+//! When a transition completes, the particle:
+//! - switches `MpmThermal::specific_heat` to the new phase
+//! - swaps its `MpmMaterial` for the one in [`MpmPhaseMaterials`]
+//! - keeps its volume ratio J but drops shear and plastic history, so the new phase
+//!   starts from rest in its current shape (water freezes into unstressed ice)
+//!
+//! **CONSERVATION**: Enthalpy m·h is unchanged by the transition itself; momentum is
+//! untouched.
+
+use bevy::prelude::*;
+use matter::phase::{LatentHeatReservoir, PhaseState, PhaseTransitionEvent, PhaseTransitions};
+
+use crate::constitutive::MpmMaterial;
+use crate::particle::MpmParticle;
+use crate::thermal::MpmThermal;
+
+/// Constitutive model per phase. `None` means no stress (ballistic particles).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct MpmPhaseMaterials {
+    pub solid: Option<MpmMaterial>,
+    pub liquid: Option<MpmMaterial>,
+    pub gas: Option<MpmMaterial>,
+}
+
+impl MpmPhaseMaterials {
+    /// Ice / water / vapor, matching the registry's `ice` and `water` entries.
+    ///
+    /// **LP-0**: Ice at game stiffness (E = 500 kPa, real ice ≈ 9 GPa). Vapor keeps
+    /// the particle mass, so it carries no stress instead of rising as steam; a gas
+    /// solver has to take it over for plumes.
+    pub fn water() -> Self {
+        Self {
+            solid: Some(MpmMaterial::fixed_corotated(5.0e5, 0.3)),
+            liquid: Some(MpmMaterial::water()),
+            gas: None,
+        }
+    }
+
+    /// Material for `phase` (plasma uses the gas entry).
+    pub fn material(&self, phase: PhaseState) -> Option<MpmMaterial> {
+        match phase {
+            PhaseState::Solid => self.solid,
+            PhaseState::Liquid => self.liquid,
+            PhaseState::Gas | PhaseState::Plasma => self.gas,
+        }
+    }
+}
+
+/// Total enthalpy of a particle (J): m·h with latent storage, or m·c_p·T without a
+/// phase table.
+pub fn particle_enthalpy(
+    particle: &MpmParticle,
+    thermal: &MpmThermal,
+    phase: Option<(&PhaseState, &LatentHeatReservoir, &PhaseTransitions)>,
+) -> f32 {
+    match phase {
+        Some((phase, reservoir, table)) => {
+            particle.mass * table.specific_enthalpy(*phase, thermal.temperature, reservoir.stored)
+        }
+        None => thermal.thermal_energy(particle.mass),
+    }
+}
+
+/// Resolve phase transitions once per fixed tick, after all substeps.
+#[allow(clippy::type_complexity)]
+pub fn update_mpm_phases(
+    mut commands: Commands,
+    mut particles: Query<(
+        Entity,
+        &mut MpmParticle,
+        &mut MpmThermal,
+        &mut PhaseState,
+        &mut LatentHeatReservoir,
+        &PhaseTransitions,
+        Option<&MpmPhaseMaterials>,
+    )>,
+    mut transitions: MessageWriter<PhaseTransitionEvent>,
+) {
+    for (entity, mut particle, mut thermal, mut phase, mut reservoir, table, materials) in
+        particles.iter_mut()
+    {
+        // Heat transport must have used this phase's c_p for the enthalpy to add up
+        thermal.specific_heat = table.specific_heat(*phase);
+        let (mut next_phase, mut temperature, mut next_reservoir) =
+            (*phase, thermal.temperature, *reservoir);
+        let transition = table.update(&mut next_phase, &mut temperature, &mut next_reservoir);
+        thermal.temperature = temperature;
+        if next_reservoir != *reservoir {
+            *reservoir = next_reservoir;
+        }
+
+        let Some((from, to)) = transition else {
+            continue;
+        };
+        *phase = to;
+        thermal.specific_heat = table.specific_heat(to);
+
+        let j = particle.volume_ratio().max(1e-3);
+        particle.deformation_gradient = Mat2::IDENTITY * j.sqrt();
+        particle.plastic_volume_ratio = 1.0;
+        if let Some(materials) = materials {
+            match materials.material(to) {
+                Some(material) => commands.entity(entity).insert(material),
+                None => commands.entity(entity).remove::<MpmMaterial>(),
+            };
+        }
+
+        transitions.write(PhaseTransitionEvent {
+            entity,
+            from,
+            to,
+            temperature,
+            latent_heat: particle.mass * table.latent_heat_released(from, to),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MPMPlugin;
+    use bevy::time::TimeUpdateStrategy;
+    use matter::material::{MaterialId, MaterialRegistry};
+    use std::time::Duration;
+
+    const SPACING: f32 = 0.05;
+
+    #[derive(Resource, Default)]
+    struct Transitions(Vec<PhaseTransitionEvent>);
+
+    fn collect_transitions(
+        mut reader: MessageReader<PhaseTransitionEvent>,
+        mut transitions: ResMut<Transitions>,
+    ) {
+        transitions.0.extend(reader.read().copied());
+    }
+
+    /// 8×8 block of water at `water_temperature` touching an 8×8 rock block.
+    /// Conductivities are scaled up so the exchange happens within a second.
+    fn water_next_to_rock(water_temperature: f32, rock_temperature: f32) -> App {
+        let tick = Duration::from_millis(10);
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, MPMPlugin))
+            .insert_resource(Time::<Fixed>::from_duration(tick))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(tick))
+            .init_resource::<Transitions>()
+            .add_systems(FixedPostUpdate, collect_transitions);
+        app.update();
+
+        let water = PhaseTransitions::water();
+        let water_phase = water.phase_at(water_temperature);
+        for j in 0..8 {
+            for i in 0..16 {
+                let position = Vec2::new(6.0 + i as f32 * SPACING, 6.0 + j as f32 * SPACING);
+                if i < 8 {
+                    app.world_mut().spawn((
+                        MpmParticle::from_density(position, 1000.0, SPACING * SPACING),
+                        MpmThermal::new(water_temperature, water.specific_heat(water_phase), 2.0e5),
+                        MpmPhaseMaterials::water().material(water_phase).unwrap(),
+                        MpmPhaseMaterials::water(),
+                        water_phase,
+                        water,
+                    ));
+                } else {
+                    app.world_mut().spawn((
+                        MpmParticle::from_density(position, 2700.0, SPACING * SPACING),
+                        MpmThermal::new(rock_temperature, 790.0, 2.0e5),
+                    ));
+                }
+            }
+        }
+        app
+    }
+
+    fn total_enthalpy(app: &mut App) -> f32 {
+        let mut query = app.world_mut().query::<(
+            &MpmParticle,
+            &MpmThermal,
+            Option<(&PhaseState, &LatentHeatReservoir, &PhaseTransitions)>,
+        )>();
+        query
+            .iter(app.world())
+            .map(|(particle, thermal, phase)| particle_enthalpy(particle, thermal, phase))
+            .sum()
+    }
+
+    fn count_phase(app: &mut App, phase: PhaseState) -> usize {
+        let mut query = app.world_mut().query::<&PhaseState>();
+        query.iter(app.world()).filter(|p| **p == phase).count()
+    }
+
+    #[test]
+    fn test_water_phases_match_registry() {
+        let registry = MaterialRegistry::default();
+        let model =
+            |name: &str| MpmMaterial::from_material(registry.get(MaterialId::new(name)).unwrap());
+        let phases = MpmPhaseMaterials::water();
+        assert_eq!(phases.solid, model("ice"));
+        assert_eq!(phases.liquid, model("water"));
+    }
+
+    #[test]
+    fn test_water_freezes_into_elastic_solid() {
+        let mut app = water_next_to_rock(274.15, 20.0);
+        let before = total_enthalpy(&mut app);
+
+        for _ in 0..100 {
+            app.update();
+        }
+
+        assert!(
+            count_phase(&mut app, PhaseState::Solid) > 0,
+            "no ice formed"
+        );
+        let mut query = app.world_mut().query::<(&PhaseState, &MpmMaterial)>();
+        for (phase, material) in query.iter(app.world()) {
+            match phase {
+                PhaseState::Solid => assert!(matches!(material, MpmMaterial::FixedCorotated(_))),
+                _ => assert!(matches!(material, MpmMaterial::Fluid(_))),
+            }
+        }
+        let after = total_enthalpy(&mut app);
+        assert!(
+            (after - before).abs() < 1e-4 * before,
+            "{} -> {}",
+            before,
+            after
+        );
+    }
+
+    #[test]
+    fn test_water_boils_into_stress_free_vapor() {
+        let mut app = water_next_to_rock(372.15, 3000.0);
+        let before = total_enthalpy(&mut app);
+
+        for _ in 0..100 {
+            app.update();
+        }
+
+        let vapor = count_phase(&mut app, PhaseState::Gas);
+        assert!(vapor > 0, "nothing boiled");
+        let transitions = &app.world().resource::<Transitions>().0;
+        assert_eq!(transitions.len(), vapor);
+        // Boiling absorbs L_v for every particle: m = 1000 kg/m³ · 0.05² m²
+        for event in transitions {
+            assert!((event.latent_heat + 2.5 * 2.257e6).abs() < 1.0);
+        }
+        let mut query = app
+            .world_mut()
+            .query_filtered::<&PhaseState, With<MpmMaterial>>();
+        assert!(query.iter(app.world()).all(|p| *p != PhaseState::Gas));
+        let after = total_enthalpy(&mut app);
+        assert!(
+            (after - before).abs() < 1e-4 * before,
+            "{} -> {}",
+            before,
+            after
+        );
+    }
+}
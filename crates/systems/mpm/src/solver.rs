@@ -1 +1,609 @@
+//! MLS-MPM time stepping.
+//!
+//! Each `FixedUpdate` tick picks a substep count (see [`plan_mpm_substeps`]) and runs
+//! the [`MpmSubstep`] schedule that many times:
+//! 1. Clear grid
+//! 2. P2G: scatter mass, momentum, stress and heat (`MpmSubstepSet::ParticleToGrid`)
+//! 3. Grid update: v = p/m, gravity, boundaries, implicit heat diffusion
+//!    (`MpmSubstepSet::GridUpdate`)
+//! 4. Constraint solve (position-based mode only, `MpmSubstepSet::ConstraintSolve`)
+//! 5. G2P: gather velocity and temperature, advect particles, update F
+//!    (`MpmSubstepSet::GridToParticle`)
+//!
+//! **STABILITY**: Explicit MPM needs Δt ≲ C·Δx / (c + |v|) where c = √(E/ρ) is the
+//! elastic wave speed. Stiff materials require more substeps per fixed tick, so the
+//! count is derived from that bound every tick (up to `MpmConfig::max_substeps`).
+//! [`MpmSolverKind::PositionBased`] trades the wave speed limit for a fixed number of
+//! constraint iterations (see [`crate::position_based`]); only the advection bound
+//! Δt ≲ C·Δx / |v| remains.
 
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::prelude::*;
+use bevy::tasks::ComputeTaskPool;
+use forces::core::gravity::UniformGravity;
+use forces::core::newton_laws::Velocity;
+
+use crate::constitutive::{ConstitutiveModel, MpmMaterial};
+use crate::damage::MpmDamage;
+use crate::grid::MpmGrid;
+use crate::particle::MpmParticle;
+use crate::scatter::scatter_in_blocks;
+use crate::transfer::{grid_to_particle, particle_to_grid};
+
+/// Schedule executed once per MPM substep (P2G → grid update → G2P).
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MpmSubstep;
+
+/// Stages inside the [`MpmSubstep`] schedule.
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub enum MpmSubstepSet {
+    /// Clear the grid and scatter particle state onto it
+    ParticleToGrid,
+    /// Normalize momentum, apply body forces and boundary conditions
+    GridUpdate,
+    /// Iterative constraint projection (position-based solver only)
+    ConstraintSolve,
+    /// Gather grid velocities and advect particles
+    GridToParticle,
+}
+
+/// Placement of the MPM step inside `FixedUpdate`.
+///
+/// Runs after force accumulation and before Newton integration, so grid-side results
+/// can still feed entity forces in the same tick.
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub enum MpmSet {
+    /// Run all substeps
+    Step,
+    /// Write particle positions back to `Transform`/`Velocity`
+    Sync,
+}
+
+/// Time integration scheme for the MPM step.
+///
+/// Both kinds read and write the same `MpmParticle` / `MpmGrid` data, so a scene can
+/// switch at runtime by editing `MpmConfig::solver`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+pub enum MpmSolverKind {
+    /// Explicit MLS-MPM: stress forces scattered in P2G. Cheap per substep but
+    /// conditionally stable, so stiff materials need many substeps.
+    #[default]
+    Explicit,
+    /// Position-based MPM (Lewin 2024): material response enforced by projecting the
+    /// particle deformation onto its constraint, iterated through the grid. Stable at
+    /// game timesteps with one or two substeps.
+    PositionBased,
+}
+
+/// Solver configuration.
+///
+/// **Numerical parameters** - not IRL physics.
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct MpmConfig {
+    /// Time integration scheme.
+    pub solver: MpmSolverKind,
+    /// Substeps per fixed tick (Δt_sub = Δt_fixed / substeps). With adaptive
+    /// substepping this is the minimum; the CFL bound can raise it.
+    /// **NUMERICAL STABILITY**: Raise for stiff materials or fast motion.
+    pub substeps: u32,
+    /// Derive the substep count from the CFL bound every tick.
+    pub adaptive_substeps: bool,
+    /// Upper bound on adaptive substeps per fixed tick (cost cap).
+    /// **NUMERICAL STABILITY**: When the bound is hit the substep stays at the stable
+    /// Δt and the continuum runs slower than real time for that tick
+    /// (see [`MpmSubstepLimitEvent`]).
+    pub max_substeps: u32,
+    /// Courant number C in Δt ≤ C·Δx / (c + |v|), in (0, 1].
+    /// **NUMERICAL**: ~0.3-0.5 for MLS-MPM with quadratic B-splines.
+    pub courant_number: f32,
+    /// Width of the wall band at the grid edge, in cells.
+    /// Nodes in the band get their outward velocity removed (slip walls).
+    pub boundary_cells: u32,
+    /// Grid ↔ particle constraint iterations per substep (position-based solver only).
+    /// **NUMERICAL**: More iterations = stiffer, less compressible response.
+    pub constraint_iterations: u32,
+    /// Conjugate-gradient iterations for the implicit heat solve per substep.
+    /// **NUMERICAL**: Too few leaves the solve unconverged and heat not exactly conserved.
+    pub heat_iterations: u32,
+}
+
+impl Default for MpmConfig {
+    fn default() -> Self {
+        Self {
+            solver: MpmSolverKind::Explicit,
+            substeps: 8,
+            adaptive_substeps: true,
+            max_substeps: 64,
+            courant_number: 0.4,
+            boundary_cells: 2,
+            constraint_iterations: 5,
+            heat_iterations: 50,
+        }
+    }
+}
+
+impl MpmConfig {
+    /// Position-based configuration for large scenes at the fixed game tick.
+    pub fn position_based() -> Self {
+        Self {
+            solver: MpmSolverKind::PositionBased,
+            substeps: 2,
+            ..default()
+        }
+    }
+}
+
+/// Run condition: the configured solver is `kind`.
+pub fn solver_is(kind: MpmSolverKind) -> impl FnMut(Res<MpmConfig>) -> bool + Clone {
+    move |config: Res<MpmConfig>| config.solver == kind
+}
+
+/// Timing of the substep currently being executed.
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct MpmTime {
+    /// Substep duration in seconds
+    pub dt: f32,
+    /// Index of the current substep within the fixed tick
+    pub substep: u32,
+    /// Number of substeps in the current fixed tick
+    pub substeps: u32,
+}
+
+/// Substep count and stability data chosen for the current fixed tick.
+#[derive(Resource, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Resource)]
+pub struct MpmStepPlan {
+    /// Substeps to run this tick
+    pub substeps: u32,
+    /// Substep duration (s)
+    pub dt: f32,
+    /// Largest stable substep from the CFL bound (s, infinite for an empty scene)
+    pub stable_dt: f32,
+    /// Fastest particle speed |v| (m/s)
+    pub max_speed: f32,
+    /// Fastest elastic wave speed c (m/s, explicit solver only)
+    pub max_wave_speed: f32,
+}
+
+impl MpmStepPlan {
+    /// Simulated time covered by this tick (s). Below the fixed Δt when the substep
+    /// limit was hit.
+    pub fn simulated_time(&self) -> f32 {
+        self.dt * self.substeps as f32
+    }
+}
+
+/// Sent when the CFL bound asks for more than `MpmConfig::max_substeps` substeps.
+///
+/// The tick then runs `substeps` stable substeps and the continuum falls behind the
+/// fixed clock by `frame_dt - simulated_dt` (slow motion instead of an explosion).
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct MpmSubstepLimitEvent {
+    /// Substeps the CFL bound asked for
+    pub required: u32,
+    /// Substeps actually run (the configured maximum)
+    pub substeps: u32,
+    /// Fixed tick duration (s)
+    pub frame_dt: f32,
+    /// Simulated time actually covered (s)
+    pub simulated_dt: f32,
+}
+
+/// Elastic (P-wave) speed of a material point, c = √((λ + 2μ) / ρ) (m/s).
+///
+/// Uses the current moduli and density, so hardened snow and compressed fluid are
+/// accounted for. Fluids have μ = 0, giving the sound speed √(K/ρ).
+pub fn wave_speed(material: &MpmMaterial, particle: &MpmParticle) -> f32 {
+    let moduli = material.constraint_moduli(particle);
+    let density = particle.mass / particle.current_volume().max(f32::EPSILON);
+    ((moduli.y + 2.0 * moduli.x).max(0.0) / density).sqrt()
+}
+
+/// Pick the substep count for this tick from the CFL bound Δt ≤ C·Δx / (c + |v|).
+///
+/// Runs before [`run_mpm_substeps`]. With `adaptive_substeps` off the configured
+/// count is used as-is.
+pub fn plan_mpm_substeps(
+    time: Res<Time>,
+    config: Res<MpmConfig>,
+    grid: Res<MpmGrid>,
+    particles: Query<(&MpmParticle, Option<&MpmMaterial>)>,
+    mut plan: ResMut<MpmStepPlan>,
+    mut limits: MessageWriter<MpmSubstepLimitEvent>,
+) {
+    let frame_dt = time.delta_secs();
+    let minimum = config.substeps.max(1);
+    if !config.adaptive_substeps || frame_dt <= 0.0 {
+        *plan = MpmStepPlan {
+            substeps: minimum,
+            dt: frame_dt / minimum as f32,
+            stable_dt: f32::INFINITY,
+            ..default()
+        };
+        return;
+    }
+
+    let explicit = config.solver == MpmSolverKind::Explicit;
+    let (mut max_speed, mut max_wave_speed, mut max_signal) = (0.0f32, 0.0f32, 0.0f32);
+    for (particle, material) in particles.iter() {
+        let speed = particle.velocity.length();
+        let wave = material
+            .filter(|_| explicit)
+            .map(|material| wave_speed(material, particle))
+            .unwrap_or(0.0);
+        max_speed = max_speed.max(speed);
+        max_wave_speed = max_wave_speed.max(wave);
+        max_signal = max_signal.max(speed + wave);
+    }
+
+    let courant = config.courant_number.clamp(0.01, 1.0);
+    let stable_dt = if max_signal > 0.0 {
+        courant * grid.cell_size() / max_signal
+    } else {
+        f32::INFINITY
+    };
+    let required = (frame_dt / stable_dt).ceil().max(1.0) as u32;
+    let maximum = config.max_substeps.max(minimum);
+    let substeps = required.clamp(minimum, maximum);
+    let dt = (frame_dt / substeps as f32).min(stable_dt);
+
+    *plan = MpmStepPlan {
+        substeps,
+        dt,
+        stable_dt,
+        max_speed,
+        max_wave_speed,
+    };
+    if required > maximum {
+        limits.write(MpmSubstepLimitEvent {
+            required,
+            substeps,
+            frame_dt,
+            simulated_dt: plan.simulated_time(),
+        });
+    }
+}
+
+/// Drive the [`MpmSubstep`] schedule for the current fixed tick.
+pub fn run_mpm_substeps(world: &mut World) {
+    let plan = *world.resource::<MpmStepPlan>();
+    if plan.dt <= 0.0 {
+        return;
+    }
+
+    for substep in 0..plan.substeps {
+        *world.resource_mut::<MpmTime>() = MpmTime {
+            dt: plan.dt,
+            substep,
+            substeps: plan.substeps,
+        };
+        world.run_schedule(MpmSubstep);
+    }
+}
+
+/// Reset grid accumulators before P2G.
+pub fn clear_grid(mut grid: ResMut<MpmGrid>) {
+    grid.clear();
+}
+
+/// P2G: scatter every particle (with its constitutive stress) to the grid.
+///
+/// The position-based solver scatters without stress; its material response comes
+/// from the constraint solve instead.
+/// Runs on the compute task pool; the block reduction in [`scatter_in_blocks`] keeps
+/// the floating-point sums bit-identical for any thread count.
+pub fn scatter_particles_to_grid(
+    time: Res<MpmTime>,
+    config: Res<MpmConfig>,
+    mut grid: ResMut<MpmGrid>,
+    particles: Query<(&MpmParticle, Option<&MpmMaterial>, Option<&MpmDamage>)>,
+) {
+    let explicit = config.solver == MpmSolverKind::Explicit;
+    let items: Vec<_> = particles.iter().collect();
+    scatter_in_blocks(
+        &mut grid,
+        ComputeTaskPool::get(),
+        &items,
+        |(particle, _, _)| particle.position,
+        |block, (particle, material, damage)| {
+            let mut stress = material
+                .filter(|_| explicit)
+                .map(|material| material.kirchhoff_stress(particle))
+                .unwrap_or(Mat2::ZERO);
+            if let Some(damage) = damage {
+                stress = damage.degrade_stress(stress, particle.volume_ratio());
+            }
+            particle_to_grid(block, particle, stress, time.dt);
+        },
+    );
+}
+
+/// Grid update: momentum → velocity, uniform gravity, slip walls at the grid edge.
+///
+/// **PHYSICS**: vᵢ = (mv)ᵢ/mᵢ + Δt·g. Gravity comes from `UniformGravity` when the
+/// gravity plugin is present, so continuum and entity bodies fall identically.
+pub fn update_grid_velocities(
+    time: Res<MpmTime>,
+    config: Res<MpmConfig>,
+    gravity: Option<Res<UniformGravity>>,
+    mut grid: ResMut<MpmGrid>,
+) {
+    let dt = time.dt;
+    let g = gravity
+        .map(|g| g.acceleration.truncate())
+        .unwrap_or(Vec2::ZERO);
+    let dims = grid.dims().as_ivec2();
+    let band = config.boundary_cells as i32;
+
+    for (coord, node) in grid.iter_nodes_mut() {
+        if !node.is_active() {
+            node.velocity = Vec2::ZERO;
+            continue;
+        }
+
+        let v = node.momentum / node.mass + g * dt;
+        node.velocity = apply_wall_boundaries(coord, v, dims, band);
+    }
+}
+
+/// Slip walls: remove the velocity component pointing out of the domain for nodes
+/// inside the boundary band.
+#[inline]
+pub fn apply_wall_boundaries(coord: IVec2, mut velocity: Vec2, dims: IVec2, band: i32) -> Vec2 {
+    if coord.x < band && velocity.x < 0.0 || coord.x >= dims.x - band && velocity.x > 0.0 {
+        velocity.x = 0.0;
+    }
+    if coord.y < band && velocity.y < 0.0 || coord.y >= dims.y - band && velocity.y > 0.0 {
+        velocity.y = 0.0;
+    }
+    velocity
+}
+
+/// G2P: gather velocities, advect particles, update deformation gradients and apply
+/// plastic return mapping.
+///
+/// Positions are clamped to stay one stencil inside the grid so no mass is lost
+/// off the edge (numerical guard, not physics).
+pub fn gather_grid_to_particles(
+    time: Res<MpmTime>,
+    grid: Res<MpmGrid>,
+    mut particles: Query<(&mut MpmParticle, Option<&MpmMaterial>)>,
+) {
+    let (min, max) = grid.bounds();
+    let margin = Vec2::splat(grid.cell_size());
+
+    particles
+        .par_iter_mut()
+        .for_each(|(mut particle, material)| {
+            grid_to_particle(&grid, &mut particle, time.dt);
+            if let Some(material) = material {
+                material.project_plasticity(&mut particle);
+            }
+            particle.position = particle.position.clamp(min + margin, max - margin);
+        });
+}
+
+/// Write particle state back to `Transform` (x, y only) and optional `Velocity`.
+pub fn sync_particle_transforms(
+    mut particles: Query<(&MpmParticle, &mut Transform, Option<&mut Velocity>)>,
+) {
+    for (particle, mut transform, velocity) in particles.iter_mut() {
+        transform.translation.x = particle.position.x;
+        transform.translation.y = particle.position.y;
+        if let Some(mut velocity) = velocity {
+            velocity.linvel = particle.velocity.extend(0.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MPMPlugin;
+    use bevy::time::TimeUpdateStrategy;
+    use forces::core::newton_laws::{IntegratorKind, NewtonLawsPlugin, PreviousAcceleration};
+    use std::time::Duration;
+
+    const TICK: Duration = Duration::from_millis(10);
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, NewtonLawsPlugin, MPMPlugin))
+            .insert_resource(UniformGravity::default())
+            .insert_resource(Time::<Fixed>::from_duration(TICK))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(TICK));
+        // First update only initializes the clocks (zero delta)
+        app.update();
+        app
+    }
+
+    fn run_fixed_ticks(app: &mut App, ticks: u32) {
+        for _ in 0..ticks {
+            app.update();
+        }
+    }
+
+    #[test]
+    fn test_particle_falls_under_uniform_gravity() {
+        let mut app = test_app();
+        let start = Vec2::new(6.4, 10.0);
+        let entity = app
+            .world_mut()
+            .spawn(MpmParticle::new(start, 0.01, 1e-4))
+            .id();
+
+        run_fixed_ticks(&mut app, 10);
+
+        let particle = app.world().get::<MpmParticle>(entity).unwrap();
+        let elapsed = 0.1;
+        // Free fall: v = g·t
+        assert!((particle.velocity.y + 9.81 * elapsed).abs() < 1e-2);
+        assert!(particle.position.y < start.y);
+        let transform = app.world().get::<Transform>(entity).unwrap();
+        assert_eq!(transform.translation.truncate(), particle.position);
+    }
+
+    #[test]
+    fn test_particles_are_not_double_integrated() {
+        // A particle carrying Newton components must only move by MPM's amount.
+        let mut app = test_app();
+        let reference = app
+            .world_mut()
+            .spawn(MpmParticle::new(Vec2::new(3.0, 6.0), 0.01, 1e-4))
+            .id();
+        let tagged = app
+            .world_mut()
+            .spawn((
+                MpmParticle::new(Vec2::new(9.0, 6.0), 0.01, 1e-4),
+                Velocity::default(),
+                PreviousAcceleration::default(),
+            ))
+            .id();
+
+        run_fixed_ticks(&mut app, 5);
+
+        assert_eq!(
+            app.world().get::<IntegratorKind>(tagged),
+            Some(&IntegratorKind::External)
+        );
+        let height = |entity| app.world().get::<Transform>(entity).unwrap().translation.y;
+        let (drop_reference, drop_tagged) = (6.0 - height(reference), 6.0 - height(tagged));
+        assert!((drop_reference - drop_tagged).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_floor_stops_falling_block() {
+        let mut app = test_app();
+        let dx = app.world().resource::<MpmGrid>().cell_size();
+        let spacing = 0.5 * dx;
+        let mut entities = Vec::new();
+        for j in 0..8 {
+            for i in 0..8 {
+                let position = Vec2::new(6.0 + i as f32 * spacing, 0.6 + j as f32 * spacing);
+                entities.push(
+                    app.world_mut()
+                        .spawn((
+                            MpmParticle::from_density(position, 1000.0, spacing * spacing),
+                            MpmMaterial::neo_hookean(5.0e4, 0.3),
+                        ))
+                        .id(),
+                );
+            }
+        }
+
+        // Elastic block bounces a few times before the grid transfers damp it out
+        run_fixed_ticks(&mut app, 300);
+
+        let floor = app.world().resource::<MpmGrid>().origin().y;
+        for entity in entities {
+            let particle = app.world().get::<MpmParticle>(entity).unwrap();
+            assert!(particle.position.is_finite());
+            assert!(particle.position.y > floor);
+            assert!(
+                particle.velocity.length() < 1.0,
+                "block should come to rest"
+            );
+        }
+    }
+
+    #[test]
+    fn test_water_column_spreads() {
+        let mut app = test_app();
+        let spacing = 0.05;
+        let mut entities = Vec::new();
+        for j in 0..16 {
+            for i in 0..8 {
+                let position = Vec2::new(6.0 + i as f32 * spacing, 0.25 + j as f32 * spacing);
+                entities.push(
+                    app.world_mut()
+                        .spawn((
+                            MpmParticle::from_density(position, 1000.0, spacing * spacing),
+                            MpmMaterial::water(),
+                        ))
+                        .id(),
+                );
+            }
+        }
+
+        run_fixed_ticks(&mut app, 100);
+
+        let (mut min_x, mut max_x) = (f32::MAX, f32::MIN);
+        for &entity in &entities {
+            let particle = app.world().get::<MpmParticle>(entity).unwrap();
+            assert!(particle.position.is_finite());
+            min_x = min_x.min(particle.position.x);
+            max_x = max_x.max(particle.position.x);
+        }
+        // A fluid cannot hold its shape: the 0.35 m column must slump sideways
+        assert!(max_x - min_x > 0.7, "column width {}", max_x - min_x);
+    }
+
+    #[derive(Resource, Default)]
+    struct Limits(Vec<MpmSubstepLimitEvent>);
+
+    fn collect_limits(mut reader: MessageReader<MpmSubstepLimitEvent>, mut out: ResMut<Limits>) {
+        out.0.extend(reader.read().copied());
+    }
+
+    fn spawn_block(app: &mut App, material: MpmMaterial) -> Vec<Entity> {
+        let spacing = 0.05;
+        let mut entities = Vec::new();
+        for j in 0..8 {
+            for i in 0..8 {
+                let position = Vec2::new(6.0 + i as f32 * spacing, 0.6 + j as f32 * spacing);
+                entities.push(
+                    app.world_mut()
+                        .spawn((
+                            MpmParticle::from_density(position, 1000.0, spacing * spacing),
+                            material,
+                        ))
+                        .id(),
+                );
+            }
+        }
+        entities
+    }
+
+    #[test]
+    fn test_stiff_material_raises_substep_count() {
+        let mut app = test_app();
+        spawn_block(&mut app, MpmMaterial::wood());
+        app.update();
+
+        // E = 10 MPa, ν = 0.3: λ + 2μ = E(1-ν)/((1+ν)(1-2ν)) ≈ 13.5 MPa → c ≈ 116 m/s
+        let plan = *app.world().resource::<MpmStepPlan>();
+        assert!((plan.max_wave_speed - 116.0).abs() < 1.0, "{:?}", plan);
+        let expected = (TICK.as_secs_f32() * plan.max_wave_speed / (0.4 * 0.1)).ceil() as u32;
+        assert!(plan.substeps >= expected && plan.substeps <= expected + 1);
+        assert!(plan.dt <= plan.stable_dt);
+        assert!((plan.simulated_time() - TICK.as_secs_f32()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_substep_limit_keeps_stiff_block_stable() {
+        let mut app = test_app();
+        app.insert_resource(MpmConfig {
+            max_substeps: 10,
+            ..default()
+        })
+        .init_resource::<Limits>()
+        .add_systems(FixedPostUpdate, collect_limits);
+        let entities = spawn_block(&mut app, MpmMaterial::wood());
+
+        run_fixed_ticks(&mut app, 50);
+
+        let limits = &app.world().resource::<Limits>().0;
+        assert_eq!(limits.len(), 50);
+        for limit in limits {
+            assert_eq!(limit.substeps, 10);
+            assert!(limit.required > 10);
+            assert!(limit.simulated_dt < limit.frame_dt);
+        }
+        for entity in entities {
+            let particle = app.world().get::<MpmParticle>(entity).unwrap();
+            assert!(particle.position.is_finite());
+            assert!(particle.velocity.length() < 5.0, "{:?}", particle.velocity);
+        }
+    }
+}
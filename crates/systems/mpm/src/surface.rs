@@ -0,0 +1,532 @@
+//! Surface reconstruction: MPM particles → iso-contours → 2D meshes.
+//!
+//! 1. Rasterize particle mass onto a [`DensityField`] (quadratic B-spline splat, the
+//!    same kernel as P2G)
+//! 2. Marching squares on the field at `MpmSurfaceSettings::iso_density`
+//! 3. One [`SurfaceBody`] per connected region: closed contours (outer boundary
+//!    counter-clockwise, holes clockwise) plus a fill triangulation
+//!
+//! [`extract_surfaces`] is headless, so contours can be tested without a GPU.
+//! [`MpmSurfacePlugin`] turns the bodies into `Mesh2d` entities every frame.
+//!
+//! **NUMERICAL**: Ambiguous saddle cells are resolved with the cell-center average, so
+//! two drops touching at a corner merge only when the density between them is high.
+
+use std::collections::HashMap;
+
+use bevy::asset::RenderAssetUsages;
+use bevy::mesh::{Indices, PrimitiveTopology};
+use bevy::prelude::*;
+
+use crate::particle::MpmParticle;
+use crate::transfer::QuadraticStencil;
+
+/// Marker: include this particle in surface reconstruction.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct MpmSurfaceSource;
+
+/// Surface reconstruction parameters.
+///
+/// **Numerical parameters** - not IRL physics.
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct MpmSurfaceSettings {
+    /// Density field spacing (m). About the particle spacing: finer shows gaps
+    /// between particles, coarser rounds off detail.
+    pub resolution: f32,
+    /// Density at which the surface is drawn (kg/m³). Half the rest density traces
+    /// the edge of the material the particles stand for.
+    pub iso_density: f32,
+    /// Fill color of the generated meshes
+    pub color: Color,
+}
+
+impl Default for MpmSurfaceSettings {
+    fn default() -> Self {
+        Self {
+            resolution: 0.05,
+            iso_density: 500.0,
+            color: Color::srgba(0.2, 0.45, 0.9, 0.85),
+        }
+    }
+}
+
+/// Mass density sampled on a regular lattice (kg/m³).
+#[derive(Debug, Clone, PartialEq)]
+pub struct DensityField {
+    /// World position of sample (0, 0) (m)
+    pub origin: Vec2,
+    /// Sample spacing (m)
+    pub cell_size: f32,
+    /// Samples along each axis
+    pub dims: UVec2,
+    /// Density per sample, row-major (kg/m³)
+    pub values: Vec<f32>,
+}
+
+impl DensityField {
+    /// Splat `(position, mass)` pairs onto a field covering their bounding box plus
+    /// an empty margin, so every contour closes. `None` without particles.
+    pub fn from_particles(
+        particles: impl IntoIterator<Item = (Vec2, f32)>,
+        cell_size: f32,
+    ) -> Option<Self> {
+        let particles: Vec<(Vec2, f32)> = particles.into_iter().collect();
+        if particles.is_empty() {
+            return None;
+        }
+        let h = cell_size.max(1e-4);
+        let (min, max) = particles
+            .iter()
+            .fold((Vec2::MAX, Vec2::MIN), |(min, max), (p, _)| {
+                (min.min(*p), max.max(*p))
+            });
+        // Kernel reaches 1.5 cells; one more empty sample keeps the border outside
+        let margin = Vec2::splat(3.0 * h);
+        let origin = ((min - margin) / h).floor() * h;
+        let dims = ((max + margin - origin) / h).ceil().as_uvec2() + UVec2::ONE;
+        let mut field = Self {
+            origin,
+            cell_size: h,
+            dims,
+            values: vec![0.0; (dims.x * dims.y) as usize],
+        };
+
+        let inv_area = 1.0 / (h * h);
+        for (position, mass) in particles {
+            let stencil = QuadraticStencil::new((position - origin) / h);
+            stencil.for_each(|coord, weight, _| {
+                if let Some(index) = field.index(coord) {
+                    field.values[index] += weight * mass * inv_area;
+                }
+            });
+        }
+        Some(field)
+    }
+
+    #[inline]
+    fn index(&self, coord: IVec2) -> Option<usize> {
+        if coord.cmplt(IVec2::ZERO).any() || coord.cmpge(self.dims.as_ivec2()).any() {
+            return None;
+        }
+        Some((coord.y as u32 * self.dims.x + coord.x as u32) as usize)
+    }
+
+    /// Density at a sample, 0 outside the field.
+    pub fn sample(&self, coord: IVec2) -> f32 {
+        self.index(coord).map_or(0.0, |i| self.values[i])
+    }
+
+    /// World position of a sample (m).
+    pub fn position(&self, coord: IVec2) -> Vec2 {
+        self.origin + coord.as_vec2() * self.cell_size
+    }
+}
+
+/// One connected region of the reconstructed surface.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SurfaceBody {
+    /// Closed polylines (last point connects to the first). The outer boundary runs
+    /// counter-clockwise, holes clockwise.
+    pub contours: Vec<Vec<Vec2>>,
+    /// Fill triangles, counter-clockwise
+    pub triangles: Vec<[Vec2; 3]>,
+}
+
+impl SurfaceBody {
+    /// Enclosed area (m²).
+    pub fn area(&self) -> f32 {
+        self.triangles
+            .iter()
+            .map(|[a, b, c]| 0.5 * (*b - *a).perp_dot(*c - *a))
+            .sum()
+    }
+
+    /// Triangle-list mesh in the XY plane (world coordinates, z = 0).
+    pub fn to_mesh(&self) -> Mesh {
+        let positions: Vec<[f32; 3]> = self
+            .triangles
+            .iter()
+            .flatten()
+            .map(|p| [p.x, p.y, 0.0])
+            .collect();
+        let count = positions.len();
+        let uvs: Vec<[f32; 2]> = positions.iter().map(|p| [p[0], p[1]]).collect();
+        Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        )
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, vec![[0.0, 0.0, 1.0]; count])
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+        .with_inserted_indices(Indices::U32((0..count as u32).collect()))
+    }
+}
+
+/// Crossing of the iso-line with a lattice edge: `(lower node, vertical edge)`.
+type EdgeId = (IVec2, bool);
+
+#[derive(Clone, Copy)]
+enum Vertex {
+    Corner(IVec2),
+    Cross(EdgeId, Vec2),
+}
+
+/// Union-find root with path halving.
+fn find(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+    i
+}
+
+/// Marching squares on `field` at `iso`: one [`SurfaceBody`] per connected region, in
+/// row-major order of their lowest-left sample.
+pub fn extract_surfaces(field: &DensityField, iso: f32) -> Vec<SurfaceBody> {
+    let dims = field.dims.as_ivec2();
+    let inside = |c: IVec2| field.sample(c) >= iso;
+    let node = |c: IVec2| (c.y * dims.x + c.x) as usize;
+
+    // Connected components of inside samples (4-neighbours, saddles decided below)
+    let mut parents: Vec<usize> = (0..(dims.x * dims.y) as usize).collect();
+    let union = |parents: &mut Vec<usize>, a: IVec2, b: IVec2| {
+        let (ra, rb) = (find(parents, node(a)), find(parents, node(b)));
+        parents[ra.max(rb)] = ra.min(rb);
+    };
+    for j in 0..dims.y {
+        for i in 0..dims.x {
+            let c = IVec2::new(i, j);
+            if !inside(c) {
+                continue;
+            }
+            for n in [c + IVec2::X, c + IVec2::Y] {
+                if n.x < dims.x && n.y < dims.y && inside(n) {
+                    union(&mut parents, c, n);
+                }
+            }
+        }
+    }
+
+    let crossing = |a: IVec2, b: IVec2| -> Vertex {
+        let (va, vb) = (field.sample(a), field.sample(b));
+        let t = ((iso - va) / (vb - va)).clamp(0.0, 1.0);
+        let (pa, pb) = (field.position(a), field.position(b));
+        let lower = a.min(b);
+        Vertex::Cross((lower, a.x == b.x), pa + (pb - pa) * t)
+    };
+
+    let mut bodies: Vec<SurfaceBody> = Vec::new();
+    let mut body_of_root: HashMap<usize, usize> = HashMap::new();
+    // Per body: start edge → (end edge, start point)
+    let mut segments: Vec<HashMap<EdgeId, (EdgeId, Vec2)>> = Vec::new();
+    let mut segment_order: Vec<Vec<EdgeId>> = Vec::new();
+
+    for j in 0..dims.y - 1 {
+        for i in 0..dims.x - 1 {
+            let base = IVec2::new(i, j);
+            // Counter-clockwise corners: bottom-left, bottom-right, top-right, top-left
+            let corners = [base, base + IVec2::X, base + IVec2::ONE, base + IVec2::Y];
+            let flags = corners.map(inside);
+            let count = flags.iter().filter(|f| **f).count();
+            if count == 0 {
+                continue;
+            }
+
+            let mut ring = Vec::with_capacity(8);
+            for k in 0..4 {
+                let (a, b) = (corners[k], corners[(k + 1) % 4]);
+                if flags[k] {
+                    ring.push(Vertex::Corner(a));
+                }
+                if flags[k] != flags[(k + 1) % 4] {
+                    ring.push(crossing(a, b));
+                }
+            }
+
+            let saddle = count == 2 && flags[0] == flags[2];
+            let connected =
+                !saddle || corners.iter().map(|c| field.sample(*c)).sum::<f32>() * 0.25 >= iso;
+            let pieces: Vec<Vec<Vertex>> = if connected {
+                if saddle {
+                    let k = if flags[0] { 0 } else { 1 };
+                    union(&mut parents, corners[k], corners[k + 2]);
+                }
+                vec![ring]
+            } else {
+                // Two separate corners: [corner, crossing after, crossing before]
+                (0..ring.len())
+                    .filter(|&r| matches!(ring[r], Vertex::Corner(_)))
+                    .map(|r| {
+                        let after = ring[(r + 1) % ring.len()];
+                        let before = ring[(r + ring.len() - 1) % ring.len()];
+                        vec![ring[r], after, before]
+                    })
+                    .collect()
+            };
+
+            for piece in pieces {
+                let corner = piece
+                    .iter()
+                    .find_map(|v| match v {
+                        Vertex::Corner(c) => Some(*c),
+                        _ => None,
+                    })
+                    .unwrap_or(base);
+                let root = find(&mut parents, node(corner));
+                let body = *body_of_root.entry(root).or_insert_with(|| {
+                    bodies.push(SurfaceBody::default());
+                    segments.push(HashMap::new());
+                    segment_order.push(Vec::new());
+                    bodies.len() - 1
+                });
+
+                let points: Vec<Vec2> = piece
+                    .iter()
+                    .map(|v| match v {
+                        Vertex::Corner(c) => field.position(*c),
+                        Vertex::Cross(_, p) => *p,
+                    })
+                    .collect();
+                for t in 1..points.len() - 1 {
+                    bodies[body]
+                        .triangles
+                        .push([points[0], points[t], points[t + 1]]);
+                }
+                // Consecutive crossings are iso-line segments with the inside on the left
+                for r in 0..piece.len() {
+                    if let (Vertex::Cross(from, p), Vertex::Cross(to, _)) =
+                        (piece[r], piece[(r + 1) % piece.len()])
+                    {
+                        segments[body].insert(from, (to, p));
+                        segment_order[body].push(from);
+                    }
+                }
+            }
+        }
+    }
+
+    // Saddle unions can merge bodies found earlier; fold them into the survivor
+    let mut merged: Vec<Option<usize>> = vec![None; bodies.len()];
+    let mut roots: Vec<(usize, usize)> = body_of_root.into_iter().collect();
+    roots.sort_by_key(|(_, body)| *body);
+    let mut owner: HashMap<usize, usize> = HashMap::new();
+    for (root, body) in roots {
+        let root = find(&mut parents, root);
+        match owner.get(&root) {
+            Some(&first) => merged[body] = Some(first),
+            None => {
+                owner.insert(root, body);
+            }
+        }
+    }
+    for body in (0..bodies.len()).rev() {
+        if let Some(target) = merged[body] {
+            let triangles = std::mem::take(&mut bodies[body].triangles);
+            bodies[target].triangles.extend(triangles);
+            let moved = std::mem::take(&mut segments[body]);
+            segments[target].extend(moved);
+            let order = std::mem::take(&mut segment_order[body]);
+            segment_order[target].extend(order);
+        }
+    }
+
+    let mut result = Vec::new();
+    for (body, mut surface) in bodies.into_iter().enumerate() {
+        if merged[body].is_some() {
+            continue;
+        }
+        let links = &mut segments[body];
+        for &start in &segment_order[body] {
+            let mut contour = Vec::new();
+            let mut edge = start;
+            while let Some((next, point)) = links.remove(&edge) {
+                contour.push(point);
+                edge = next;
+            }
+            if contour.len() >= 3 {
+                surface.contours.push(contour);
+            }
+        }
+        result.push(surface);
+    }
+    result
+}
+
+/// Headless convenience: all contours of the particles' surface as closed polylines.
+pub fn contour_polylines(
+    particles: impl IntoIterator<Item = (Vec2, f32)>,
+    settings: &MpmSurfaceSettings,
+) -> Vec<Vec<Vec2>> {
+    DensityField::from_particles(particles, settings.resolution)
+        .map(|field| extract_surfaces(&field, settings.iso_density))
+        .unwrap_or_default()
+        .into_iter()
+        .flat_map(|body| body.contours)
+        .collect()
+}
+
+/// Reconstructed surface bodies of the current frame (resource).
+#[derive(Resource, Debug, Clone, Default)]
+pub struct MpmSurfaces {
+    pub bodies: Vec<SurfaceBody>,
+}
+
+/// Rebuild [`MpmSurfaces`] from every [`MpmSurfaceSource`] particle.
+pub fn reconstruct_mpm_surfaces(
+    settings: Res<MpmSurfaceSettings>,
+    particles: Query<&MpmParticle, With<MpmSurfaceSource>>,
+    mut surfaces: ResMut<MpmSurfaces>,
+) {
+    surfaces.bodies = DensityField::from_particles(
+        particles.iter().map(|p| (p.position, p.mass)),
+        settings.resolution,
+    )
+    .map(|field| extract_surfaces(&field, settings.iso_density))
+    .unwrap_or_default();
+}
+
+/// Mesh entity showing one surface body.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MpmSurfaceMesh {
+    /// Index into `MpmSurfaces::bodies`
+    pub body: usize,
+}
+
+/// Keep one `Mesh2d` entity per surface body, rewriting the meshes in place.
+pub fn sync_surface_meshes(
+    mut commands: Commands,
+    settings: Res<MpmSurfaceSettings>,
+    surfaces: Res<MpmSurfaces>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut material: Local<Option<Handle<ColorMaterial>>>,
+    existing: Query<(Entity, &MpmSurfaceMesh, &Mesh2d)>,
+) {
+    let material = material
+        .get_or_insert_with(|| materials.add(settings.color))
+        .clone();
+    if let Some(current) = materials.get_mut(&material)
+        && current.color != settings.color
+    {
+        current.color = settings.color;
+    }
+
+    let mut shown = vec![false; surfaces.bodies.len()];
+    for (entity, surface, mesh) in existing.iter() {
+        match surfaces.bodies.get(surface.body) {
+            Some(body) => {
+                if let Some(target) = meshes.get_mut(&mesh.0) {
+                    *target = body.to_mesh();
+                }
+                shown[surface.body] = true;
+            }
+            None => commands.entity(entity).despawn(),
+        }
+    }
+    for (index, body) in surfaces.bodies.iter().enumerate() {
+        if !shown[index] {
+            commands.spawn((
+                MpmSurfaceMesh { body: index },
+                Mesh2d(meshes.add(body.to_mesh())),
+                MeshMaterial2d(material.clone()),
+                Transform::default(),
+            ));
+        }
+    }
+}
+
+/// Renders MPM surfaces as 2D meshes. Needs the render plugins (`DefaultPlugins`).
+///
+/// Add [`MpmSurfaceSource`] to the particles that should be drawn as a surface.
+#[derive(Default)]
+pub struct MpmSurfacePlugin;
+
+impl Plugin for MpmSurfacePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MpmSurfaceSettings>()
+            .init_resource::<MpmSurfaces>()
+            .register_type::<MpmSurfaceSettings>()
+            .register_type::<MpmSurfaceSource>()
+            .add_systems(
+                PostUpdate,
+                (reconstruct_mpm_surfaces, sync_surface_meshes).chain(),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn disk(center: Vec2, radius: f32, spacing: f32) -> Vec<(Vec2, f32)> {
+        let n = (radius / spacing).ceil() as i32;
+        let mut particles = Vec::new();
+        for j in -n..=n {
+            for i in -n..=n {
+                let p = center + Vec2::new(i as f32, j as f32) * spacing;
+                if p.distance(center) <= radius {
+                    particles.push((p, 1000.0 * spacing * spacing));
+                }
+            }
+        }
+        particles
+    }
+
+    fn signed_area(contour: &[Vec2]) -> f32 {
+        (0..contour.len())
+            .map(|i| 0.5 * contour[i].perp_dot(contour[(i + 1) % contour.len()]))
+            .sum()
+    }
+
+    #[test]
+    fn test_disk_gives_one_closed_counter_clockwise_contour() {
+        let settings = MpmSurfaceSettings::default();
+        let center = Vec2::new(2.0, 3.0);
+        let particles = disk(center, 0.5, 0.05);
+        let field = DensityField::from_particles(particles.iter().copied(), 0.05).unwrap();
+        let bodies = extract_surfaces(&field, settings.iso_density);
+
+        assert_eq!(bodies.len(), 1);
+        assert_eq!(bodies[0].contours.len(), 1);
+        let contour = &bodies[0].contours[0];
+        assert!(signed_area(contour) > 0.0);
+        // Contour follows the rim of the disk
+        for p in contour {
+            let r = p.distance(center);
+            assert!((r - 0.5).abs() < 0.05, "radius {}", r);
+        }
+        // Fill and contour enclose the same area: the area the particles stand for
+        let area = particles.len() as f32 * 0.05 * 0.05;
+        assert!((bodies[0].area() - signed_area(contour)).abs() < 1e-3);
+        assert!((bodies[0].area() - area).abs() < 0.05 * area);
+
+        let mesh = bodies[0].to_mesh();
+        assert_eq!(mesh.count_vertices(), 3 * bodies[0].triangles.len());
+    }
+
+    #[test]
+    fn test_separate_drops_and_ring_hole() {
+        let settings = MpmSurfaceSettings::default();
+        let mut particles = disk(Vec2::new(1.0, 1.0), 0.3, 0.05);
+        particles.extend(disk(Vec2::new(3.0, 1.0), 0.3, 0.05));
+        // Ring: outer radius 0.6, inner 0.3
+        particles.extend(
+            disk(Vec2::new(2.0, 3.0), 0.6, 0.05)
+                .into_iter()
+                .filter(|(p, _)| p.distance(Vec2::new(2.0, 3.0)) > 0.3),
+        );
+
+        let contours = contour_polylines(particles.iter().copied(), &settings);
+        assert_eq!(contours.len(), 4);
+        let field = DensityField::from_particles(particles, settings.resolution).unwrap();
+        let bodies = extract_surfaces(&field, settings.iso_density);
+        assert_eq!(bodies.len(), 3);
+
+        let ring = bodies.iter().find(|b| b.contours.len() == 2).unwrap();
+        let areas: Vec<f32> = ring.contours.iter().map(|c| signed_area(c)).collect();
+        assert!(areas.iter().any(|a| *a > 0.0) && areas.iter().any(|a| *a < 0.0));
+        assert!((ring.area() - areas.iter().sum::<f32>()).abs() < 1e-3);
+    }
+}
@@ -9,7 +9,7 @@ use save_system::save_system::SaveSettings;
 // NOTE: Systems domain orchestrates runtime wiring. Core subsystems:
 // - AI: utility-driven agents (production-ready)
 // - Acoustics: physics-based sound (early-stage, partial wave coupling)
-// - MPM: Material Point Method solver (early-stage; needs full rewrite of basic_forces.rs for parameter exposure)
+// - MPM: Material Point Method solver (2D MLS-MPM; early-stage, parameter exposure still minimal)
 // - SaveSystem: save/load infrastructure (production-ready)
 //
 // TODO: MPM integration with matter crate -- requires universal collision physics, energy/matter ledger
@@ -51,7 +51,7 @@ impl SystemsPlugin {
         self
     }
 
-    /// Enable or disable the Material Point Method stub systems (until MPM integration is ready).
+    /// Enable or disable the Material Point Method solver.
     pub fn with_mpm(mut self, enabled: bool) -> Self {
         self.include_mpm = enabled;
         self
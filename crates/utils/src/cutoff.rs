@@ -30,6 +30,20 @@ pub fn force_switch(r: f32, r_on: f32, r_cut: f32) -> f32 {
     1.0 - 3.0 * x.powi(2) + 2.0 * x.powi(3)
 }
 
+/// Radial derivative dS/dr of [`force_switch`].
+///
+/// Needed when the switch multiplies a potential instead of a force: for
+/// U_s = U·S the consistent force is -(dU/dr·S + U·dS/dr), and U_s is the exact
+/// potential energy of the switched interaction.
+pub fn force_switch_derivative(r: f32, r_on: f32, r_cut: f32) -> f32 {
+    if r >= r_cut || r <= r_on {
+        return 0.0;
+    }
+    let width = r_cut - r_on;
+    let x = (r - r_on) / width;
+    6.0 * x * (x - 1.0) / width
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -51,4 +65,19 @@ mod tests {
         let factor = force_switch(mid, r_on, r_cut);
         assert!(factor > 0.0 && factor < 1.0);
     }
+
+    #[test]
+    fn test_force_switch_derivative() {
+        let r_on = 8.0;
+        let r_cut = 10.0;
+        let mid = (r_on + r_cut) / 2.0;
+
+        // Derivative matches a central difference and vanishes at both ends
+        let h = 1e-3;
+        let numeric =
+            (force_switch(mid + h, r_on, r_cut) - force_switch(mid - h, r_on, r_cut)) / (2.0 * h);
+        assert!((force_switch_derivative(mid, r_on, r_cut) - numeric).abs() < 1e-3);
+        assert_eq!(force_switch_derivative(r_on, r_on, r_cut), 0.0);
+        assert_eq!(force_switch_derivative(r_cut, r_on, r_cut), 0.0);
+    }
 }
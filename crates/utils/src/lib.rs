@@ -55,7 +55,7 @@ impl Plugin for UtilsPlugin {
     }
 }
 
-pub use cutoff::force_switch;
+pub use cutoff::{force_switch, force_switch_derivative};
 pub use pool::{EntityPool, Pooled};
 pub use spatial::grid::{GridCell, SpatialGrid};
 pub use spatial::unified::{
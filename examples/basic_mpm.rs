@@ -1,6 +1,112 @@
+//! Basic MLS-MPM scene: a sand castle drops onto a tilted ramp and slumps off it.
+//!
+//! Physics: MPM particles (`MpmParticle`) + Drucker-Prager sand, uniform gravity,
+//! SDF box collider with Coulomb friction (`MpmCollider`). The castle silhouette is
+//! filled with Poisson-disk particles (`PoissonDiskSampler`).
+//! Rendering: one sprite per material point; camera zoomed to the 12.8 m grid.
+//!
+//! Run: `cargo run --example basic_mpm`
+
 use bevy::prelude::*;
-//use systems::mpm::*;
+use forces::prelude::*;
+use systems::mpm::prelude::*;
+
+const PARTICLE_SPACING: f32 = 0.04; // m
+const SAND_DENSITY: f32 = 1600.0; // kg/m³
 
 fn main() {
-    App::new().run();
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "MPM — Sand Castle".to_string(),
+                resolution: (1280, 720).into(),
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_plugins((NewtonLawsPlugin, GravityPlugin::new(), MPMPlugin))
+        .insert_resource(ClearColor(Color::srgb(0.05, 0.05, 0.08)))
+        .add_systems(Startup, setup)
+        .add_systems(Update, color_by_compression)
+        .run();
+}
+
+fn setup(mut commands: Commands, grid: Res<MpmGrid>) {
+    let (min, max) = grid.bounds();
+    let center = (min + max) * 0.5;
+
+    commands.spawn((
+        Camera2d,
+        Projection::Orthographic(OrthographicProjection {
+            scale: 0.02,
+            ..OrthographicProjection::default_2d()
+        }),
+        Transform::from_translation(center.extend(0.0)),
+    ));
+
+    let ramp_size = Vec2::new(6.0, 0.4);
+    commands.spawn((
+        MpmCollider::new(
+            ColliderShape::rectangle(ramp_size.x, ramp_size.y),
+            BoundaryCondition::Friction { coefficient: 0.3 },
+        ),
+        Sprite {
+            color: Color::srgb(0.4, 0.35, 0.3),
+            custom_size: Some(ramp_size),
+            ..default()
+        },
+        Transform::from_translation(center.extend(0.0)).with_rotation(Quat::from_rotation_z(-0.3)),
+    ));
+
+    // Castle outline (m, relative to its bottom-left corner): wall with two
+    // crenellated towers and a keep in the middle
+    let outline = [
+        (0.0, 0.0),
+        (2.0, 0.0),
+        (2.0, 1.3),
+        (1.9, 1.3),
+        (1.9, 1.2),
+        (1.8, 1.2),
+        (1.8, 1.3),
+        (1.7, 1.3),
+        (1.7, 0.8),
+        (1.25, 0.8),
+        (1.25, 1.1),
+        (1.0, 1.35),
+        (0.75, 1.1),
+        (0.75, 0.8),
+        (0.3, 0.8),
+        (0.3, 1.3),
+        (0.2, 1.3),
+        (0.2, 1.2),
+        (0.1, 1.2),
+        (0.1, 1.3),
+        (0.0, 1.3),
+    ];
+    let origin = Vec2::new(center.x - 1.5, center.y + 1.5);
+    let castle = ShapeRegion::polygon(
+        outline
+            .iter()
+            .map(|&(x, y)| origin + Vec2::new(x, y))
+            .collect(),
+    );
+    let batch = PoissonDiskSampler::new(PARTICLE_SPACING).fill(&castle, SAND_DENSITY);
+    commands.spawn_batch(batch.into_bundles((
+        MpmMaterial::sand(),
+        Sprite {
+            color: Color::srgb(0.85, 0.75, 0.5),
+            custom_size: Some(Vec2::splat(PARTICLE_SPACING)),
+            ..default()
+        },
+    )));
+}
+
+/// Shade sand by volume ratio J: darker where packed, lighter where loosened.
+fn color_by_compression(mut particles: Query<(&MpmParticle, &mut Sprite)>) {
+    for (particle, mut sprite) in &mut particles {
+        let j = particle.volume_ratio();
+        let t = ((j - 1.0) * 5.0).clamp(-1.0, 1.0);
+        let shade = 0.85 + 0.15 * t;
+        sprite.color = Color::srgb(shade, 0.88 * shade, 0.6 * shade);
+    }
 }